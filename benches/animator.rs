@@ -0,0 +1,133 @@
+// Compares the time taken to advance the animations of 10 000 sprites on a single thread and on all the available threads
+//
+// Bevy's task pools are global to a process, so each configuration is measured in a process of its own:
+// this benchmark runs itself once per configuration.
+//
+// Run with: cargo bench --bench animator
+
+use std::{
+    env,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    audio::AudioPlugin,
+    log::LogPlugin,
+    prelude::*,
+    render::{RenderPlugin, settings::WgpuSettings},
+    time::TimeUpdateStrategy,
+    winit::WinitPlugin,
+};
+use bevy_spritesheet_animation::prelude::*;
+
+const SPRITES: usize = 10_000;
+const WARMUP_UPDATES: u32 = 10;
+const MEASURED_UPDATES: u32 = 200;
+
+const SINGLE_THREADED: &str = "--single-threaded";
+const MULTI_THREADED: &str = "--multi-threaded";
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.iter().any(|arg| arg == SINGLE_THREADED) {
+        report(
+            "single-threaded",
+            measure(TaskPoolOptions::with_num_threads(1)),
+        );
+    } else if args.iter().any(|arg| arg == MULTI_THREADED) {
+        report("multi-threaded", measure(TaskPoolOptions::default()));
+    } else {
+        // Measure each configuration in a new process
+
+        let executable = env::current_exe().expect("cannot locate the benchmark executable");
+
+        for configuration in [SINGLE_THREADED, MULTI_THREADED] {
+            let status = Command::new(&executable)
+                .arg(configuration)
+                .status()
+                .expect("cannot run the benchmark");
+
+            assert!(status.success(), "the {configuration} benchmark failed");
+        }
+    }
+}
+
+/// Returns the average duration of an app update
+fn measure(task_pool_options: TaskPoolOptions) -> Duration {
+    let mut app = App::new();
+
+    app.add_plugins((
+        DefaultPlugins
+            .build()
+            // Headless mode
+            .disable::<WinitPlugin>()
+            .disable::<LogPlugin>()
+            .disable::<AudioPlugin>()
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            })
+            .set(TaskPoolPlugin { task_pool_options }),
+        SpritesheetAnimationPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        16,
+    )));
+
+    // Spawn sprites with short animations so that frames change on most updates
+
+    let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+    let animations: Vec<Handle<Animation>> = {
+        let mut animations = app.world_mut().resource_mut::<Assets<Animation>>();
+
+        (0..8)
+            .map(|row| {
+                animations.add(
+                    spritesheet
+                        .create_animation()
+                        .add_row(row)
+                        .set_duration(AnimationDuration::PerFrame(10 + row as u32 * 5))
+                        .build(),
+                )
+            })
+            .collect()
+    };
+
+    let generator = spritesheet.with_size_hint(768, 768);
+
+    for index in 0..SPRITES {
+        let sprite =
+            generator.sprite(&mut app.world_mut().resource_mut::<Assets<TextureAtlasLayout>>());
+
+        app.world_mut().spawn((
+            sprite,
+            SpritesheetAnimation::new(animations[index % animations.len()].clone()),
+        ));
+    }
+
+    for _ in 0..WARMUP_UPDATES {
+        app.update();
+    }
+
+    let start = Instant::now();
+
+    for _ in 0..MEASURED_UPDATES {
+        app.update();
+    }
+
+    start.elapsed() / MEASURED_UPDATES
+}
+
+fn report(configuration: &str, update_duration: Duration) {
+    println!(
+        "{configuration:>16}: {SPRITES} sprites, {update_duration:?} per update ({} threads)",
+        bevy::tasks::ComputeTaskPool::get().thread_num()
+    );
+}
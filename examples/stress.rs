@@ -7,6 +7,10 @@
 //
 // Pass --sprites X to render X sprites (default is 100 000)
 //
+// Pass --single-threaded to run all the systems on a single thread.
+// Comparing the FPS with and without this option shows the gains of advancing the animations in parallel.
+// For instance: --sprites 10000 --single-threaded
+//
 // Best executed in --release mode!
 
 use bevy::{dev_tools::fps_overlay::FpsOverlayPlugin, prelude::*, window::PrimaryWindow};
@@ -29,14 +33,25 @@ struct Cli {
 
     #[arg(long, default_value_t = 100_000)]
     sprites: usize,
+
+    #[arg(long)]
+    single_threaded: bool,
 }
 
 fn main() {
     let cli = Cli::parse();
 
+    let task_pool_options = if cli.single_threaded {
+        TaskPoolOptions::with_num_threads(1)
+    } else {
+        TaskPoolOptions::default()
+    };
+
     App::new()
         .add_plugins((
-            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            DefaultPlugins
+                .set(ImagePlugin::default_nearest())
+                .set(TaskPoolPlugin { task_pool_options }),
             SpritesheetAnimationPlugin,
             FpsOverlayPlugin::default(),
         ))
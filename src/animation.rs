@@ -1,27 +1,102 @@
 use bevy::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    time::Duration,
+};
 
-use crate::{clip::Clip, easing::Easing};
+use crate::{
+    clip::Clip,
+    consts::{DEFAULT_DIRECTION, DEFAULT_DURATION, DEFAULT_REPEAT},
+    easing::Easing,
+    error::AnimationError,
+};
 
 /// The duration of an [Animation].
 #[derive(Debug, Clone, Copy, Reflect)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[reflect(Debug)]
+#[reflect(Debug, PartialEq, Hash)]
 pub enum AnimationDuration {
     /// Specifies the duration of one frame in milliseconds (default = `PerFrame(100)`).
     PerFrame(u32),
     /// Specifies the duration of one repetition of the animation in milliseconds.
     PerRepetition(u32),
+    /// Specifies the duration of one frame in fractional milliseconds.
+    ///
+    /// This is useful for frame rates that do not divide a second evenly, like 24 fps.
+    /// See [AnimationBuilder::set_clip_frame_rate()](crate::prelude::AnimationBuilder::set_clip_frame_rate).
+    PerFrameExact(f32),
+}
+
+// Manual implementations as f32 does not implement Eq and Hash: the values are compared bit by bit
+
+impl PartialEq for AnimationDuration {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::PerFrame(a), Self::PerFrame(b))
+            | (Self::PerRepetition(a), Self::PerRepetition(b)) => a == b,
+            (Self::PerFrameExact(a), Self::PerFrameExact(b)) => a.to_bits() == b.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for AnimationDuration {}
+
+impl Hash for AnimationDuration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Self::PerFrame(ms) | Self::PerRepetition(ms) => ms.hash(state),
+            Self::PerFrameExact(ms) => ms.to_bits().hash(state),
+        }
+    }
 }
 
 impl Default for AnimationDuration {
     fn default() -> Self {
-        Self::PerFrame(100)
+        DEFAULT_DURATION
+    }
+}
+
+impl AnimationDuration {
+    /// Multiplies the duration by a factor, switching to fractional milliseconds if a frame duration is not whole anymore
+    fn scaled(self, factor: f32) -> Self {
+        match self {
+            Self::PerFrame(ms) => {
+                let scaled_ms = ms as f32 * factor;
+
+                if scaled_ms.fract() == 0.0 {
+                    Self::PerFrame(scaled_ms as u32)
+                } else {
+                    Self::PerFrameExact(scaled_ms)
+                }
+            }
+            Self::PerRepetition(ms) => Self::PerRepetition((ms as f32 * factor).round() as u32),
+            Self::PerFrameExact(ms) => Self::PerFrameExact(ms * factor),
+        }
+    }
+}
+
+impl fmt::Display for AnimationDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PerFrame(ms) => write!(f, "{ms}ms per frame"),
+            Self::PerRepetition(ms) => write!(f, "{ms}ms per repetition"),
+            Self::PerFrameExact(ms) => write!(f, "{ms}ms per frame"),
+        }
     }
 }
 
 /// How many times an [Animation] repeats.
+///
+/// One repetition of an animation plays its whole sequence of clips.
+/// Each clip plays all of its own repetitions (see [AnimationBuilder::set_clip_repetitions()](crate::prelude::AnimationBuilder::set_clip_repetitions)) during every repetition of the animation.
+///
+/// For instance, an animation with two clips `A` and `B`, where `B` repeats twice, that itself repeats twice will play `A B B A B B`.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[reflect(Debug, PartialEq, Hash)]
@@ -31,6 +106,24 @@ pub enum AnimationRepeat {
     Loop,
     /// Repeats n times.
     Times(usize),
+    /// Loops until the given duration has elapsed.
+    ///
+    /// The animation stops at the end of the repetition during which the duration is exceeded, so that it always ends on a full repetition.
+    /// The duration is measured in real time ([`Time<Real>`]) from the moment the animation starts playing,
+    /// so it is neither affected by the [speed factor](crate::prelude::SpritesheetAnimation::speed_factor) nor by the relative speed of [`Time<Virtual>`].
+    /// The time spent with the animation paused or waiting for the [delay](crate::prelude::AnimationBuilder::set_delay) does not count.
+    LoopFor(Duration),
+}
+
+impl fmt::Display for AnimationRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Loop => write!(f, "loops forever"),
+            Self::Times(1) => write!(f, "plays once"),
+            Self::Times(n) => write!(f, "repeats {n} times"),
+            Self::LoopFor(duration) => write!(f, "loops for {duration:?}"),
+        }
+    }
 }
 
 /// The direction in which the frames of an [Animation] are played.
@@ -43,8 +136,70 @@ pub enum AnimationDirection {
     Forwards,
     /// Frames play from right to left.
     Backwards,
-    /// Alternates at each repetition of the animation, starting from left to right.
+    /// Alternates at each repetition, starting from left to right.
+    ///
+    /// The frame at which the direction changes is not repeated.
+    ///
+    /// - On an animation, the whole sequence of clips alternates: clips `A B` play `A B` then `B' A'`, where `A'` and `B'` are `A` and `B` reversed.
+    ///   Clips that ping-pong themselves are reversed as a whole as well.
+    /// - On a clip, only that clip alternates at each of its own repetitions while the animation keeps moving forwards to the next clips.
     PingPong,
+    /// Plays the frames in a random order determined by a seed, re-shuffled at each repetition.
+    ///
+    /// Repetition `n` is shuffled with the seed `seed ^ n` so that the same seed always plays the same sequence.
+    ///
+    /// Only the displayed frames are shuffled, along with their markers and flips: the durations and easings stay at their positions so that the timing of each repetition is unchanged.
+    ///
+    /// - On an animation, the frames are shuffled at each repetition of the animation. Clips are not mixed together: the frames of each clip repetition are shuffled among themselves.
+    /// - On a clip, the frames are shuffled at each repetition of that clip.
+    Shuffle(u64),
+}
+
+impl fmt::Display for AnimationDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Forwards => write!(f, "forwards"),
+            Self::Backwards => write!(f, "backwards"),
+            Self::PingPong => write!(f, "ping-pong"),
+            Self::Shuffle(seed) => write!(f, "shuffled (seed {seed})"),
+        }
+    }
+}
+
+/// The animation-level parameters that the durations of clips are resolved against.
+///
+/// See [Clip::effective_duration_ms()].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationContext {
+    /// The duration of the animation, which overrides the durations of its clips if set
+    pub animation_duration: Option<AnimationDuration>,
+    /// The duration of the clips when neither them nor their animation specify one
+    pub default_duration: AnimationDuration,
+    /// The total duration of the clips of the animation in milliseconds, between which a [per-repetition](AnimationDuration::PerRepetition) duration of the animation is shared
+    ///
+    /// If `None`, the clip is considered to be the only clip of the animation.
+    pub clips_duration_ms: Option<f64>,
+}
+
+impl Default for AnimationContext {
+    /// A context without animation duration and with the [default duration](DEFAULT_DURATION).
+    fn default() -> Self {
+        Self {
+            animation_duration: None,
+            default_duration: DEFAULT_DURATION,
+            clips_duration_ms: None,
+        }
+    }
+}
+
+impl From<&Animation> for AnimationContext {
+    fn from(animation: &Animation) -> Self {
+        Self {
+            animation_duration: animation.duration,
+            clips_duration_ms: Some(animation.clips.iter().map(Clip::own_duration_ms).sum()),
+            ..default()
+        }
+    }
 }
 
 /// A playable animation to assign to a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component.
@@ -88,9 +243,9 @@ pub enum AnimationDirection {
 ///     .build();
 /// # }
 /// ```
-#[derive(Asset, Debug, Clone, Reflect)]
+#[derive(Asset, Debug, Clone, PartialEq, Reflect)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[reflect(Debug)]
+#[reflect(Debug, Default, PartialEq)]
 pub struct Animation {
     pub(crate) clips: Vec<Clip>,
 
@@ -98,6 +253,34 @@ pub struct Animation {
     pub(crate) repetitions: Option<AnimationRepeat>,
     pub(crate) direction: Option<AnimationDirection>,
     pub(crate) easing: Option<Easing>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) delay: u32,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) on_complete_atlas_index: Option<usize>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) debug_name: Option<String>,
+}
+
+impl Default for Animation {
+    /// Creates an animation with a single empty clip.
+    ///
+    /// Such an animation loops forever without displaying any frame.
+    /// This is typically useful as a placeholder, until an actual animation is available.
+    fn default() -> Self {
+        Self {
+            clips: vec![Clip::empty()],
+            duration: Some(DEFAULT_DURATION),
+            repetitions: Some(DEFAULT_REPEAT),
+            direction: Some(DEFAULT_DIRECTION),
+            easing: None,
+            delay: 0,
+            on_complete_atlas_index: None,
+            debug_name: None,
+        }
+    }
 }
 
 impl Animation {
@@ -112,14 +295,340 @@ impl Animation {
             repetitions: None,
             direction: None,
             easing: None,
+            delay: 0,
+            on_complete_atlas_index: None,
+            debug_name: None,
+        }
+    }
+
+    /// Creates an animation from clips that are already built, with default parameters.
+    ///
+    /// This is a shortcut for programmatic construction: use an [AnimationBuilder](crate::prelude::AnimationBuilder) to set the parameters of the animation.
+    ///
+    /// # Arguments
+    ///
+    /// - `clips` - the clips of the animation, in the order they will be played
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let walk = ClipBuilder::new(spritesheet).add_row(0).build();
+    /// let turn = ClipBuilder::new(spritesheet).add_row(1).build();
+    ///
+    /// let animation = Animation::from_clips(vec![walk, turn]);
+    ///
+    /// assert_eq!(animation.num_clips(), 2);
+    /// # }
+    /// ```
+    pub fn from_clips(clips: Vec<Clip>) -> Self {
+        Self {
+            clips,
+            ..Self::empty()
         }
     }
 
+    /// Creates an animation from a single clip that is already built, with default parameters.
+    ///
+    /// See [Animation::from_clips()].
+    pub fn from_single_clip(clip: Clip) -> Self {
+        Self::from_clips(vec![clip])
+    }
+
     /// The [Clips](crate::prelude::Clip) that compose this animation
     pub fn clips(&self) -> &[Clip] {
         &self.clips
     }
 
+    /// The [Clips](crate::prelude::Clip) that compose this animation, for editing
+    ///
+    /// When updating an animation that is already registered, access it with [Assets::get_mut()] so that Bevy detects the change.
+    pub fn clips_mut(&mut self) -> &mut [Clip] {
+        &mut self.clips
+    }
+
+    /// The number of [Clips](crate::prelude::Clip) that compose this animation
+    pub fn num_clips(&self) -> usize {
+        self.clips.len()
+    }
+
+    /// The [Clip] at the given index, if any
+    pub fn clip_at(&self, index: usize) -> Option<&Clip> {
+        self.clips.get(index)
+    }
+
+    /// Replaces the [Clip] at the given index.
+    ///
+    /// Returns the previous clip or `None` if the index is out of bounds, in which case the animation is left untouched.
+    ///
+    /// When updating an animation that is already registered, access it with [Assets::get_mut()] so that Bevy detects the change.
+    /// Entities playing this animation will then use the new clip, resuming from their current progress when possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn swap_first_clip(
+    ///     mut animations: ResMut<Assets<Animation>>,
+    ///     # animation_handle: Handle<Animation>,
+    ///     # spritesheet: Spritesheet,
+    /// ) {
+    ///     // ... omitted: retrieve the animation's handle and its spritesheet
+    ///
+    ///     let new_clip = ClipBuilder::new(&spritesheet).add_row(5).build();
+    ///
+    ///     if let Some(animation) = animations.get_mut(&animation_handle) {
+    ///         let old_clip = animation.replace_clip(0, new_clip);
+    ///     }
+    /// }
+    /// ```
+    pub fn replace_clip(&mut self, index: usize, new_clip: Clip) -> Option<Clip> {
+        self.clips
+            .get_mut(index)
+            .map(|clip| std::mem::replace(clip, new_clip))
+    }
+
+    /// Adds a [Clip] at the end of this animation.
+    ///
+    /// When updating an animation that is already registered, access it with [Assets::get_mut()] so that Bevy detects the change.
+    /// Entities playing this animation will then resume from their current progress with the new clip at the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn add_victory_clip(
+    ///     mut animations: ResMut<Assets<Animation>>,
+    ///     # animation_handle: Handle<Animation>,
+    ///     # spritesheet: Spritesheet,
+    /// ) {
+    ///     // ... omitted: retrieve the animation's handle and its spritesheet
+    ///
+    ///     let victory_clip = ClipBuilder::new(&spritesheet).add_row(6).build();
+    ///
+    ///     if let Some(animation) = animations.get_mut(&animation_handle) {
+    ///         animation.append_clip(victory_clip);
+    ///     }
+    /// }
+    /// ```
+    pub fn append_clip(&mut self, clip: Clip) {
+        self.clips.push(clip);
+    }
+
+    /// Adds a [Clip] at the start of this animation.
+    ///
+    /// The markers of the clips are indexed by frame within each clip, so they stay on the same frames.
+    /// However, the indices of the clips and of the frames in the whole animation are shifted.
+    ///
+    /// When updating an animation that is already registered, access it with [Assets::get_mut()] so that Bevy detects the change.
+    /// As the progress of the entities playing this animation is not shifted, you might want to [reset](crate::prelude::SpritesheetAnimation::reset) them.
+    pub fn prepend_clip(&mut self, clip: Clip) {
+        self.clips.insert(0, clip);
+    }
+
+    /// A rough estimation of the memory used by this animation, in bytes.
+    ///
+    /// This accounts for the animation itself and for the heap allocations of its clips, such as their frames and markers.
+    /// It does not include the spritesheet images or the data computed when the animation is played.
+    ///
+    /// This is typically useful for tools that track asset budgets.
+    pub fn estimated_memory_usage(&self) -> usize {
+        size_of::<Self>()
+            + self.clips.capacity() * size_of::<Clip>()
+            + self.debug_name.as_ref().map_or(0, String::capacity)
+            + self
+                .clips
+                .iter()
+                .map(|clip| clip.estimated_heap_size())
+                .sum::<usize>()
+    }
+
+    /// The total number of clip repetitions played by this animation, or `None` if it loops forever.
+    ///
+    /// This is the number of repetitions of the animation multiplied by the sum of the repetitions of its clips.
+    /// Animations that [loop for a duration](AnimationRepeat::LoopFor) also return `None` as their number of repetitions depends on how they are played.
+    /// The count saturates at `usize::MAX` if it cannot be represented.
+    ///
+    /// This is typically useful to schedule events like cutscenes around animations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let animation = Spritesheet::new(&Handle::default(), 8, 8)
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .set_clip_repetitions(3)
+    ///     .start_clip()
+    ///     .add_row(1)
+    ///     .set_repetitions(AnimationRepeat::Times(2))
+    ///     .build();
+    ///
+    /// assert_eq!(animation.total_repetitions(), Some(8));
+    /// ```
+    pub fn total_repetitions(&self) -> Option<usize> {
+        let animation_repetitions = match self.repetitions.unwrap_or_default() {
+            AnimationRepeat::Loop | AnimationRepeat::LoopFor(_) => None,
+            AnimationRepeat::Times(n) => Some(n),
+        };
+
+        animation_repetitions.map(|n| {
+            self.clips
+                .iter()
+                .map(|clip| clip.repetitions().unwrap_or(1))
+                .fold(0usize, usize::saturating_add)
+                .saturating_mul(n)
+        })
+    }
+
+    /// Compares the structure of this animation with another one, ignoring the identity of their clips and markers.
+    ///
+    /// Two animations are structurally equal if they play the same frames in the same way: same frame sequences, durations, repetitions, directions, easings, flips and marker positions.
+    /// Unlike `==`, their [ClipIds](crate::prelude::ClipId) and the [Markers](crate::prelude::Marker) themselves are not compared, nor are the names of the animations and clips.
+    ///
+    /// This is typically useful to deduplicate animations built separately from the same definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let create_walk = || {
+    ///     spritesheet
+    ///         .create_animation()
+    ///         .add_row(0)
+    ///         .add_clip_marker(Marker::new(), 2)
+    ///         .build()
+    /// };
+    ///
+    /// let walk1 = create_walk();
+    /// let walk2 = create_walk();
+    ///
+    /// assert_ne!(walk1, walk2);
+    /// assert!(walk1.structural_eq(&walk2));
+    /// # }
+    /// ```
+    pub fn structural_eq(&self, other: &Animation) -> bool {
+        self.duration == other.duration
+            && self.repetitions == other.repetitions
+            && self.direction == other.direction
+            && self.easing == other.easing
+            && self.delay == other.delay
+            && self.on_complete_atlas_index == other.on_complete_atlas_index
+            && self.clips.len() == other.clips.len()
+            && self
+                .clips
+                .iter()
+                .zip(&other.clips)
+                .all(|(clip, other_clip)| clip.structural_eq(other_clip))
+    }
+
+    /// Creates a copy of this animation whose durations are multiplied by a factor.
+    ///
+    /// This is convenient to create tempo variations of the same animation: a factor of 2 plays it at half speed while a factor of 0.5 plays it at double speed.
+    ///
+    /// The durations of the animation and of its clips are scaled, including the default durations, the fixed durations of frames such as [pauses](crate::prelude::AnimationBuilder::add_pause) and the [blends](crate::prelude::AnimationBuilder::set_clip_blend_in).
+    /// The [delay](Animation::delay) is kept as it is.
+    ///
+    /// The clips keep their [ClipIds](crate::prelude::ClipId) so that the events of both animations can be handled the same way.
+    ///
+    /// Returns [AnimationError::InvalidDurationFactor] if `factor` is not strictly positive.
+    ///
+    /// # Arguments
+    ///
+    /// - `factor` - the factor to multiply the durations with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let walk = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .set_duration(AnimationDuration::PerFrame(100))
+    ///     .build();
+    ///
+    /// let slow_walk = walk.with_duration_scaled(2.0).unwrap();
+    ///
+    /// assert_eq!(slow_walk.duration(), &Some(AnimationDuration::PerFrame(200)));
+    ///
+    /// assert_eq!(
+    ///     walk.with_duration_scaled(0.0),
+    ///     Err(AnimationError::InvalidDurationFactor)
+    /// );
+    /// # }
+    /// ```
+    pub fn with_duration_scaled(&self, factor: f32) -> Result<Animation, AnimationError> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(AnimationError::InvalidDurationFactor);
+        }
+
+        let mut animation = self.clone();
+
+        animation.duration = animation.duration.map(|duration| duration.scaled(factor));
+
+        for clip in &mut animation.clips {
+            clip.duration = Some(clip.duration.unwrap_or_default().scaled(factor));
+
+            for duration in clip
+                .frame_durations
+                .values_mut()
+                .chain([&mut clip.blend_in, &mut clip.blend_out])
+            {
+                *duration = (*duration as f32 * factor).round() as u32;
+            }
+        }
+
+        Ok(animation)
+    }
+
+    /// Converts this animation into its reverse, typically for rewind effects.
+    ///
+    /// The clips are played in the opposite order and each one of them in the opposite direction: forwards clips become backwards and vice versa.
+    /// Clips without a direction play backwards.
+    /// [PingPong](AnimationDirection::PingPong) and [Shuffle](AnimationDirection::Shuffle) clips keep their direction.
+    ///
+    /// The clips keep their [ClipIds](crate::prelude::ClipId) and their markers stay on the same frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let open_door = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .start_clip()
+    ///     .add_row(1)
+    ///     .set_repetitions(AnimationRepeat::Times(1))
+    ///     .build();
+    ///
+    /// // Plays row 1 backwards and then row 0 backwards
+    ///
+    /// let close_door = open_door.clone().into_reversed();
+    /// # }
+    /// ```
+    pub fn into_reversed(mut self) -> Animation {
+        self.clips.reverse();
+
+        for clip in &mut self.clips {
+            clip.direction = match clip.direction.unwrap_or_default() {
+                AnimationDirection::Forwards => Some(AnimationDirection::Backwards),
+                AnimationDirection::Backwards => Some(AnimationDirection::Forwards),
+                direction @ (AnimationDirection::PingPong | AnimationDirection::Shuffle(_)) => {
+                    Some(direction)
+                }
+            };
+        }
+
+        self
+    }
+
     /// The optional duration of this animation
     pub fn duration(&self) -> &Option<AnimationDuration> {
         &self.duration
@@ -139,4 +648,395 @@ impl Animation {
     pub fn easing(&self) -> &Option<Easing> {
         &self.easing
     }
+
+    /// The delay in milliseconds before this animation starts playing
+    pub fn delay(&self) -> u32 {
+        self.delay
+    }
+
+    /// The optional atlas index to display once this animation is over
+    pub fn on_complete_atlas_index(&self) -> Option<usize> {
+        self.on_complete_atlas_index
+    }
+
+    /// The optional name of this animation, for debugging purposes
+    pub fn name(&self) -> Option<&str> {
+        self.debug_name.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        events::Marker,
+        prelude::{ClipBuilder, Spritesheet},
+    };
+
+    #[test]
+    fn estimated_memory_usage() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        let small_animation = spritesheet.create_animation().add_indices([0, 1]).build();
+
+        let large_animation = spritesheet
+            .create_animation()
+            .add_indices(0..64)
+            .add_clip_marker(Marker::new(), 5)
+            .start_clip()
+            .add_indices(0..64)
+            .build();
+
+        // At least the animation, its clips and their frames
+
+        assert!(
+            small_animation.estimated_memory_usage()
+                >= size_of::<Animation>() + size_of::<Clip>() + 2 * size_of::<usize>()
+        );
+
+        assert!(
+            large_animation.estimated_memory_usage()
+                >= size_of::<Animation>()
+                    + 2 * size_of::<Clip>()
+                    + 128 * size_of::<usize>()
+                    + size_of::<Marker>()
+        );
+
+        assert!(
+            large_animation.estimated_memory_usage() > small_animation.estimated_memory_usage()
+        );
+    }
+
+    #[test]
+    fn total_repetitions() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        // Loops forever by default
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .build()
+                .total_repetitions(),
+            None
+        );
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .set_repetitions(AnimationRepeat::Times(3))
+                .build()
+                .total_repetitions(),
+            Some(3)
+        );
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .set_clip_repetitions(2)
+                .start_clip()
+                .add_row(1)
+                .set_clip_repetitions(0)
+                .start_clip()
+                .add_row(2)
+                .set_repetitions(AnimationRepeat::Times(2))
+                .build()
+                .total_repetitions(),
+            Some(6)
+        );
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .set_clip_repetitions(2)
+                .set_duration(AnimationDuration::PerFrame(100))
+                .set_repetitions(AnimationRepeat::LoopFor(Duration::from_millis(2000)))
+                .build()
+                .total_repetitions(),
+            None
+        );
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .set_clip_repetitions(2)
+                .set_repetitions(AnimationRepeat::Times(0))
+                .build()
+                .total_repetitions(),
+            Some(0)
+        );
+
+        // Saturates instead of overflowing
+
+        assert_eq!(
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .set_clip_repetitions(2)
+                .set_repetitions(AnimationRepeat::Times(usize::MAX))
+                .build()
+                .total_repetitions(),
+            Some(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn from_clips() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        let clip1 = ClipBuilder::new(&spritesheet).add_row(0).build();
+        let clip2 = ClipBuilder::new(&spritesheet).add_row(1).build();
+
+        let animation = Animation::from_clips(vec![clip1.clone(), clip2.clone()]);
+
+        assert_eq!(animation.clips(), &[clip1.clone(), clip2]);
+
+        // Same parameters as a built animation
+
+        let built_animation = spritesheet
+            .create_animation()
+            .add_clip(clip1.clone())
+            .build();
+
+        assert_eq!(Animation::from_single_clip(clip1), built_animation);
+    }
+
+    #[test]
+    fn default() {
+        let animation = Animation::default();
+
+        assert_eq!(animation.num_clips(), 1);
+        assert!(animation.clips()[0].atlas_indices().is_empty());
+
+        assert_eq!(
+            animation.duration(),
+            &Some(AnimationDuration::PerFrame(100))
+        );
+        assert_eq!(animation.repetitions(), &Some(AnimationRepeat::Loop));
+        assert_eq!(animation.direction(), &Some(AnimationDirection::Forwards));
+        assert_eq!(animation.easing(), &None);
+        assert_eq!(animation.total_repetitions(), None);
+    }
+
+    #[test]
+    fn with_duration_scaled() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        let animation = spritesheet
+            .create_animation()
+            .add_row(0)
+            .add_pause(300)
+            .start_clip()
+            .add_row(1)
+            .set_clip_duration(AnimationDuration::PerRepetition(1000))
+            .start_clip()
+            .add_row(2)
+            .set_clip_duration(AnimationDuration::PerFrameExact(40.0))
+            .set_duration(AnimationDuration::PerFrame(100))
+            .build();
+
+        let scaled = animation.with_duration_scaled(1.5).unwrap();
+
+        assert_eq!(scaled.duration(), &Some(AnimationDuration::PerFrame(150)));
+
+        // Clips without a duration get the scaled default
+
+        assert_eq!(
+            scaled.clips()[0].duration(),
+            &Some(AnimationDuration::PerFrame(150))
+        );
+        assert_eq!(scaled.clips()[0].frame_durations()[&8], 450);
+
+        assert_eq!(
+            scaled.clips()[1].duration(),
+            &Some(AnimationDuration::PerRepetition(1500))
+        );
+        assert_eq!(
+            scaled.clips()[2].duration(),
+            &Some(AnimationDuration::PerFrameExact(60.0))
+        );
+
+        // Fractional frame durations
+
+        assert_eq!(
+            animation.with_duration_scaled(0.255).unwrap().duration(),
+            &Some(AnimationDuration::PerFrameExact(100.0 * 0.255))
+        );
+
+        // Clip IDs are kept
+
+        assert_eq!(scaled.clips()[1].id(), animation.clips()[1].id());
+
+        // Invalid factors
+
+        for factor in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert_eq!(
+                animation.with_duration_scaled(factor),
+                Err(AnimationError::InvalidDurationFactor)
+            );
+        }
+    }
+
+    #[test]
+    fn structural_eq() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        let create_animation = |marker: Marker| {
+            spritesheet
+                .create_animation()
+                .add_row(0)
+                .add_clip_marker(marker, 2)
+                .add_pause(100)
+                .start_clip()
+                .add_row(1)
+                .set_clip_direction(AnimationDirection::Backwards)
+                .set_repetitions(AnimationRepeat::Times(2))
+        };
+
+        let animation = create_animation(Marker::new()).build();
+
+        // Same structure with other IDs and markers
+
+        assert!(animation.structural_eq(&create_animation(Marker::new()).build()));
+        assert!(
+            animation.structural_eq(
+                &create_animation(Marker::new())
+                    .set_animation_name("other")
+                    .build()
+            )
+        );
+
+        // Different structures
+
+        assert!(
+            !animation.structural_eq(
+                &create_animation(Marker::new())
+                    .set_clip_repetitions(2)
+                    .build()
+            )
+        );
+        assert!(
+            !animation.structural_eq(
+                &create_animation(Marker::new())
+                    .set_direction(AnimationDirection::PingPong)
+                    .build()
+            )
+        );
+        assert!(
+            !animation.structural_eq(
+                &create_animation(Marker::new())
+                    .add_clip_marker(Marker::new(), 3)
+                    .build()
+            )
+        );
+        assert!(!animation.structural_eq(&create_animation(Marker::new()).start_clip().build()));
+        assert!(
+            !animation.structural_eq(&create_animation(Marker::new()).add_indices([0]).build())
+        );
+    }
+
+    #[test]
+    fn into_reversed() {
+        let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+        let animation = spritesheet
+            .create_animation()
+            .add_row(0)
+            .start_clip()
+            .add_row(1)
+            .set_clip_direction(AnimationDirection::Backwards)
+            .start_clip()
+            .add_row(2)
+            .set_clip_direction(AnimationDirection::PingPong)
+            .set_repetitions(AnimationRepeat::Times(2))
+            .build();
+
+        let reversed = animation.clone().into_reversed();
+
+        let clip_ids: Vec<_> = reversed.clips().iter().map(|clip| clip.id()).collect();
+        let expected_clip_ids: Vec<_> = animation
+            .clips()
+            .iter()
+            .rev()
+            .map(|clip| clip.id())
+            .collect();
+
+        assert_eq!(clip_ids, expected_clip_ids);
+
+        assert_eq!(
+            reversed.clips()[0].direction(),
+            &Some(AnimationDirection::PingPong)
+        );
+        assert_eq!(
+            reversed.clips()[1].direction(),
+            &Some(AnimationDirection::Forwards)
+        );
+        assert_eq!(
+            reversed.clips()[2].direction(),
+            &Some(AnimationDirection::Backwards)
+        );
+
+        // The other parameters are kept
+
+        assert_eq!(reversed.repetitions(), animation.repetitions());
+        assert_eq!(
+            reversed.clips()[2].atlas_indices(),
+            animation.clips()[0].atlas_indices()
+        );
+
+        // Reversing twice gives the clips back in their order with explicit directions
+
+        let twice_reversed = reversed.into_reversed();
+
+        assert_eq!(twice_reversed.clips()[0].id(), animation.clips()[0].id());
+        assert_eq!(
+            twice_reversed.clips()[0].direction(),
+            &Some(AnimationDirection::Forwards)
+        );
+    }
+
+    #[test]
+    fn default_parameters() {
+        assert_eq!(AnimationDuration::default(), DEFAULT_DURATION);
+        assert_eq!(AnimationRepeat::default(), DEFAULT_REPEAT);
+        assert_eq!(AnimationDirection::default(), DEFAULT_DIRECTION);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            AnimationDuration::PerFrame(100).to_string(),
+            "100ms per frame"
+        );
+        assert_eq!(
+            AnimationDuration::PerRepetition(2500).to_string(),
+            "2500ms per repetition"
+        );
+        assert_eq!(
+            AnimationDuration::PerFrameExact(41.5).to_string(),
+            "41.5ms per frame"
+        );
+
+        assert_eq!(AnimationRepeat::Loop.to_string(), "loops forever");
+        assert_eq!(AnimationRepeat::Times(1).to_string(), "plays once");
+        assert_eq!(AnimationRepeat::Times(3).to_string(), "repeats 3 times");
+        assert_eq!(
+            AnimationRepeat::LoopFor(Duration::from_secs(3)).to_string(),
+            "loops for 3s"
+        );
+
+        assert_eq!(AnimationDirection::Forwards.to_string(), "forwards");
+        assert_eq!(AnimationDirection::Backwards.to_string(), "backwards");
+        assert_eq!(AnimationDirection::PingPong.to_string(), "ping-pong");
+        assert_eq!(
+            AnimationDirection::Shuffle(42).to_string(),
+            "shuffled (seed 42)"
+        );
+    }
 }
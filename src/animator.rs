@@ -1,7 +1,10 @@
 pub(crate) mod cache;
 mod iterator;
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use bevy::prelude::*;
 
@@ -16,7 +19,7 @@ use bevy::{
         resource::Resource,
         system::{Query, ResMut},
     },
-    platform::collections::HashMap,
+    platform::{collections::HashMap, time::Instant},
     reflect::Reflect,
     sprite::Sprite,
     time::Time,
@@ -26,13 +29,19 @@ use bevy::{
 #[cfg(feature = "3d")]
 use crate::components::sprite3d::Sprite3d;
 use crate::{
+    CRATE_NAME,
     animation::Animation,
     animator::{
         cache::AnimationCache,
         iterator::{AnimationIterator, IteratorFrame},
     },
-    components::spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
-    events::AnimationEvent,
+    components::{
+        frame_remapper::FrameRemapper,
+        profiling::AnimationProfiling,
+        recorder::AnimationRecorder,
+        spritesheet_animation::{AnimationProgress, PlayingAnimation, SpritesheetAnimation},
+    },
+    events::{AnimationEvent, ClipChangedEvent, FrameChangedEvent},
 };
 use iterator::AnimationIteratorEvent;
 
@@ -48,6 +57,30 @@ struct AnimationInstance {
 
     /// Time accumulated since the last frame
     accumulated_time: Duration,
+
+    /// Time left to wait before the animation starts
+    remaining_delay: Duration,
+
+    /// Real time spent playing the animation, for animations that loop for a duration
+    playing_time: Duration,
+
+    /// Was the current frame replaced by an overridden atlas index during the last update?
+    frame_overridden: bool,
+
+    /// The alpha of the sprite before it was faded by blended clips, to restore once the fades are over
+    base_alpha: Option<f32>,
+
+    /// Animation events emitted since the last update, waiting to be sent
+    #[reflect(ignore)]
+    events: Vec<AnimationEvent>,
+
+    /// Clip changes since the last update, waiting to be sent
+    #[reflect(ignore)]
+    clip_changes: Vec<ClipChangedEvent>,
+
+    /// Frame changes since the last update, waiting to be sent
+    #[reflect(ignore)]
+    frame_changes: Vec<FrameChangedEvent>,
 }
 
 /// The animator is responsible for playing animations as time advances.
@@ -62,7 +95,24 @@ pub(crate) struct Animator {
     /// Instances of animations currently being played
     ///
     /// Each animation instance is associated to an entity with a [SpritesheetAnimation] component.
-    animation_instances: HashMap<Entity, AnimationInstance>,
+    ///
+    /// The instances are behind mutexes so that they can be advanced in parallel.
+    #[reflect(ignore)]
+    animation_instances: HashMap<Entity, Mutex<AnimationInstance>>,
+
+    /// Are marker events disabled?
+    ///
+    /// If so, the markers are stripped from the animation caches so that they cost nothing during playback.
+    marker_events_disabled: bool,
+
+    /// The alphas to restore on the sprites whose animation instances were discarded during a fade
+    detached_base_alphas: HashMap<Entity, f32>,
+
+    /// The timestep by which the animations advance, if not advancing on every update
+    fixed_timestep: Option<Duration>,
+
+    /// Time accumulated since the last fixed timestep
+    fixed_accumulated_time: Duration,
 }
 
 /// A query data type for the [`Animator::update`] system.
@@ -77,14 +127,57 @@ pub(crate) struct SpritesheetAnimationQuery {
     image_node: Option<&'static mut ImageNode>,
     #[cfg(feature = "custom_cursor")]
     cursor_icon: Option<&'static mut CursorIcon>,
+    frame_remapper: Option<Ref<'static, FrameRemapper>>,
+    recorder: Option<&'static mut AnimationRecorder>,
+    profiling: Option<&'static mut AnimationProfiling>,
 }
 
 impl Animator {
+    pub fn new(marker_events_disabled: bool, fixed_timestep: Option<Duration>) -> Self {
+        Self {
+            marker_events_disabled,
+            fixed_timestep,
+            ..default()
+        }
+    }
+
+    /// Discards the cache of an animation that has been modified or removed.
+    ///
+    /// The instances playing this animation are discarded as well so that they are re-created from the updated animation on the next update.
+    /// The entities that play a removed animation stop on their current frame.
+    pub fn invalidate(&mut self, animation_id: AssetId<Animation>) {
+        self.animation_caches.remove(&animation_id);
+
+        self.animation_instances.retain(|entity, instance| {
+            let instance = instance.get_mut().unwrap();
+
+            if instance.animation.id() != animation_id {
+                return true;
+            }
+
+            // Keep the original alpha of faded sprites around for their next instance
+
+            if let Some(base_alpha) = instance.base_alpha {
+                self.detached_base_alphas.insert(*entity, base_alpha);
+            }
+
+            false
+        });
+    }
+
     /// Plays the animations
+    ///
+    /// The update runs in three phases:
+    /// - new animation instances are set up sequentially, as it may require creating animation caches
+    /// - the animations are advanced in parallel, each instance buffering its own events
+    /// - the buffered events are sent sequentially, in the order of the query
     pub fn update(
         &mut self,
         time: &Time,
+        real_time: &Time<Real>,
         message_writer: &mut MessageWriter<AnimationEvent>,
+        clip_changed_writer: &mut MessageWriter<ClipChangedEvent>,
+        frame_changed_writer: &mut MessageWriter<FrameChangedEvent>,
         query: &mut Query<SpritesheetAnimationQuery>,
         animations: &mut ResMut<Assets<Animation>>,
     ) {
@@ -93,25 +186,51 @@ impl Animator {
         self.animation_instances
             .retain(|entity, _state| query.contains(*entity));
 
-        // Run animations for all the entities
+        self.detached_base_alphas
+            .retain(|entity, _alpha| query.contains(*entity));
+
+        // Set up new animation instances
 
         for mut item in query.iter_mut() {
             // Create a cache for the current animation if there are none yet
 
-            let cache = self
-                .animation_caches
-                .entry(item.spritesheet_animation.animation.id())
-                .or_insert_with(|| {
-                    let animation = animations
-                        .get(item.spritesheet_animation.animation.id())
-                        .unwrap();
-                    Arc::new(AnimationCache::from_animation(animation))
-                });
+            let animation_id = item.spritesheet_animation.animation.id();
+
+            if !self.animation_caches.contains_key(&animation_id) {
+                // (the animation may not be loaded yet or may have been removed)
+                let Some(animation) = animations.get(animation_id) else {
+                    continue;
+                };
+
+                let mut cache = AnimationCache::from_animation(animation);
+
+                if self.marker_events_disabled {
+                    if animation
+                        .clips()
+                        .iter()
+                        .any(|clip| !clip.markers().is_empty())
+                    {
+                        warn!(
+                            name = ?animation.name(),
+                            "{CRATE_NAME}: the markers of an animation are ignored because marker events are disabled"
+                        );
+                    }
+
+                    cache.strip_markers();
+                }
+
+                self.animation_caches.insert(animation_id, Arc::new(cache));
+            }
+
+            let cache = &self.animation_caches[&animation_id];
 
             // Create a new animation instance if:
-            let needs_new_animation_instance = match self.animation_instances.get(&item.entity) {
+            let needs_new_animation_instance = match self.animation_instances.get_mut(&item.entity)
+            {
                 // The entity has an animation instance already but it switched animation
                 Some(instance) => {
+                    let instance = instance.get_mut().unwrap();
+
                     instance.animation != item.spritesheet_animation.animation
                         || instance.current_frame.is_none()
                             && item.spritesheet_animation.progress.frame == 0
@@ -134,201 +253,697 @@ impl Animator {
                     }
                 }
 
+                item.spritesheet_animation.playing_animation = PlayingAnimation(Some((
+                    item.spritesheet_animation.animation.id(),
+                    cache.clone(),
+                )));
+
                 // Create the instance and immediately play the first frame
 
-                let first_frame = Self::play_frame(&mut iterator, &mut item, message_writer);
+                let mut events = Vec::new();
+                let mut frame_changes = Vec::new();
+
+                let first_frame = Self::play_frame(
+                    &mut iterator,
+                    &mut item,
+                    &mut events,
+                    &mut frame_changes,
+                    Duration::ZERO,
+                );
+
+                // Carry over the original alpha of the sprite if the previous animation was fading it
+                // (restored on the next update if the new animation does not fade it)
+
+                let base_alpha = self
+                    .animation_instances
+                    .get_mut(&item.entity)
+                    .and_then(|instance| instance.get_mut().unwrap().base_alpha)
+                    .or_else(|| self.detached_base_alphas.remove(&item.entity));
 
                 self.animation_instances.insert(
                     item.entity,
-                    AnimationInstance {
+                    Mutex::new(AnimationInstance {
                         animation: item.spritesheet_animation.animation.clone(),
                         iterator,
                         current_frame: first_frame,
                         accumulated_time: Duration::ZERO,
-                    },
+                        remaining_delay: cache.delay,
+                        playing_time: Duration::ZERO,
+                        frame_overridden: false,
+                        base_alpha,
+                        events,
+                        clip_changes: Vec::new(),
+                        frame_changes,
+                    }),
                 );
             }
+        }
 
-            let animation_instance = self.animation_instances.get_mut(&item.entity).unwrap();
+        // Synchronize the animations with the ones of other entities
+        //
+        // (done before advancing so that the synchronized animations all advance from the same state)
 
-            // Apply manual progress updates
+        for mut item in query.iter_mut() {
+            // (read before being cleared so that the component is not marked as changed on every update)
+            let Some(target) = item.spritesheet_animation.synchronization else {
+                continue;
+            };
 
-            if animation_instance
-                .current_frame
-                .as_ref()
-                .filter(|frame| item.spritesheet_animation.progress != frame.1)
-                .is_some()
-            {
-                if animation_instance
-                    .iterator
-                    .to(item.spritesheet_animation.progress)
-                {
-                    Self::play_frame(&mut animation_instance.iterator, &mut item, message_writer)
-                        .inspect(|new_frame| {
-                            animation_instance.current_frame = Some(new_frame.clone());
-                            animation_instance.accumulated_time = Duration::ZERO;
-                        });
+            item.spritesheet_animation.synchronization = None;
+
+            let Some((progress, accumulated_time, remaining_delay)) = self
+                .animation_instances
+                .get(&target)
+                .filter(|_| target != item.entity)
+                .and_then(|instance| {
+                    let instance = instance.lock().unwrap();
+
+                    instance.current_frame.as_ref().map(|(_, progress)| {
+                        (
+                            *progress,
+                            instance.accumulated_time,
+                            instance.remaining_delay,
+                        )
+                    })
+                })
+            else {
+                error!(
+                    "{CRATE_NAME}: cannot synchronize the animation of entity {} with entity {target} that plays no animation",
+                    item.entity
+                );
+                continue;
+            };
+
+            if let Some(animation_instance) = self.animation_instances.get_mut(&item.entity) {
+                let animation_instance = animation_instance.get_mut().unwrap();
+
+                if Self::seek(animation_instance, &mut item, progress) {
+                    animation_instance.accumulated_time = accumulated_time;
+                    animation_instance.remaining_delay = remaining_delay;
                 } else {
-                    // Restore to the last valid progress if invalid
-                    item.spritesheet_animation.progress = animation_instance
-                        .current_frame
-                        .as_ref()
-                        .map(|(_, progress)| *progress)
-                        .unwrap_or_default()
+                    error!(
+                        "{CRATE_NAME}: cannot synchronize the animation of entity {} with entity {target}: frame {} of repetition {} does not exist",
+                        item.entity, progress.frame, progress.repetition
+                    );
                 }
             }
+        }
 
-            // Skip the update if the animation is paused
-            //
-            // (skipped AFTER the setup above so that the first frame is assigned, even if paused)
+        // Advance the animations of all the entities in parallel
 
-            if !item.spritesheet_animation.playing {
-                continue;
-            }
+        let delta_secs = match self.fixed_timestep {
+            // Only advance by whole timesteps, the time left being accumulated for the next updates
+            Some(fixed_timestep) => {
+                self.fixed_accumulated_time += time.delta();
 
-            // Update the animation
+                let steps = (self.fixed_accumulated_time.as_nanos() / fixed_timestep.as_nanos())
+                    .min(u32::MAX as u128) as u32;
 
-            animation_instance.accumulated_time += Duration::from_secs_f32(
-                time.delta_secs() * item.spritesheet_animation.speed_factor,
-            );
+                let elapsed = fixed_timestep.saturating_mul(steps);
 
-            while let Some(current_frame) = animation_instance
-                .current_frame
-                .as_ref()
-                .filter(|frame| animation_instance.accumulated_time > frame.0.duration)
-            {
-                // Consume the elapsed time
+                self.fixed_accumulated_time -= elapsed;
 
-                animation_instance.accumulated_time -= current_frame.0.duration;
+                elapsed.as_secs_f32()
+            }
+            None => time.delta_secs(),
+        };
 
-                // Fetch the next frame
+        let real_delta = real_time.delta();
 
-                animation_instance.current_frame =
-                    Self::play_frame(&mut animation_instance.iterator, &mut item, message_writer)
-                        .or_else(|| {
-                            // The animation is over
+        let animation_instances = &self.animation_instances;
 
-                            // Emit the end events if the animation just ended
+        query.par_iter_mut().for_each(|mut item| {
+            if let Some(animation_instance) = animation_instances.get(&item.entity) {
+                let start = item.profiling.is_some().then(Instant::now);
 
-                            message_writer.write(AnimationEvent::ClipRepetitionEnd {
-                                entity: item.entity,
-                                clip_id: current_frame.0.clip_id,
-                                clip_repetition: current_frame.0.clip_repetition,
-                                animation: animation_instance.animation.clone(),
-                            });
+                Self::advance(
+                    &mut animation_instance.lock().unwrap(),
+                    &mut item,
+                    delta_secs,
+                    real_delta,
+                );
 
-                            message_writer.write(AnimationEvent::ClipEnd {
-                                entity: item.entity,
-                                clip_id: current_frame.0.clip_id,
-                                animation: animation_instance.animation.clone(),
-                            });
+                if let (Some(start), Some(profiling)) = (start, item.profiling.as_deref_mut()) {
+                    profiling.last_frame_advance_time = start.elapsed();
+                }
+            }
+        });
 
-                            message_writer.write(AnimationEvent::AnimationRepetitionEnd {
-                                entity: item.entity,
-                                animation: animation_instance.animation.clone(),
-                                animation_repetition: current_frame.0.animation_repetition,
-                            });
+        // Send the events buffered by the animation instances
 
-                            message_writer.write(AnimationEvent::AnimationEnd {
-                                entity: item.entity,
-                                animation: animation_instance.animation.clone(),
-                            });
+        for item in query.iter() {
+            if let Some(animation_instance) = self.animation_instances.get_mut(&item.entity) {
+                let animation_instance = animation_instance.get_mut().unwrap();
 
-                            None
-                        });
+                message_writer.write_batch(animation_instance.events.drain(..));
+                clip_changed_writer.write_batch(animation_instance.clip_changes.drain(..));
+                frame_changed_writer.write_batch(animation_instance.frame_changes.drain(..));
             }
         }
     }
 
-    fn play_frame(
-        iterator: &mut AnimationIterator,
+    /// Advances the animation instance of an entity
+    fn advance(
+        animation_instance: &mut AnimationInstance,
         item: &mut SpritesheetAnimationQueryItem<'_, '_>,
-        message_writer: &mut MessageWriter<AnimationEvent>,
-    ) -> Option<(IteratorFrame, AnimationProgress)> {
-        let maybe_frame = iterator.next();
+        delta_secs: f32,
+        real_delta: Duration,
+    ) {
+        // Move the recording forwards, even if paused
 
-        if let Some((frame, progress)) = &maybe_frame {
-            // Update the sprite
-            // (we compare the indices to prevent needless "Changed" events)
-
-            if let Some(atlas) = item
-                .sprite
-                .as_deref_mut()
-                .and_then(|sprite| sprite.texture_atlas.as_mut())
-                && atlas.index != frame.atlas_index
-            {
-                atlas.index = frame.atlas_index;
+        if let Some(recorder) = item.recorder.as_deref_mut() {
+            recorder.tick(Duration::from_secs_f32(delta_secs));
+        }
+
+        // Re-assign the current frame if its remapping changed
+
+        if let Some(current_frame) = animation_instance.current_frame.as_ref().filter(|_| {
+            item.frame_remapper
+                .as_ref()
+                .is_some_and(|remapper| remapper.is_changed())
+        }) {
+            Self::assign_atlas_index(item, current_frame.0.atlas_index);
+        }
+
+        // Apply manual progress updates
+
+        if animation_instance
+            .current_frame
+            .as_ref()
+            .filter(|frame| item.spritesheet_animation.progress != frame.1)
+            .is_some()
+        {
+            let progress = item.spritesheet_animation.progress;
+
+            if !Self::seek(animation_instance, item, progress) {
+                // Restore to the last valid progress if invalid
+                item.spritesheet_animation.progress = animation_instance
+                    .current_frame
+                    .as_ref()
+                    .map(|(_, progress)| *progress)
+                    .unwrap_or_default()
             }
+        }
+
+        // Display the overridden frame for this update only, without advancing the animation
+        //
+        // (read before being cleared so that the component is not marked as changed on every update)
+
+        if let Some(atlas_index) = item.spritesheet_animation.frame_override {
+            item.spritesheet_animation.frame_override = None;
+
+            Self::assign_atlas_index(item, atlas_index);
+
+            animation_instance.frame_overridden = true;
+
+            return;
+        }
+
+        // Display the current frame again after an override
+
+        if std::mem::take(&mut animation_instance.frame_overridden)
+            && let Some(current_frame) = &animation_instance.current_frame
+        {
+            Self::assign_atlas_index(item, current_frame.0.atlas_index);
+        }
+
+        // Skip the update if the animation is paused
+        //
+        // (skipped AFTER the setup above so that the first frame is assigned, even if paused)
+
+        if !item.spritesheet_animation.playing {
+            Self::assign_fade(animation_instance, item);
+            return;
+        }
+
+        // Update the animation
+
+        let mut elapsed_time =
+            Duration::from_secs_f32(delta_secs * item.spritesheet_animation.speed_factor);
+
+        // (measured in real time for the animations that loop for a duration)
+        let mut playing_time = real_delta;
+
+        // Wait for the initial delay to be over
+
+        if !animation_instance.remaining_delay.is_zero() {
+            let consumed_delay = elapsed_time.min(animation_instance.remaining_delay);
+
+            // (the real time spent waiting is not part of the playing time)
+            playing_time = if elapsed_time.is_zero() {
+                Duration::ZERO
+            } else {
+                real_delta.mul_f64(
+                    (elapsed_time - consumed_delay).as_secs_f64() / elapsed_time.as_secs_f64(),
+                )
+            };
+
+            animation_instance.remaining_delay -= consumed_delay;
+            elapsed_time -= consumed_delay;
+        }
+
+        animation_instance.playing_time += playing_time;
+
+        animation_instance.accumulated_time += elapsed_time;
+
+        while let Some(current_frame) = animation_instance
+            .current_frame
+            .as_ref()
+            .filter(|frame| animation_instance.accumulated_time > frame.0.duration)
+        {
+            // Consume the elapsed time
+
+            animation_instance.accumulated_time -= current_frame.0.duration;
 
-            // 3D sprites
+            // Interrupting animations end after their first repetition: resume the interrupted animation
 
-            #[cfg(feature = "3d")]
-            if let Some(atlas) = item
-                .sprite3d
-                .as_deref_mut()
-                .and_then(|sprite| sprite.texture_atlas.as_mut())
-                && atlas.index != frame.atlas_index
+            if item.spritesheet_animation.resume.is_some()
+                && item.spritesheet_animation.is_on_last_frame()
             {
-                atlas.index = frame.atlas_index;
+                Self::emit_end_events(
+                    current_frame,
+                    item,
+                    &animation_instance.animation,
+                    &mut animation_instance.events,
+                );
+
+                animation_instance.current_frame = None;
+
+                if let Some((animation, progress)) = item.spritesheet_animation.resume.take() {
+                    item.spritesheet_animation.animation = animation;
+                    item.spritesheet_animation.progress = progress;
+                }
+
+                break;
             }
 
-            // UI images
+            // Loop back to the start of the segment, if any, after its last frame
 
-            if let Some(atlas) = item
-                .image_node
-                .as_deref_mut()
-                .and_then(|image| image.texture_atlas.as_mut())
-                && atlas.index != frame.atlas_index
+            if let Some((start_frame, end_frame)) = item.spritesheet_animation.playing_segment()
+                && current_frame.1.frame >= end_frame
             {
-                atlas.index = frame.atlas_index;
+                animation_instance
+                    .iterator
+                    .to(AnimationProgress::with_frame_repetition(
+                        start_frame,
+                        current_frame.1.repetition,
+                    ));
             }
 
-            // Cursors
-
-            #[cfg(feature = "custom_cursor")]
-            if let Some(atlas) = item
-                .cursor_icon
-                .as_deref_mut()
-                .and_then(|cursor_icon| {
-                    if let CursorIcon::Custom(CustomCursor::Image(CustomCursorImage {
-                        ref mut texture_atlas,
-                        ..
-                    })) = *cursor_icon
-                    {
-                        Some(texture_atlas)
-                    } else {
-                        None
-                    }
+            // Fetch the next frame
+
+            let events = &mut animation_instance.events;
+            let frame_changes = &mut animation_instance.frame_changes;
+
+            // (the new frame actually started a bit earlier, during the time that remains accumulated)
+            let time_ago = if item.spritesheet_animation.speed_factor > 0.0 {
+                animation_instance
+                    .accumulated_time
+                    .div_f32(item.spritesheet_animation.speed_factor)
+            } else {
+                Duration::ZERO
+            };
+
+            // Animations that loop for a duration end with the repetition during which the duration is exceeded
+
+            if item
+                .spritesheet_animation
+                .playing_cache()
+                .and_then(|cache| cache.loop_for)
+                .is_some_and(|loop_for| {
+                    animation_instance.playing_time.saturating_sub(time_ago) >= loop_for
                 })
-                .and_then(|atlas| atlas.as_mut())
-                && atlas.index != frame.atlas_index
             {
-                atlas.index = frame.atlas_index;
+                animation_instance.iterator.end_after_repetition();
+            }
+
+            animation_instance.current_frame = Self::play_frame(
+                &mut animation_instance.iterator,
+                item,
+                events,
+                frame_changes,
+                time_ago,
+            )
+            .inspect(|next_frame| {
+                Self::emit_clip_change(
+                    Some(current_frame),
+                    next_frame,
+                    item,
+                    &mut animation_instance.clip_changes,
+                );
+            })
+            .or_else(|| {
+                // The animation is over
+
+                // Emit the end events if the animation just ended
+
+                Self::emit_end_events(current_frame, item, &animation_instance.animation, events);
+
+                // Jump to the resting frame if any
+
+                if let Some(atlas_index) = item
+                    .spritesheet_animation
+                    .playing_cache()
+                    .and_then(|cache| cache.on_complete_atlas_index)
+                {
+                    Self::assign_atlas_index(item, atlas_index);
+                }
+
+                None
+            });
+        }
+
+        Self::assign_fade(animation_instance, item);
+    }
+
+    /// Moves an animation instance to a specific frame and plays it from its start
+    ///
+    /// Returns false, leaving the instance untouched, if the frame does not exist.
+    fn seek(
+        animation_instance: &mut AnimationInstance,
+        item: &mut SpritesheetAnimationQueryItem<'_, '_>,
+        progress: AnimationProgress,
+    ) -> bool {
+        if !animation_instance.iterator.to(progress) {
+            return false;
+        }
+
+        Self::play_frame(
+            &mut animation_instance.iterator,
+            item,
+            &mut animation_instance.events,
+            &mut animation_instance.frame_changes,
+            Duration::ZERO,
+        )
+        .inspect(|new_frame| {
+            Self::emit_clip_change(
+                animation_instance.current_frame.as_ref(),
+                new_frame,
+                item,
+                &mut animation_instance.clip_changes,
+            );
+
+            animation_instance.current_frame = Some(new_frame.clone());
+            animation_instance.accumulated_time = Duration::ZERO;
+            animation_instance.remaining_delay = Duration::ZERO;
+        });
+
+        true
+    }
+
+    /// Updates the alpha of the sprite if the current frame belongs to an animation with blended clips
+    ///
+    /// The fades are applied on top of the original alpha of the sprite, which is restored once they are over.
+    fn assign_fade(
+        animation_instance: &mut AnimationInstance,
+        item: &mut SpritesheetAnimationQueryItem<'_, '_>,
+    ) {
+        let fade = animation_instance
+            .current_frame
+            .as_ref()
+            .and_then(|(frame, _)| frame.fade);
+
+        let alpha = match fade {
+            Some(fade) => {
+                let base_alpha = *animation_instance
+                    .base_alpha
+                    .get_or_insert_with(|| Self::alpha(item).unwrap_or(1.0));
+
+                base_alpha * fade.alpha(animation_instance.accumulated_time)
+            }
+            None => match animation_instance.base_alpha.take() {
+                Some(base_alpha) => base_alpha,
+                None => return,
+            },
+        };
+
+        // (we compare the alphas to prevent needless "Changed" events)
+
+        if let Some(sprite) = item
+            .sprite
+            .as_deref_mut()
+            .filter(|sprite| sprite.color.alpha() != alpha)
+        {
+            sprite.color.set_alpha(alpha);
+        }
+
+        #[cfg(feature = "3d")]
+        if let Some(sprite) = item
+            .sprite3d
+            .as_deref_mut()
+            .filter(|sprite| sprite.color.alpha() != alpha)
+        {
+            sprite.color.set_alpha(alpha);
+        }
+
+        if let Some(image) = item
+            .image_node
+            .as_deref_mut()
+            .filter(|image| image.color.alpha() != alpha)
+        {
+            image.color.set_alpha(alpha);
+        }
+    }
+
+    /// The alpha of the first animatable component of an entity that has a color
+    fn alpha(item: &SpritesheetAnimationQueryItem<'_, '_>) -> Option<f32> {
+        let alpha = item.sprite.as_deref().map(|sprite| sprite.color.alpha());
+
+        #[cfg(feature = "3d")]
+        let alpha = alpha.or_else(|| item.sprite3d.as_deref().map(|sprite| sprite.color.alpha()));
+
+        alpha.or_else(|| item.image_node.as_deref().map(|image| image.color.alpha()))
+    }
+
+    /// Emits the events marking the end of an animation after its last frame
+    fn emit_end_events(
+        last_frame: &(IteratorFrame, AnimationProgress),
+        item: &SpritesheetAnimationQueryItem<'_, '_>,
+        animation: &Handle<Animation>,
+        events: &mut Vec<AnimationEvent>,
+    ) {
+        info!(
+            entity = ?item.entity,
+            animation = ?animation.id(),
+            name = ?item
+                .spritesheet_animation
+                .playing_cache()
+                .and_then(|cache| cache.name.as_deref()),
+            "{CRATE_NAME}: animation ended"
+        );
+
+        events.push(AnimationEvent::ClipRepetitionEnd {
+            entity: item.entity,
+            clip_id: last_frame.0.clip_id,
+            clip_repetition: last_frame.0.clip_repetition,
+            animation: animation.clone(),
+        });
+
+        events.push(AnimationEvent::ClipEnd {
+            entity: item.entity,
+            clip_id: last_frame.0.clip_id,
+            animation: animation.clone(),
+        });
+
+        events.push(AnimationEvent::AnimationRepetitionEnd {
+            entity: item.entity,
+            animation: animation.clone(),
+            animation_repetition: last_frame.0.animation_repetition,
+        });
+
+        events.push(AnimationEvent::AnimationEnd {
+            entity: item.entity,
+            animation: animation.clone(),
+        });
+    }
+
+    fn play_frame(
+        iterator: &mut AnimationIterator,
+        item: &mut SpritesheetAnimationQueryItem<'_, '_>,
+        events: &mut Vec<AnimationEvent>,
+        frame_changes: &mut Vec<FrameChangedEvent>,
+        time_ago: Duration,
+    ) -> Option<(IteratorFrame, AnimationProgress)> {
+        let maybe_frame = iterator.next();
+
+        if let Some((frame, progress)) = &maybe_frame {
+            trace!(
+                entity = ?item.entity,
+                clip_index = frame.clip_index,
+                frame = progress.frame,
+                repetition = progress.repetition,
+                atlas_index = frame.atlas_index,
+                "{CRATE_NAME}: frame advanced"
+            );
+
+            Self::assign_atlas_index(item, frame.atlas_index);
+
+            if let Some(flip_x) = frame.flip_x {
+                Self::assign_flip_x(item, flip_x);
             }
 
             item.spritesheet_animation.progress = *progress;
 
+            // Record the frame
+
+            if let Some(recorder) = item.recorder.as_deref_mut() {
+                recorder.record(
+                    frame.clip_index,
+                    progress.frame,
+                    frame.atlas_index,
+                    time_ago,
+                );
+            }
+
             // Emit events
 
+            if item.spritesheet_animation.frame_changed_events {
+                frame_changes.push(FrameChangedEvent {
+                    entity: item.entity,
+                    clip_index: frame.clip_index,
+                    frame_index: progress.frame,
+                    atlas_index: frame.atlas_index,
+                });
+            }
+
             Animator::emit_events(
                 &frame.events,
                 &item.spritesheet_animation.animation,
                 &item.entity,
-                message_writer,
+                events,
             );
         }
 
         maybe_frame
     }
 
+    /// Flips all the animatable components of an entity horizontally
+    fn assign_flip_x(item: &mut SpritesheetAnimationQueryItem<'_, '_>, flip_x: bool) {
+        // (we compare the flips to prevent needless "Changed" events)
+
+        if let Some(sprite) = item
+            .sprite
+            .as_deref_mut()
+            .filter(|sprite| sprite.flip_x != flip_x)
+        {
+            sprite.flip_x = flip_x;
+        }
+
+        #[cfg(feature = "3d")]
+        if let Some(sprite) = item
+            .sprite3d
+            .as_deref_mut()
+            .filter(|sprite| sprite.flip_x != flip_x)
+        {
+            sprite.flip_x = flip_x;
+        }
+
+        if let Some(image) = item
+            .image_node
+            .as_deref_mut()
+            .filter(|image| image.flip_x != flip_x)
+        {
+            image.flip_x = flip_x;
+        }
+    }
+
+    /// Assigns an atlas index to all the animatable components of an entity
+    fn assign_atlas_index(item: &mut SpritesheetAnimationQueryItem<'_, '_>, atlas_index: usize) {
+        // Apply the optional remapping
+
+        let atlas_index = item
+            .frame_remapper
+            .as_deref()
+            .map(|remapper| remapper.remap(atlas_index))
+            .unwrap_or(atlas_index);
+
+        // Update the sprite
+        // (we compare the indices to prevent needless "Changed" events)
+
+        if let Some(atlas) = item
+            .sprite
+            .as_deref_mut()
+            .and_then(|sprite| sprite.texture_atlas.as_mut())
+            && atlas.index != atlas_index
+        {
+            atlas.index = atlas_index;
+        }
+
+        // 3D sprites
+
+        #[cfg(feature = "3d")]
+        if let Some(atlas) = item
+            .sprite3d
+            .as_deref_mut()
+            .and_then(|sprite| sprite.texture_atlas.as_mut())
+            && atlas.index != atlas_index
+        {
+            atlas.index = atlas_index;
+        }
+
+        // UI images
+
+        if let Some(atlas) = item
+            .image_node
+            .as_deref_mut()
+            .and_then(|image| image.texture_atlas.as_mut())
+            && atlas.index != atlas_index
+        {
+            atlas.index = atlas_index;
+        }
+
+        // Cursors
+
+        #[cfg(feature = "custom_cursor")]
+        if let Some(atlas) = item
+            .cursor_icon
+            .as_deref_mut()
+            .and_then(|cursor_icon| {
+                if let CursorIcon::Custom(CustomCursor::Image(CustomCursorImage {
+                    ref mut texture_atlas,
+                    ..
+                })) = *cursor_icon
+                {
+                    Some(texture_atlas)
+                } else {
+                    None
+                }
+            })
+            .and_then(|atlas| atlas.as_mut())
+            && atlas.index != atlas_index
+        {
+            atlas.index = atlas_index;
+        }
+    }
+
+    fn emit_clip_change(
+        previous_frame: Option<&(IteratorFrame, AnimationProgress)>,
+        next_frame: &(IteratorFrame, AnimationProgress),
+        item: &SpritesheetAnimationQueryItem<'_, '_>,
+        clip_changes: &mut Vec<ClipChangedEvent>,
+    ) {
+        // Only emit the event for actual transitions, repetitions of the same clip are not changes
+
+        if let Some((previous_frame, _)) =
+            previous_frame.filter(|(frame, _)| frame.clip_index != next_frame.0.clip_index)
+        {
+            clip_changes.push(ClipChangedEvent {
+                entity: item.entity,
+                from_clip: previous_frame.clip_index,
+                to_clip: next_frame.0.clip_index,
+                animation: item.spritesheet_animation.animation.clone(),
+            });
+        }
+    }
+
     fn emit_events(
         animation_events: &[AnimationIteratorEvent],
         animation: &Handle<Animation>,
         entity: &Entity,
-        message_writer: &mut MessageWriter<AnimationEvent>,
+        events: &mut Vec<AnimationEvent>,
     ) {
         animation_events.iter().for_each(|event| {
-            message_writer.write(
+            events.push(
                 // Promote AnimationIteratorEvents to regular AnimationEvents
                 match event {
                     AnimationIteratorEvent::MarkerHit {
@@ -336,14 +951,25 @@ impl Animator {
                         clip_id,
                         clip_repetition,
                         animation_repetition,
-                    } => AnimationEvent::MarkerHit {
-                        entity: *entity,
-                        marker: *marker,
-                        clip_id: *clip_id,
-                        clip_repetition: *clip_repetition,
-                        animation: animation.clone(),
-                        animation_repetition: *animation_repetition,
-                    },
+                    } => {
+                        debug!(
+                            entity = ?entity,
+                            marker = ?marker,
+                            clip_id = ?clip_id,
+                            clip_repetition,
+                            animation_repetition,
+                            "{CRATE_NAME}: marker hit"
+                        );
+
+                        AnimationEvent::MarkerHit {
+                            entity: *entity,
+                            marker: *marker,
+                            clip_id: *clip_id,
+                            clip_repetition: *clip_repetition,
+                            animation: animation.clone(),
+                            animation_repetition: *animation_repetition,
+                        }
+                    }
                     AnimationIteratorEvent::ClipRepetitionEnd {
                         clip_id,
                         clip_repetition,
@@ -17,8 +17,49 @@ pub(crate) struct CacheFrame {
     pub atlas_index: usize,
     pub duration: Duration,
     pub clip_id: ClipId,
+    /// Index of the clip in the animation
+    pub clip_index: usize,
     pub clip_repetition: usize,
     pub events: Vec<AnimationCacheEvent>,
+    /// Whether the frame is flipped horizontally, if the animation flips any of its frames
+    pub flip_x: Option<bool>,
+    /// The position of the frame in its clip to fade the sprite, if the animation blends any of its clips
+    pub fade: Option<CacheFade>,
+    /// Whether the frame displays the previous frame again instead of its own atlas index
+    pub is_virtual: bool,
+}
+
+/// The position of a frame in its clip, to compute the alpha of the sprite while the clip blends in or out
+#[derive(Debug, Clone, Copy, Reflect)]
+#[reflect(Debug)]
+pub(crate) struct CacheFade {
+    /// The time from the start of the clip to the start of the frame
+    pub clip_elapsed: Duration,
+    /// The time from the start of the frame to the end of the clip
+    pub clip_remaining: Duration,
+    pub blend_in: Duration,
+    pub blend_out: Duration,
+}
+
+impl CacheFade {
+    /// The alpha of the sprite after some time spent on the frame
+    pub fn alpha(&self, frame_elapsed: Duration) -> f32 {
+        let ratio = |time: Duration, blend: Duration| {
+            if blend.is_zero() {
+                1.0
+            } else {
+                (time.as_secs_f32() / blend.as_secs_f32()).min(1.0)
+            }
+        };
+
+        let fade_in = ratio(self.clip_elapsed + frame_elapsed, self.blend_in);
+        let fade_out = ratio(
+            self.clip_remaining.saturating_sub(frame_elapsed),
+            self.blend_out,
+        );
+
+        fade_in * fade_out
+    }
 }
 
 /// A partial version of AnimationEvent.
@@ -61,13 +102,30 @@ pub(crate) struct AnimationCache {
     /// None for other directions.
     pub frames_pong: Option<Vec<CacheFrame>>,
 
+    /// Frames for the first repetition when some clips start at a later frame the first time they are played.
+    /// None if no clips have a start frame.
+    pub frames_first: Option<Vec<CacheFrame>>,
+
     /// The total number of repetitions to play.
-    /// None if looping forever.
+    /// None if looping forever or for a duration.
     pub repetitions: Option<usize>,
 
+    /// The real time after which the animation ends its current repetition.
+    /// None if not looping for a duration.
+    pub loop_for: Option<Duration>,
+
     /// The direction of the animation to handle the PingPong case
     /// (after the first repetition, the first frame must be skipped)
     pub animation_direction: AnimationDirection,
+
+    /// The time to wait before the animation starts
+    pub delay: Duration,
+
+    /// The atlas index to display once the animation is over
+    pub on_complete_atlas_index: Option<usize>,
+
+    /// The name of the animation, for logging
+    pub name: Option<String>,
 }
 
 impl AnimationCache {
@@ -75,39 +133,198 @@ impl AnimationCache {
         Self {
             frames: Vec::new(),
             frames_pong: None,
+            frames_first: None,
             repetitions: None,
+            loop_for: None,
             animation_direction: AnimationDirection::Forwards,
+            delay: Duration::ZERO,
+            on_complete_atlas_index: None,
+            name: None,
         }
     }
 
-    pub fn from_animation(animation: &Animation) -> AnimationCache {
-        // If the animation repeats 0 times, just create an empty cache that will play no frames
-        // TODO should use the first frame only instead?
+    /// The frames of a repetition of the animation
+    pub fn repetition_frames(&self, repetition: usize) -> &[CacheFrame] {
+        match (&self.frames_first, &self.frames_pong) {
+            (Some(frames_first), _) if repetition == 0 => frames_first,
+            (_, Some(frames_pong)) if repetition % 2 == 1 => frames_pong,
+            _ => &self.frames,
+        }
+    }
 
-        let animation_repetitions = animation.repetitions().unwrap_or_default();
+    /// Removes the marker events from all the frames.
+    pub fn strip_markers(&mut self) {
+        for frame in self
+            .frames
+            .iter_mut()
+            .chain(self.frames_pong.iter_mut().flatten())
+            .chain(self.frames_first.iter_mut().flatten())
+        {
+            frame
+                .events
+                .retain(|event| !matches!(event, AnimationCacheEvent::MarkerHit { .. }));
+        }
+    }
 
-        if matches!(animation_repetitions, AnimationRepeat::Times(0)) {
-            return Self::empty();
+    /// Shuffles the frames of one repetition of an animation with the [Shuffle](AnimationDirection::Shuffle) direction.
+    ///
+    /// The frames are only shuffled within each clip repetition so that the clip events stay consistent.
+    /// The durations stay at their positions while the atlas indices, markers and flips follow their frames.
+    ///
+    /// Virtual frames are not shuffled and display the frames before them in the shuffled order.
+    pub fn shuffled_frames(&self, repetition: usize, seed: u64) -> Vec<CacheFrame> {
+        let mut frames = self.shuffle(repetition, seed);
+
+        if frames.iter().any(|frame| frame.is_virtual) {
+            // The first frames follow the last frame of the previous repetition, which is shuffled differently
+
+            let before_frames = match repetition.checked_sub(1) {
+                Some(previous_repetition) => {
+                    Self::last_displayed(&self.shuffle(previous_repetition, seed))
+                }
+                None => Self::last_displayed(&frames),
+            };
+
+            Self::resolve(&mut frames, before_frames);
+        }
+
+        frames
+    }
+
+    fn shuffle(&self, repetition: usize, seed: u64) -> Vec<CacheFrame> {
+        let mut rng = fastrand::Rng::with_seed(seed ^ repetition as u64);
+
+        let mut frames = self.repetition_frames(repetition).to_vec();
+
+        for run in frames.chunk_by_mut(|a, b| {
+            a.clip_index == b.clip_index && a.clip_repetition == b.clip_repetition
+        }) {
+            let mut order: Vec<CacheFrame> = run
+                .iter()
+                .filter(|frame| !frame.is_virtual)
+                .cloned()
+                .collect();
+
+            rng.shuffle(&mut order);
+
+            for (frame, source_frame) in run.iter_mut().filter(|frame| !frame.is_virtual).zip(order)
+            {
+                // Keep the clip events on the first frame of the clip repetition
+
+                let clip_events = frame
+                    .events
+                    .iter()
+                    .filter(|event| !matches!(event, AnimationCacheEvent::MarkerHit { .. }))
+                    .cloned();
+
+                let marker_events = source_frame
+                    .events
+                    .iter()
+                    .filter(|event| matches!(event, AnimationCacheEvent::MarkerHit { .. }))
+                    .cloned();
+
+                frame.events = marker_events.chain(clip_events).collect();
+
+                frame.atlas_index = source_frame.atlas_index;
+                frame.flip_x = source_frame.flip_x;
+            }
+        }
+
+        frames
+    }
+
+    /// Replaces the virtual frames with the frames displayed just before them, in play order
+    ///
+    /// The first frames of a repetition follow the last frame of the previous repetition (which is a pong repetition for ping-pong animations).
+    fn resolve_virtual_frames(frames: &mut [CacheFrame], frames_pong: Option<&mut [CacheFrame]>) {
+        match frames_pong {
+            Some(frames_pong) => {
+                let (before_frames, before_pong) = (
+                    Self::last_displayed(frames_pong),
+                    Self::last_displayed(frames),
+                );
+
+                Self::resolve(frames, before_frames.or(before_pong));
+                Self::resolve(frames_pong, before_pong.or(before_frames));
+            }
+            None => {
+                let before_frames = Self::last_displayed(frames);
+
+                Self::resolve(frames, before_frames);
+            }
+        }
+    }
+
+    /// The atlas index and flip of the last frame that is not virtual
+    fn last_displayed(frames: &[CacheFrame]) -> Option<(usize, Option<bool>)> {
+        frames
+            .iter()
+            .rfind(|frame| !frame.is_virtual)
+            .map(|frame| (frame.atlas_index, frame.flip_x))
+    }
+
+    /// Displays the previous frame on the virtual frames, starting with the given one
+    fn resolve(frames: &mut [CacheFrame], mut displayed: Option<(usize, Option<bool>)>) {
+        for frame in frames {
+            match (frame.is_virtual, displayed) {
+                (true, Some((atlas_index, flip_x))) => {
+                    frame.atlas_index = atlas_index;
+                    frame.flip_x = flip_x;
+                }
+                (true, None) => {}
+                (false, _) => displayed = Some((frame.atlas_index, frame.flip_x)),
+            }
         }
+    }
 
+    /// Assigns its position in its clip to each frame so that blended clips can fade the sprite
+    fn locate_fades(animation: &Animation, frames: &mut [CacheFrame]) {
+        for clip_frames in frames.chunk_by_mut(|a, b| a.clip_index == b.clip_index) {
+            let clip = &animation.clips()[clip_frames[0].clip_index];
+
+            let blend_in = Duration::from_millis(clip.blend_in() as u64);
+            let blend_out = Duration::from_millis(clip.blend_out() as u64);
+
+            let clip_duration: Duration = clip_frames.iter().map(|frame| frame.duration).sum();
+
+            let mut clip_elapsed = Duration::ZERO;
+
+            for frame in clip_frames {
+                frame.fade = Some(CacheFade {
+                    clip_elapsed,
+                    clip_remaining: clip_duration - clip_elapsed,
+                    blend_in,
+                    blend_out,
+                });
+
+                clip_elapsed += frame.duration;
+            }
+        }
+    }
+
+    /// Builds the frames of one repetition of an animation, along with the frames of its odd repetitions if it plays in ping-pong
+    ///
+    /// Returns `None` if the animation lasts 0 ms.
+    fn build_frames(animation: &Animation) -> Option<(Vec<CacheFrame>, Option<Vec<CacheFrame>>)> {
         // Gather data for all the clips
 
         let clips_data = animation
             .clips()
             .iter()
-            .map(ClipData::new)
+            .enumerate()
+            .map(|(index, clip)| ClipData::new(index, clip))
             // Filter out clips with 0 frames / 0 repetitions / durations of 0
             //
             // Doing so at this point will simplify what follows as well as the playback code as we won't have to handle those special cases
             .filter(|data| {
                 !data.clip.atlas_indices().is_empty()
                     && data.repetitions > 0
-                    && data.duration_with_repetitions_ms > 0
+                    && data.duration_with_repetitions_ms > 0.0
             });
 
         // Compute the total duration of one cycle of the animation in milliseconds
 
-        let animation_duration_ms: u32 = clips_data
+        let animation_duration_ms: f64 = clips_data
             .clone()
             .map(|data| data.duration_with_repetitions_ms)
             .sum();
@@ -115,8 +332,8 @@ impl AnimationCache {
         // If the animation lasts 0 ms, just create an empty cache that will play no frames
         // TODO should use the first frame only instead?
 
-        if animation_duration_ms == 0 {
-            return Self::empty();
+        if animation_duration_ms <= 0.0 {
+            return None;
         }
 
         // Generate the full animation from all the clips
@@ -130,15 +347,16 @@ impl AnimationCache {
                     None => clip_data.duration,
 
                     // The per-frame duration is defined for the animation: override the clip's duration with it
-                    Some(AnimationDuration::PerFrame(animation_frame_duration)) => {
-                        AnimationDuration::PerFrame(*animation_frame_duration)
-                    }
+                    Some(
+                        animation_frame_duration @ (AnimationDuration::PerFrame(_)
+                        | AnimationDuration::PerFrameExact(_)),
+                    ) => *animation_frame_duration,
 
                     // The per-cycle duration of the animation is defined:
                     // assign a duration to the clip that stays proportional to its base duration with respect to the total animation duration
                     Some(AnimationDuration::PerRepetition(animation_cycle_duration)) => {
-                        let clip_ratio = clip_data.duration_with_repetitions_ms as f32
-                            / animation_duration_ms as f32;
+                        let clip_ratio =
+                            (clip_data.duration_with_repetitions_ms / animation_duration_ms) as f32;
 
                         AnimationDuration::PerRepetition(
                             (*animation_cycle_duration as f32 * clip_ratio
@@ -150,9 +368,10 @@ impl AnimationCache {
                 // Compute the duration of a single frame
 
                 let clip_frame_corrected_duration_ms = match clip_corrected_duration {
-                    AnimationDuration::PerFrame(frame_duration_ms) => frame_duration_ms,
+                    AnimationDuration::PerFrame(frame_duration_ms) => frame_duration_ms as f64,
+                    AnimationDuration::PerFrameExact(frame_duration_ms) => frame_duration_ms as f64,
                     AnimationDuration::PerRepetition(cycle_duration_ms) => {
-                        cycle_duration_ms / clip_data.clip.atlas_indices().len() as u32
+                        (cycle_duration_ms / clip_data.clip.atlas_indices().len() as u32) as f64
                     }
                 };
 
@@ -167,21 +386,105 @@ impl AnimationCache {
         let animation_direction = animation.direction().unwrap_or_default();
         let animation_easing = animation.easing().unwrap_or_default();
 
-        let (all_frames, all_frames_pong) =
+        let (mut all_frames, mut all_frames_pong) =
             animation_frames.build(animation_direction, animation_easing);
 
+        // Display the previous frames again on the virtual frames
+
+        if animation
+            .clips()
+            .iter()
+            .any(|clip| !clip.virtual_frames().is_empty())
+        {
+            Self::resolve_virtual_frames(&mut all_frames, all_frames_pong.as_deref_mut());
+        }
+
+        // Leave the sprites' flips untouched if the animation does not flip any frame
+
+        if animation
+            .clips()
+            .iter()
+            .all(|clip| clip.flipped_frames().is_empty())
+        {
+            for frame in all_frames
+                .iter_mut()
+                .chain(all_frames_pong.iter_mut().flatten())
+            {
+                frame.flip_x = None;
+            }
+        }
+
+        // Locate the frames in their clips if the animation blends any of its clips
+
+        if animation
+            .clips()
+            .iter()
+            .any(|clip| clip.blend_in() > 0 || clip.blend_out() > 0)
+        {
+            for frames in [Some(&mut all_frames), all_frames_pong.as_mut()]
+                .into_iter()
+                .flatten()
+            {
+                Self::locate_fades(animation, frames);
+            }
+        }
+
+        Some((all_frames, all_frames_pong))
+    }
+
+    pub fn from_animation(animation: &Animation) -> AnimationCache {
+        // If the animation repeats 0 times, just create an empty cache that will play no frames
+        // TODO should use the first frame only instead?
+
+        let animation_repetitions = animation.repetitions().unwrap_or_default();
+
+        if matches!(animation_repetitions, AnimationRepeat::Times(0)) {
+            return Self::empty();
+        }
+
+        let Some((all_frames, all_frames_pong)) = Self::build_frames(animation) else {
+            return Self::empty();
+        };
+
+        // Build the first repetition separately if some clips start at a later frame the first time they are played
+        //
+        // (the start frames are skipped like offsets, for that repetition only)
+
+        let all_frames_first = animation
+            .clips()
+            .iter()
+            .any(|clip| clip.start_frame() > clip.offset())
+            .then(|| {
+                let mut first_animation = animation.clone();
+
+                for clip in &mut first_animation.clips {
+                    clip.offset = clip.offset.max(clip.start_frame);
+                }
+
+                Self::build_frames(&first_animation)
+                    .map(|(frames, _)| frames)
+                    .filter(|frames| !frames.is_empty())
+            })
+            .flatten();
+
         // Done!
 
-        let animation_repetition_count = match animation_repetitions {
-            AnimationRepeat::Loop => None,
-            AnimationRepeat::Times(n) => Some(n),
+        let (animation_repetition_count, loop_for) = match animation_repetitions {
+            AnimationRepeat::Loop => (None, None),
+            AnimationRepeat::Times(n) => (Some(n), None),
+            AnimationRepeat::LoopFor(duration) => (None, Some(duration)),
         };
 
         Self {
             frames: all_frames,
             frames_pong: all_frames_pong,
+            frames_first: all_frames_first,
             repetitions: animation_repetition_count,
-            animation_direction,
+            loop_for,
+            animation_direction: animation.direction().unwrap_or_default(),
+            delay: Duration::from_millis(animation.delay() as u64),
+            on_complete_atlas_index: animation.on_complete_atlas_index(),
+            name: animation.debug_name.clone(),
         }
     }
 }
@@ -189,40 +492,26 @@ impl AnimationCache {
 #[derive(Clone)]
 struct ClipData {
     clip: Clip,
+    index: usize,
     duration: AnimationDuration,
     repetitions: usize,
     direction: AnimationDirection,
     easing: Easing,
-    duration_with_repetitions_ms: u32,
+    duration_with_repetitions_ms: f64,
 }
 
 impl ClipData {
-    fn new(clip: &Clip) -> Self {
+    fn new(index: usize, clip: &Clip) -> Self {
         let duration = clip.duration().unwrap_or_default();
         let repetitions = clip.repetitions().unwrap_or(1);
         let direction = clip.direction().unwrap_or_default();
         let easing = clip.easing().unwrap_or_default();
 
-        // Compute the clip's duration in milliseconds, taking repetitions into account
-
-        let frame_count_with_repetitions = match direction {
-            AnimationDirection::Forwards | AnimationDirection::Backwards => {
-                clip.atlas_indices().len() as u32 * repetitions as u32
-            }
-            AnimationDirection::PingPong => {
-                clip.atlas_indices().len().saturating_sub(1) as u32 * repetitions as u32 + 1
-            }
-        };
-
-        let duration_with_repetitions_ms = match duration {
-            AnimationDuration::PerFrame(frame_duration) => {
-                frame_duration * frame_count_with_repetitions
-            }
-            AnimationDuration::PerRepetition(repetition_duration) => repetition_duration,
-        };
+        let duration_with_repetitions_ms = clip.duration_with_repetitions_ms(duration);
 
         Self {
             clip: clip.clone(),
+            index,
             duration,
             repetitions,
             direction,
@@ -239,6 +528,11 @@ struct Frame {
     atlas_index: usize,
     duration: Duration,
     markers: Vec<Marker>,
+    easing: Option<Easing>,
+    flip_x: bool,
+    is_virtual: bool,
+    /// Whether the frame has a fixed duration, which is left untouched by easings
+    is_fixed: bool,
 }
 
 #[derive(Clone)]
@@ -247,7 +541,14 @@ struct ClipRepetitionFrames {
 }
 
 impl ClipRepetitionFrames {
-    fn new(clip_data: &ClipData, frame_duration_ms: u32) -> Self {
+    fn new(clip_data: &ClipData, frame_duration_ms: f64) -> Self {
+        // (invalid durations result in frames with no duration)
+        let frame_duration = if frame_duration_ms.is_finite() && frame_duration_ms > 0.0 {
+            Duration::from_nanos((frame_duration_ms * 1_000_000.0).round() as u64)
+        } else {
+            Duration::ZERO
+        };
+
         Self {
             frames: clip_data
                 .clip
@@ -264,10 +565,23 @@ impl ClipRepetitionFrames {
                         .cloned()
                         .unwrap_or(Vec::new());
 
+                    // Frames with a fixed duration ignore the clip's duration
+
+                    let fixed_duration = clip_data
+                        .clip
+                        .frame_durations()
+                        .get(&frame_index)
+                        .map(|fixed_duration| Duration::from_millis(*fixed_duration as u64));
+
                     Frame {
                         atlas_index: *frame_atlas_index,
-                        duration: Duration::from_millis(frame_duration_ms as u64),
+                        duration: fixed_duration.unwrap_or(frame_duration),
                         markers,
+                        // Easings that restart at this frame
+                        easing: clip_data.clip.frame_easings().get(&frame_index).copied(),
+                        flip_x: clip_data.clip.flipped_frames().contains(&frame_index),
+                        is_virtual: clip_data.clip.virtual_frames().contains(&frame_index),
+                        is_fixed: fixed_duration.is_some(),
                     }
                 })
                 // Filter out frames with no duration
@@ -282,6 +596,41 @@ impl ClipRepetitionFrames {
         }
     }
 
+    /// Shuffles the displayed frames, the durations and easings staying at their positions
+    ///
+    /// Virtual frames are not shuffled, they display the frames before them once the animation is assembled.
+    fn shuffled(&self, seed: u64) -> Self {
+        let mut order: Vec<usize> = (0..self.frames.len())
+            .filter(|frame_index| !self.frames[*frame_index].is_virtual)
+            .collect();
+
+        fastrand::Rng::with_seed(seed).shuffle(&mut order);
+
+        let mut order = order.into_iter();
+
+        Self {
+            frames: self
+                .frames
+                .iter()
+                .map(|frame| {
+                    if frame.is_virtual {
+                        return frame.clone();
+                    }
+
+                    // (there are as many shuffled indices as frames that are not virtual)
+                    let source_frame = &self.frames[order.next().unwrap_or_default()];
+
+                    Frame {
+                        atlas_index: source_frame.atlas_index,
+                        markers: source_frame.markers.clone(),
+                        flip_x: source_frame.flip_x,
+                        ..frame.clone()
+                    }
+                })
+                .collect(),
+        }
+    }
+
     fn ping(&self) -> Self {
         Self {
             frames: self.frames.iter().skip(1).cloned().collect(),
@@ -302,7 +651,7 @@ struct ClipFrames {
 }
 
 impl ClipFrames {
-    fn new(clip_data: ClipData, frame_duration_override_ms: u32) -> Self {
+    fn new(clip_data: ClipData, frame_duration_override_ms: f64) -> Self {
         let reference_repetition =
             ClipRepetitionFrames::new(&clip_data, frame_duration_override_ms);
 
@@ -312,6 +661,9 @@ impl ClipFrames {
                     match clip_data.direction {
                         AnimationDirection::Forwards => reference_repetition.clone(),
                         AnimationDirection::Backwards => reference_repetition.backwards(),
+                        AnimationDirection::Shuffle(seed) => {
+                            reference_repetition.shuffled(seed ^ repetition as u64)
+                        }
                         AnimationDirection::PingPong => {
                             if repetition == 0 {
                                 // First ping cycle: use all the frames (ping() would remove the first one)
@@ -324,6 +676,22 @@ impl ClipFrames {
                         }
                     }
                 })
+                .enumerate()
+                .map(|(repetition, frames)| {
+                    // Skip the offset frames of the first repetition
+
+                    if repetition == 0 && clip_data.clip.offset() > 0 {
+                        ClipRepetitionFrames {
+                            frames: frames
+                                .frames
+                                .into_iter()
+                                .skip(clip_data.clip.offset())
+                                .collect(),
+                        }
+                    } else {
+                        frames
+                    }
+                })
                 // Filter out repetitions with no frames
                 .filter(|repetition| !repetition.frames.is_empty())
                 .collect(),
@@ -393,12 +761,16 @@ impl AnimationFrames {
             // PingPong: reverse ALL the frame in the alternate "pong" collection
             // (all the frame because the iterator will skip the first frame of all the ping & pong repetitions after the first one)
             AnimationDirection::PingPong => (self.clone(), Some(self.backwards())),
+
+            // Shuffle: the iterator shuffles the frames at each repetition
+            AnimationDirection::Shuffle(_) => (self.clone(), None),
         };
 
         // Assemble the nested animation/clip/repetition tree into a single sequence of frames
 
         let merge = |mut frames: AnimationFrames| {
             let mut all_frames = Vec::new();
+            let mut all_frames_fixed = Vec::new();
 
             let mut previous_clip_id = None;
             let mut previous_clip_repetition = None;
@@ -408,14 +780,33 @@ impl AnimationFrames {
 
                 for (repetition_index, repetition) in clip.repetitions.iter_mut().enumerate() {
                     // Apply easing to the clip repetition
+                    //
+                    // The frames are split at the frames that restart the easing, each sequence being eased separately.
+                    // Frames with a fixed duration keep it.
+
+                    let mut sequence_easing = clip.data.easing;
+                    let mut sequence_frame_durations = Vec::new();
+
+                    for frame in &mut repetition.frames {
+                        if let Some(frame_easing) = frame.easing {
+                            if !sequence_frame_durations.is_empty() {
+                                apply_easing(
+                                    std::mem::take(&mut sequence_frame_durations),
+                                    sequence_easing,
+                                );
+                            }
 
-                    let clip_frame_durations = repetition
-                        .frames
-                        .iter_mut()
-                        .map(|frame| &mut frame.duration)
-                        .collect();
+                            sequence_easing = frame_easing;
+                        }
+
+                        if !frame.is_fixed {
+                            sequence_frame_durations.push(&mut frame.duration);
+                        }
+                    }
+
+                    apply_easing(sequence_frame_durations, sequence_easing);
 
-                    apply_easing(clip_frame_durations, clip.data.easing);
+                    all_frames_fixed.extend(repetition.frames.iter().map(|frame| frame.is_fixed));
 
                     // Convert to runtime AnimationFrames
 
@@ -426,7 +817,11 @@ impl AnimationFrames {
                             atlas_index: frame.atlas_index,
                             duration: frame.duration,
                             clip_id: clip.data.clip.id(),
+                            clip_index: clip.data.index,
                             clip_repetition: repetition_index,
+                            flip_x: Some(frame.flip_x),
+                            fade: None,
+                            is_virtual: frame.is_virtual,
                             // Convert the markers to events
                             events: frame
                                 .markers
@@ -481,11 +876,13 @@ impl AnimationFrames {
                 all_frames.extend(all_clip_frames);
             }
 
-            // Apply easing on the whole animation
+            // Apply easing on the whole animation, except on the frames with a fixed duration
 
             let animation_frame_durations = all_frames
                 .iter_mut()
-                .map(|frame| &mut frame.duration)
+                .zip(&all_frames_fixed)
+                .filter(|(_, is_fixed)| !**is_fixed)
+                .map(|(frame, _)| &mut frame.duration)
                 .collect();
 
             apply_easing(animation_frame_durations, easing);
@@ -498,9 +895,9 @@ impl AnimationFrames {
 }
 
 fn apply_easing(frame_durations: Vec<&mut Duration>, easing: Easing) {
-    // Linear easing: there's nothing to do
+    // Linear easing or no frames to ease: there's nothing to do
 
-    if matches!(easing, Easing::Linear) {
+    if matches!(easing, Easing::Linear) || frame_durations.is_empty() {
         return;
     }
 
@@ -5,7 +5,7 @@ use bevy::prelude::*;
 use crate::{
     CRATE_NAME,
     animation::AnimationDirection,
-    animator::cache::{AnimationCache, AnimationCacheEvent, CacheFrame},
+    animator::cache::{AnimationCache, AnimationCacheEvent, CacheFade, CacheFrame},
     clip::ClipId,
     components::spritesheet_animation::AnimationProgress,
     events::Marker,
@@ -18,9 +18,12 @@ pub(crate) struct IteratorFrame {
     pub atlas_index: usize,
     pub duration: Duration,
     pub clip_id: ClipId,
+    pub clip_index: usize,
     pub clip_repetition: usize,
     pub animation_repetition: usize,
     pub events: Vec<AnimationIteratorEvent>,
+    pub flip_x: Option<bool>,
+    pub fade: Option<CacheFade>,
 }
 
 /// A partial version of AnimationEvent.
@@ -62,6 +65,12 @@ pub(crate) struct AnimationIterator {
     /// Marks when a repetition just completed so that end events can be emitted on the next iteration
     /// (the value is the last frame)
     repetition_just_ended: Option<CacheFrame>,
+
+    /// The frames of the current repetition if the animation is shuffled, along with the repetition
+    shuffled_frames: Option<(usize, Vec<CacheFrame>)>,
+
+    /// The repetition at which the animation ends early, once an animation that loops for a duration exceeded it
+    repetition_limit: Option<usize>,
 }
 
 impl AnimationIterator {
@@ -70,6 +79,21 @@ impl AnimationIterator {
             cache,
             next_frame_progress: AnimationProgress::default(),
             repetition_just_ended: None,
+            shuffled_frames: None,
+            repetition_limit: None,
+        }
+    }
+
+    /// Makes the animation end once its current repetition is over.
+    ///
+    /// If the last frame returned was the last one of a repetition, the animation ends right away.
+    pub fn end_after_repetition(&mut self) {
+        if self.repetition_limit.is_none() {
+            self.repetition_limit = Some(if self.repetition_just_ended.is_some() {
+                self.next_frame_progress.repetition
+            } else {
+                self.next_frame_progress.repetition + 1
+            });
         }
     }
 
@@ -79,15 +103,9 @@ impl AnimationIterator {
     pub fn to(&mut self, progress: AnimationProgress) -> bool {
         // Validate the target progress
 
-        if progress.frame >= self.cache.frames.len() {
-            error!(
-                "{CRATE_NAME}: invalid frame {} in {}-frame animation, cannot update progress",
-                progress.frame,
-                self.cache.frames.len().saturating_sub(1)
-            );
+        let frame_count = self.cache.repetition_frames(progress.repetition).len();
 
-            false
-        } else if let Some(repetitions) = self
+        if let Some(repetitions) = self
             .cache
             .repetitions
             .filter(|repetitions| progress.repetition >= *repetitions)
@@ -98,12 +116,21 @@ impl AnimationIterator {
                 repetitions.saturating_sub(1)
             );
 
+            false
+        } else if progress.frame >= frame_count {
+            error!(
+                "{CRATE_NAME}: invalid frame {} in {}-frame animation, cannot update progress",
+                progress.frame,
+                frame_count.saturating_sub(1)
+            );
+
             false
         } else {
             // Update the iterator
 
             self.next_frame_progress = progress;
             self.repetition_just_ended = None;
+            self.repetition_limit = None;
 
             true
         }
@@ -146,21 +173,42 @@ impl Iterator for AnimationIterator {
     type Item = (IteratorFrame, AnimationProgress);
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Retrieve the appropriate frame set from the cache
+        // Stop early if the animation was ended
 
-        let cached_frames = if let Some(frames_pong) = &self.cache.frames_pong {
-            if self.next_frame_progress.repetition.is_multiple_of(2) {
-                // Regular frames for even PingPong repetitions
-                &self.cache.frames
-            } else {
-                // Frames for odd PingPong repetitions
-                frames_pong
+        if self
+            .repetition_limit
+            .is_some_and(|limit| self.next_frame_progress.repetition >= limit)
+        {
+            return None;
+        }
+
+        // Shuffle the frames at the start of each repetition
+
+        if let AnimationDirection::Shuffle(seed) = self.cache.animation_direction {
+            let repetition = self.next_frame_progress.repetition;
+
+            if self
+                .shuffled_frames
+                .as_ref()
+                .is_none_or(|(shuffled_repetition, _)| *shuffled_repetition != repetition)
+            {
+                self.shuffled_frames =
+                    Some((repetition, self.cache.shuffled_frames(repetition, seed)));
             }
+        }
+
+        // Retrieve the appropriate frame set from the cache
+
+        let cached_frames = if let Some((_, shuffled_frames)) = &self.shuffled_frames {
+            shuffled_frames
         } else {
-            // Regular frames
-            &self.cache.frames
+            // (regular frames, or frames for odd PingPong repetitions, or frames for the first repetition)
+            self.cache
+                .repetition_frames(self.next_frame_progress.repetition)
         };
 
+        let frame_count = cached_frames.len();
+
         // Fetch the current frame
 
         cached_frames
@@ -174,12 +222,15 @@ impl Iterator for AnimationIterator {
                     atlas_index: cached_frame.atlas_index,
                     duration: cached_frame.duration,
                     clip_id: cached_frame.clip_id,
+                    clip_index: cached_frame.clip_index,
                     clip_repetition: cached_frame.clip_repetition,
                     animation_repetition: current_frame_progress.repetition,
                     events: Self::promote_events(
                         &cached_frame.events,
                         current_frame_progress.repetition,
                     ),
+                    flip_x: cached_frame.flip_x,
+                    fade: cached_frame.fade,
                 };
 
                 // Inject the missing end events in the returned frame
@@ -213,7 +264,7 @@ impl Iterator for AnimationIterator {
 
                 // Go back to the start if we reached the end
 
-                if self.next_frame_progress.frame >= self.cache.frames.len() {
+                if self.next_frame_progress.frame >= frame_count {
                     self.next_frame_progress.repetition += 1;
 
                     // Mark that an animation repetition just ended so that the appropriate events are emitted on the next frame
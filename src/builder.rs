@@ -8,7 +8,7 @@ use crate::{CRATE_NAME, prelude::*};
 ///
 /// Set playback parameters with [set_duration()](AnimationBuilder::set_duration), [set_repetitions()](AnimationBuilder::set_repetitions), etc...
 ///
-/// Add additional clips with [start_clip()](AnimationBuilder::start_clip) and [copy_clip()](AnimationBuilder::copy_clip) (only needed for complex animations).
+/// Add additional clips with [start_clip()](AnimationBuilder::start_clip), [copy_clip()](AnimationBuilder::copy_clip) and [mirror_clip()](AnimationBuilder::mirror_clip) (only needed for complex animations).
 ///
 /// Set clip-level playback parameters with [set_clip_duration()](AnimationBuilder::set_clip_duration), [set_clip_repetitions()](AnimationBuilder::set_clip_repetitions), etc...
 ///
@@ -35,9 +35,43 @@ impl AnimationBuilder {
         self
     }
 
+    /// Sets the duration of the frames of the whole animation from a frame rate.
+    ///
+    /// This is the same as calling [set_duration()](AnimationBuilder::set_duration) with [AnimationDuration::PerFrameExact], without rounding the duration of the frames to the millisecond.
+    ///
+    /// # Arguments
+    ///
+    /// - `fps` - the number of frames per second, must be positive
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .start_clip()
+    ///     .add_row(1)
+    ///     // All the frames last 41.666...ms
+    ///     .set_fps(24.0)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn set_fps(self, fps: f32) -> Self {
+        if fps.is_finite() && fps > 0.0 {
+            self.set_duration(AnimationDuration::PerFrameExact(1000.0 / fps))
+        } else {
+            error!("{CRATE_NAME}: invalid frame rate {fps}");
+            self
+        }
+    }
+
     /// Sets the repetitions of the whole animation.
     ///
     /// If specified, this will be combined with the underlying clips' repetitions set with [set_clip_repetitions()](AnimationBuilder::set_clip_repetitions).
+    ///
+    /// Each repetition of the animation plays the whole sequence of clips, each clip playing all of its own repetitions.
     pub fn set_repetitions(mut self, repetitions: AnimationRepeat) -> Self {
         self.animation.repetitions = Some(repetitions);
         self
@@ -46,6 +80,8 @@ impl AnimationBuilder {
     /// Sets the direction of the whole animation.
     ///
     /// If specified, this will be combined with the underlying clips' directions set with [set_clip_direction()](AnimationBuilder::set_clip_direction).
+    ///
+    /// See [AnimationDirection::PingPong] for how ping-pong animations and ping-pong clips combine.
     pub fn set_direction(mut self, direction: AnimationDirection) -> Self {
         self.animation.direction = Some(direction);
         self
@@ -59,6 +95,98 @@ impl AnimationBuilder {
         self
     }
 
+    /// Sets a delay before the animation starts playing.
+    ///
+    /// During the delay, the first frame of the animation is displayed but playback does not advance.
+    ///
+    /// The delay only applies once when the animation starts, not on each repetition.
+    ///
+    /// # Arguments
+    ///
+    /// - `ms` - the duration of the delay in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// // A flicker effect that begins 200ms after being assigned to a sprite
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(4)
+    ///     .set_delay(200)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn set_delay(mut self, ms: u32) -> Self {
+        self.animation.delay = ms;
+        self
+    }
+
+    /// Sets the atlas index to display once the animation is over.
+    ///
+    /// By default, the last frame of an animation stays displayed when it ends.
+    /// This allows to jump to a "resting" frame instead, which does not have to be part of the animation.
+    ///
+    /// This has no effect on animations that loop forever, or on animations that are [interrupting](crate::prelude::SpritesheetAnimation::play_once_then_resume) others as they do not end.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - the atlas index to display
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// // Go back to the idle frame after the attack
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(3)
+    ///     .set_repetitions(AnimationRepeat::Times(1))
+    ///     .set_on_complete_atlas_index(0)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn set_on_complete_atlas_index(mut self, index: usize) -> Self {
+        if index >= self.spritesheet.total_frame_count() {
+            error!(
+                "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
+                self.spritesheet.total_frame_count()
+            );
+        } else {
+            self.animation.on_complete_atlas_index = Some(self.spritesheet.atlas_index(index));
+        }
+
+        self
+    }
+
+    /// Sets a name for the animation.
+    ///
+    /// The name has no effect on playback.
+    /// It identifies the animation in its `Debug` output and in the logs of the crate, which is convenient when debugging games with many animations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(3)
+    ///     .set_animation_name("hero/attack")
+    ///     .build();
+    ///
+    /// assert_eq!(animation.name(), Some("hero/attack"));
+    /// # }
+    /// ```
+    pub fn set_animation_name(mut self, name: &str) -> Self {
+        self.animation.debug_name = Some(name.to_owned());
+        self
+    }
+
     /// Creates a new clip in the animation.
     ///
     /// All the clip-related functions ([get_current_clip_id()](AnimationBuilder::get_current_clip_id), [set_clip_duration()](AnimationBuilder::set_clip_duration), ...) will apply to this new clip until another one is created.
@@ -132,1094 +260,3709 @@ impl AnimationBuilder {
         self
     }
 
-    fn current_clip(&self) -> &Clip {
-        self.animation.clips.last().unwrap()
-    }
-
-    fn current_clip_mut(&mut self) -> &mut Clip {
-        self.animation.clips.last_mut().unwrap()
-    }
-
-    /// Returns the ID of the current clip (ie. the last one added to the animation).
+    /// Appends copies of two clips that are already part of the animation, alternating between them.
     ///
-    /// Clip IDs are useful in a few cases:
-    /// - You want to [copy a clip](AnimationBuilder::copy_clip()).
-    /// - You want to check if an [animation event](crate::prelude::AnimationEvent) is associated with a specific clip.
+    /// This is the same as calling [copy_clip()](AnimationBuilder::copy_clip) with `a` then `b`, `count` times.
+    /// This is convenient for effects that alternate between two clips, such as a hit flash that alternates between normal and inverted frames.
+    ///
+    /// The last copy of `b` becomes the current clip.
     ///
     /// # Arguments
     ///
-    /// - `clip_id` - the clip ID that will be assigned the value of the current clip's
+    /// - `a` - the ID of the first clip of each pair
+    /// - `b` - the ID of the second clip of each pair
+    /// - `count` - the number of `a` + `b` pairs to append
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_spritesheet_animation::prelude::*;
     /// # fn f(spritesheet: &Spritesheet) {
-    /// // Create clip IDs
-    ///
-    /// let mut some_clip_id = ClipId::dummy();
-    /// let mut another_clip_id = ClipId::dummy();
-    ///
-    /// // The IDs are initially invalid but they can be written to with get_current_clip_id()
+    /// let mut normal_clip_id = ClipId::dummy();
+    /// let mut flash_clip_id = ClipId::dummy();
     ///
     /// let animation = spritesheet
     ///     .create_animation()
-    ///     // Clip 1 (the default clip)
     ///     .add_row(0)
-    ///     .get_current_clip_id(&mut some_clip_id)
-    ///     // Clip 2
+    ///     .get_current_clip_id(&mut normal_clip_id)
     ///     .start_clip()
-    ///     .add_row(3)
-    ///     .get_current_clip_id(&mut another_clip_id)
+    ///     .add_row(1)
+    ///     .get_current_clip_id(&mut flash_clip_id)
+    ///     // normal → flash → normal → flash → normal → flash
+    ///     .interleave_clip(normal_clip_id, flash_clip_id, 2)
     ///     .build();
     ///
-    /// // You can use those clip IDs later, for instance with animation events
+    /// assert_eq!(animation.num_clips(), 6);
     /// # }
     /// ```
-    pub fn get_current_clip_id(self, id: &mut ClipId) -> Self {
-        *id = self.current_clip().id();
-        self
-    }
-
-    /// Sets the duration of the current clip.
-    ///
-    /// If specified, this will be combined with the animation's duration set with [set_duration()](AnimationBuilder::set_duration).
-    pub fn set_clip_duration(mut self, duration: AnimationDuration) -> Self {
-        self.current_clip_mut().duration = Some(duration);
-        self
-    }
-
-    /// Sets the repetitions of the current clip.
-    ///
-    /// If specified, this will be combined with the animation's repetitions set with [set_repetitions()](AnimationBuilder::set_repetitions).
-    pub fn set_clip_repetitions(mut self, repetitions: usize) -> Self {
-        self.current_clip_mut().repetitions = Some(repetitions);
-        self
-    }
-
-    /// Sets the direction of the current clip.
-    ///
-    /// If specified, this will be combined with the animation's direction set with [set_direction()](AnimationBuilder::set_direction).
-    pub fn set_clip_direction(mut self, direction: AnimationDirection) -> Self {
-        self.current_clip_mut().direction = Some(direction);
-        self
-    }
+    pub fn interleave_clip(mut self, a: ClipId, b: ClipId, count: usize) -> Self {
+        let find_clip = |clip_id: ClipId| {
+            self.animation
+                .clips
+                .iter()
+                .find(|clip| clip.id() == clip_id)
+                .cloned()
+        };
+
+        match (find_clip(a), find_clip(b)) {
+            (Some(clip_a), Some(clip_b)) => {
+                for _ in 0..count {
+                    self.animation.clips.push(clip_a.clone());
+                    self.animation.clips.push(clip_b.clone());
+                }
+            }
+            (None, _) => error!(
+                "{CRATE_NAME}: clip {} is not part of the animation",
+                a.value
+            ),
+            (_, None) => error!(
+                "{CRATE_NAME}: clip {} is not part of the animation",
+                b.value
+            ),
+        }
 
-    /// Sets the easing of the current clip.
-    ///
-    /// If specified, this will be combined with the animation's easing set with [set_easing()](AnimationBuilder::set_easing).
-    pub fn set_clip_easing(mut self, easing: Easing) -> Self {
-        self.current_clip_mut().easing = Some(easing);
         self
     }
 
-    /// Adds a marker on a specific frame of the current clip.
+    /// Copies a clip that is already part of the the animation, replacing some of its parameters.
     ///
-    /// Multiple markers can be added to the same frame.
+    /// This is the same as [copy_clip()](AnimationBuilder::copy_clip) followed by calls to `set_clip_xxx()` for each parameter specified in `overrides`.
+    /// The parameters left to `None` are kept from the source clip.
     ///
     /// # Arguments
     ///
-    /// - `marker` - the marker to add
-    /// - `frame_index` - the index of the frame to associate the marker with (the index in the clip, not in the whole animation)
+    /// - `clip_id` - the ID of a clip that is already part of the the animation
+    /// - `overrides` - the parameters to replace
     ///
     /// # Example
+    ///
     /// ```
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # fn f(spritesheet: &Spritesheet) {    ///
-    /// let marker1 = Marker::new();
-    /// let marker2 = Marker::new();
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let mut walk_clip_id = ClipId::dummy();
     ///
     /// let animation = spritesheet
     ///     .create_animation()
+    ///     // Clip 1: walk
     ///     .add_row(0)
-    ///     .add_clip_marker(marker1, 1)
-    ///     .add_clip_marker(marker2, 4)
+    ///     .set_clip_duration(AnimationDuration::PerFrame(100))
+    ///     .set_clip_easing(Easing::In(EasingVariety::Quadratic))
+    ///     .get_current_clip_id(&mut walk_clip_id)
+    ///     // Clip 2: same frames and easing, but faster and backwards
+    ///     .copy_clip_with_overrides(
+    ///         walk_clip_id,
+    ///         ClipOverride {
+    ///             duration: Some(AnimationDuration::PerFrame(50)),
+    ///             direction: Some(AnimationDirection::Backwards),
+    ///             ..Default::default()
+    ///         },
+    ///     )
     ///     .build();
     /// # }
     /// ```
-    pub fn add_clip_marker(mut self, marker: Marker, frame_index: usize) -> Self {
-        let clip = self.current_clip_mut();
+    pub fn copy_clip_with_overrides(mut self, clip_id: ClipId, overrides: ClipOverride) -> Self {
+        let clip = self
+            .animation
+            .clips
+            .iter()
+            .find(|clip| clip.id() == clip_id);
 
-        if frame_index >= clip.atlas_indices().len() {
-            error!(
-                "{CRATE_NAME}: frame {frame_index} exceeds the clip size ({})",
-                clip.atlas_indices().len()
-            );
-        } else {
-            let frame_markers = clip.markers.entry(frame_index).or_default();
+        match clip {
+            Some(clip) => {
+                let mut clip = clip.clone();
 
-            frame_markers.push(marker);
+                if overrides.duration.is_some() {
+                    clip.duration = overrides.duration;
+                }
+
+                if overrides.repetitions.is_some() {
+                    clip.repetitions = overrides.repetitions;
+                }
+
+                if overrides.direction.is_some() {
+                    clip.direction = overrides.direction;
+                }
+
+                if overrides.easing.is_some() {
+                    clip.easing = overrides.easing;
+                }
+
+                self.animation.clips.push(clip);
+            }
+            None => error!(
+                "{CRATE_NAME}: clip {} is not part of the animation",
+                clip_id.value
+            ),
         }
 
         self
     }
 
-    /// Adds the frames at the given indices of the spritesheet to the current clip.
+    /// Creates a horizontally-mirrored version of a clip that is already part of the animation.
     ///
-    /// Indices increase from left-to-right and top-to-bottom.
+    /// This is convenient when a spritesheet contains left-facing frames in a row and the same frames facing right, in reverse order, in another row.
+    ///
+    /// Each frame of the source clip at column `C` is replaced by the frame of `target_row` at column `columns - 1 - C`.
+    /// Since the spritesheet is a regular grid, the source and target rows are assumed to have the same number of columns.
+    ///
+    /// The new clip keeps the parameters and markers of the source clip but gets its own [ClipId].
+    ///
+    /// Like after [start_clip()](AnimationBuilder::start_clip), all the clip-related functions will apply to this clip until another one is created/copied.
+    ///
+    /// # Arguments
+    ///
+    /// - `source_clip_id` - the ID of a clip that is already part of the animation
+    /// - `target_row` - the row that contains the mirrored frames
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌───┐
-    /// // │0 1│
-    /// // │2 3│
-    /// // └───┘
+    /// // ┌───────┐
+    /// // │0 1 2 3│ ← facing left
+    /// // │4 5 6 7│ ← facing right
+    /// // └───────┘
     ///
     /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 2, 2)
+    /// # let image = Handle::default();
+    /// let mut walk_left_clip_id = ClipId::dummy();
+    ///
+    /// let animation = Spritesheet::new(&image, 4, 2)
     ///     .create_animation()
-    ///     .add_indices([0, 3])
+    ///     .add_indices([0, 1, 2])
+    ///     .get_current_clip_id(&mut walk_left_clip_id)
+    ///     .mirror_clip(walk_left_clip_id, 1)
     ///     .build();
     ///
-    /// // This clip will play frames 0 → 3
+    /// // The mirrored clip will play frames 7 → 6 → 5
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// let mirrored_clip = animation.clips().last().unwrap();
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![0, 3]);
+    /// assert_eq!(mirrored_clip.atlas_indices(), vec![7, 6, 5]);
     /// ```
-    pub fn add_indices(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
-        for index in indices {
-            if index >= self.spritesheet.columns() * self.spritesheet.rows() {
-                error!(
-                    "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
-                    self.spritesheet.columns() * self.spritesheet.rows()
-                );
-            } else {
-                self.current_clip_mut().atlas_indices.push(index);
+    pub fn mirror_clip(mut self, source_clip_id: ClipId, target_row: usize) -> Self {
+        if target_row >= self.spritesheet.rows() {
+            error!(
+                "{CRATE_NAME}: row {target_row} exceeds the spritesheet size ({})",
+                self.spritesheet.rows()
+            );
+
+            return self;
+        }
+
+        let columns = self.spritesheet.columns();
+
+        let clip = self
+            .animation
+            .clips
+            .iter()
+            .find(|clip| clip.id() == source_clip_id);
+
+        match clip {
+            Some(clip) => {
+                let mut mirrored_clip = clip.duplicate();
+
+                mirrored_clip.atlas_indices = clip
+                    .atlas_indices
+                    .iter()
+                    .map(|index| {
+                        let mirrored_column =
+                            (columns - 1).saturating_sub(self.spritesheet.column_of(*index));
+
+                        self.spritesheet
+                            .atlas_index(target_row * columns + mirrored_column)
+                    })
+                    .collect();
+
+                self.animation.clips.push(mirrored_clip);
             }
+            None => error!(
+                "{CRATE_NAME}: clip {} is not part of the animation",
+                source_clip_id.value
+            ),
         }
 
         self
     }
 
-    /// Adds all the frames of the spritesheet to the current clip.
+    /// Adds a clip built separately with a [ClipBuilder] to the animation.
     ///
-    /// This is convenient if the whole spritesheet represents a single animation.
+    /// Like after [start_clip()](AnimationBuilder::start_clip), all the clip-related functions will apply to this clip until another one is created/added.
+    ///
+    /// If the current clip has no frames (for instance, the default clip of a new animation), it is replaced by the added clip.
+    ///
+    /// # Arguments
+    ///
+    /// - `clip` - the clip to add
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌───┐
-    /// // │0 1│
-    /// // │2 3│
-    /// // └───┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 2, 2)
-    ///     .create_animation()
-    ///     .add_all_cells()
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let clip = ClipBuilder::new(spritesheet)
+    ///     .add_row(0)
+    ///     .set_repetitions(3)
     ///     .build();
     ///
-    /// // This clip will play frames 0 → 1 → 2 → 3
-    ///
-    /// let clip = animation.clips().first().unwrap();
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_clip(clip)
+    ///     .build();
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![0, 1, 2, 3]);
+    /// assert_eq!(animation.clips().len(), 1);
+    /// # }
     /// ```
-    pub fn add_all_cells(mut self) -> Self {
-        let cols = self.spritesheet.columns();
-        let rows = self.spritesheet.rows();
+    pub fn add_clip(mut self, clip: Clip) -> Self {
+        if self.current_clip().atlas_indices().is_empty() {
+            *self.current_clip_mut() = clip;
+        } else {
+            self.animation.clips.push(clip);
+        }
+
+        self
+    }
+
+    fn current_clip(&self) -> &Clip {
+        self.animation.clips.last().unwrap()
+    }
 
-        self.current_clip_mut()
+    /// Checks that the atlas indices of a clip are part of the spritesheet
+    fn validate_clip(&self, clip: &Clip) -> Result<(), Vec<usize>> {
+        let invalid_indices: Vec<usize> = clip
             .atlas_indices
-            .extend(0..(cols * rows));
+            .iter()
+            .copied()
+            .filter(|index| !self.spritesheet.contains_atlas_index(*index))
+            .collect();
 
-        self
+        if invalid_indices.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_indices)
+        }
     }
 
-    /// Adds the frame at coordinates (x, y) of the spritesheet to the current clip.
+    fn current_clip_mut(&mut self) -> &mut Clip {
+        self.animation.clips.last_mut().unwrap()
+    }
+
+    /// Returns the ID of the current clip (ie. the last one added to the animation).
+    ///
+    /// Clip IDs are useful in a few cases:
+    /// - You want to [copy a clip](AnimationBuilder::copy_clip()).
+    /// - You want to check if an [animation event](crate::prelude::AnimationEvent) is associated with a specific clip.
+    ///
+    /// # Arguments
+    ///
+    /// - `clip_id` - the clip ID that will be assigned the value of the current clip's
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌───┐
-    /// // │0 1│
-    /// // │2 3│
-    /// // └───┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 2, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// // Create clip IDs
+    ///
+    /// let mut some_clip_id = ClipId::dummy();
+    /// let mut another_clip_id = ClipId::dummy();
+    ///
+    /// // The IDs are initially invalid but they can be written to with get_current_clip_id()
+    ///
+    /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_cell(1, 1)
-    ///     .add_cell(1, 0)
+    ///     // Clip 1 (the default clip)
+    ///     .add_row(0)
+    ///     .get_current_clip_id(&mut some_clip_id)
+    ///     // Clip 2
+    ///     .start_clip()
+    ///     .add_row(3)
+    ///     .get_current_clip_id(&mut another_clip_id)
     ///     .build();
     ///
-    /// // This clip will play frames 3 → 1
+    /// // You can use those clip IDs later, for instance with animation events
+    /// # }
+    /// ```
+    pub fn get_current_clip_id(self, id: &mut ClipId) -> Self {
+        *id = self.current_clip().id();
+        self
+    }
+
+    /// Names the current clip.
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// The name is only used for debugging: it appears in the debug output of the clip and in inspectors, and can be retrieved with [Clip::name()].
+    /// It does not affect playback nor the [ID](Clip::id) of the clip.
+    ///
+    /// # Example
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![3, 1]);
     /// ```
-    pub fn add_cell(mut self, x: usize, y: usize) -> Self {
-        let index = y * self.spritesheet.columns() + x;
-
-        if index >= self.spritesheet.columns() * self.spritesheet.rows() {
-            error!(
-                "{CRATE_NAME}: position ({x}, {y}) exceeds the spritesheet size ({}, {})",
-                self.spritesheet.columns(),
-                self.spritesheet.rows()
-            );
-        } else {
-            self.current_clip_mut().atlas_indices.push(index);
-        }
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .set_clip_name("attack")
+    ///     .build();
+    ///
+    /// assert_eq!(animation.clips()[0].name(), Some("attack"));
+    /// # }
+    /// ```
+    pub fn set_clip_name(mut self, name: &str) -> Self {
+        self.current_clip_mut().debug_name = Some(name.to_owned());
+        self
+    }
 
+    /// Sets the duration of the current clip.
+    ///
+    /// If specified, this will be combined with the animation's duration set with [set_duration()](AnimationBuilder::set_duration).
+    pub fn set_clip_duration(mut self, duration: AnimationDuration) -> Self {
+        self.current_clip_mut().duration = Some(duration);
         self
     }
 
-    /// Adds the frames at multiple disjoint coordinates of the spritesheet to the current clip.
+    /// Sets the duration of the frames of the current clip from a frame rate.
     ///
-    /// This is convenient if the frames that you're interested in are scattered all over the spritesheet.
+    /// This is the same as calling [set_clip_duration()](AnimationBuilder::set_clip_duration) with [AnimationDuration::PerFrameExact], without rounding the duration of the frames to the millisecond.
     ///
     /// # Arguments
     ///
-    /// - `positions` - the list of (x, y) positions, one per frame
+    /// - `fps` - the number of frames per second, must be positive
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌───┐
-    /// // │0 1│
-    /// // │2 3│
-    /// // └───┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 2, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_cells([(1, 0), (0, 1)])
+    ///     .add_row(0)
+    ///     // Each frame lasts 41.666...ms
+    ///     .set_clip_frame_rate(24.0)
     ///     .build();
+    /// # }
+    /// ```
+    pub fn set_clip_frame_rate(self, fps: f32) -> Self {
+        if fps.is_finite() && fps > 0.0 {
+            self.set_clip_duration(AnimationDuration::PerFrameExact(1000.0 / fps))
+        } else {
+            error!("{CRATE_NAME}: invalid frame rate {fps}");
+            self
+        }
+    }
+
+    /// Sets the repetitions of the current clip.
     ///
-    /// // This clip will play frames 1 → 2
+    /// If specified, this will be combined with the animation's repetitions set with [set_repetitions()](AnimationBuilder::set_repetitions).
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// The clip plays all of its repetitions during each repetition of the animation.
+    pub fn set_clip_repetitions(mut self, repetitions: usize) -> Self {
+        self.current_clip_mut().repetitions = Some(repetitions);
+        self
+    }
+
+    /// Sets the direction of the current clip.
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![1, 2]);
-    /// ```
-    pub fn add_cells(mut self, positions: impl IntoIterator<Item = (usize, usize)>) -> Self {
-        for (x, y) in positions {
-            self = self.add_cell(x, y);
-        }
+    /// If specified, this will be combined with the animation's direction set with [set_direction()](AnimationBuilder::set_direction).
+    ///
+    /// A ping-pong clip only alternates at each of its own repetitions, see [set_clip_repetitions()](AnimationBuilder::set_clip_repetitions).
+    pub fn set_clip_direction(mut self, direction: AnimationDirection) -> Self {
+        self.current_clip_mut().direction = Some(direction);
+        self
+    }
 
+    /// Sets the easing of the current clip.
+    ///
+    /// If specified, this will be combined with the animation's easing set with [set_easing()](AnimationBuilder::set_easing).
+    pub fn set_clip_easing(mut self, easing: Easing) -> Self {
+        self.current_clip_mut().easing = Some(easing);
         self
     }
 
-    /// Adds all the frames in a row of the spritesheet to the current clip.
+    /// Sets an offset for the first repetition of the current clip.
     ///
-    /// This is convenient if some spritesheet row contains a single animation.
+    /// The first repetition of the clip will start at the given frame instead of its first frame.
+    /// The following repetitions are played in full.
+    ///
+    /// The offset applies each time the clip starts, so it is applied again on every repetition of the animation.
+    /// To only skip frames the first time the clip is played, use [set_clip_start_frame()](AnimationBuilder::set_clip_start_frame) instead.
+    ///
+    /// This is convenient for seamless transitions when the previous clip already ends on a pose that appears partway through the current clip.
+    ///
+    /// The frames are counted in the order they are played, so this takes the clip's direction into account.
     ///
     /// # Arguments
     ///
-    /// - `row` - the index of the spritesheet row
+    /// - `frames` - the number of frames to skip
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────┐
-    /// // │0 1 2│
-    /// // │3 4 5│
-    /// // └─────┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 3, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
     ///     .create_animation()
+    ///     // Clip 1
+    ///     .add_row(0)
+    ///     // Clip 2: the first repetition starts at its third frame
+    ///     .start_clip()
     ///     .add_row(1)
+    ///     .set_clip_repetitions(4)
+    ///     .set_clip_offset(2)
     ///     .build();
-    ///
-    /// // This clip will play frames 3 → 4 → 5
-    ///
-    /// let clip = animation.clips().first().unwrap();
-    ///
-    /// assert_eq!(clip.atlas_indices(), vec![3, 4, 5]);
+    /// # }
     /// ```
-    pub fn add_row(mut self, row: usize) -> Self {
-        if row < self.spritesheet.rows() {
-            let cols = self.spritesheet.columns();
-            let first_index = row * cols;
-
-            self.current_clip_mut()
-                .atlas_indices
-                .extend(first_index..first_index + cols);
-        } else {
-            error!(
-                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
-                self.spritesheet.columns(),
-                self.spritesheet.rows()
-            );
-        }
-
+    pub fn set_clip_offset(mut self, frames: usize) -> Self {
+        self.current_clip_mut().offset = frames;
         self
     }
 
-    /// Adds the frames in a section of a row of the spritesheet to the current clip.
+    /// Sets the frame at which the current clip starts when it is played for the first time.
     ///
-    /// This is convenient if some spritesheet row contains an animation next to other unrelated frames.
+    /// Unlike an [offset](AnimationBuilder::set_clip_offset), this only applies to the first repetition of the clip during the first repetition of the animation.
+    /// All the following repetitions start from the first frame of the clip, including when the animation loops.
+    ///
+    /// This is convenient to enter a clip partway through when the animation starts, for instance to continue a motion started by another animation.
+    ///
+    /// The frames are counted in the order they are played, so this takes the clip's direction into account.
+    /// If the clip also has an offset, its first repetition starts at the furthest of the two frames.
     ///
     /// # Arguments
     ///
-    /// - `row` - the index of the spritesheet row
-    /// - `column_range` - the range of columns
+    /// - `frame` - the index of the frame to start from
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────────┐
-    /// // │0 1 2 3 4│
-    /// // │5 6 7 8 9│
-    /// // └─────────┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let spritesheet = Spritesheet::new(&image, 5, 2);
-    ///
-    /// // This clip will play frames 2 → 3 → 4
-    ///
+    /// # fn f(spritesheet: &Spritesheet) {
     /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_partial_row(0, 2..)
+    ///     // Starts at its third frame the first time, then plays in full
+    ///     .add_row(0)
+    ///     .set_clip_start_frame(2)
     ///     .build();
+    /// # }
+    /// ```
+    pub fn set_clip_start_frame(mut self, frame: usize) -> Self {
+        self.current_clip_mut().start_frame = frame;
+        self
+    }
+
+    /// Fades the sprite in at the start of the current clip.
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// The alpha of the sprite's color increases from 0 to 1 during the first `ms` milliseconds of the clip, including all of its repetitions.
+    /// This is typically useful to smooth the transitions between the clips of an animation.
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![2, 3, 4]);
+    /// The fades are applied on top of the alpha that the sprite had before the animation started playing, so the other clips are displayed with that original alpha.
+    /// It is restored once the animation is over or when the sprite switches to an animation without blended clips.
+    /// Other animations leave it untouched.
     ///
-    /// // This clip will play frames 6 → 7 → 8 → 9
+    /// # Arguments
     ///
+    /// - `ms` - the duration of the fade in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
     /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_partial_row(1, 1..=4)
+    ///     // Appear
+    ///     .add_row(0)
+    ///     .set_clip_blend_in(300)
+    ///     // Idle
+    ///     .start_clip()
+    ///     .add_row(1)
+    ///     .set_clip_repetitions(3)
+    ///     // Disappear
+    ///     .start_clip()
+    ///     .add_row(2)
+    ///     .set_clip_blend_out(300)
     ///     .build();
+    /// # }
+    /// ```
+    pub fn set_clip_blend_in(mut self, ms: u32) -> Self {
+        self.current_clip_mut().blend_in = ms;
+        self
+    }
+
+    /// Fades the sprite out at the end of the current clip.
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// The alpha of the sprite's color decreases from 1 to 0 during the last `ms` milliseconds of the clip, including all of its repetitions.
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![6, 7, 8, 9]);
+    /// See [AnimationBuilder::set_clip_blend_in()].
     ///
-    /// // This clip will play frames 7 → 8
+    /// # Arguments
     ///
-    /// let animation = spritesheet
-    ///     .create_animation()
-    ///     .add_partial_row(1, 2..4)
-    ///     .build();
+    /// - `ms` - the duration of the fade in milliseconds
+    pub fn set_clip_blend_out(mut self, ms: u32) -> Self {
+        self.current_clip_mut().blend_out = ms;
+        self
+    }
+
+    /// Adds a marker on a specific frame of the current clip.
     ///
-    /// let clip = animation.clips().first().unwrap();
+    /// Multiple markers can be added to the same frame.
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![7, 8]);
-    /// ```
-    pub fn add_partial_row<R: RangeBounds<usize>>(mut self, row: usize, column_range: R) -> Self {
-        if row >= self.spritesheet.rows() {
-            error!(
-                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
-                self.spritesheet.columns(),
-                self.spritesheet.rows()
+    /// # Arguments
+    ///
+    /// - `marker` - the marker to add
+    /// - `frame_index` - the index of the frame to associate the marker with (the index in the clip, not in the whole animation)
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {    ///
+    /// let marker1 = Marker::new();
+    /// let marker2 = Marker::new();
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .add_clip_marker(marker1, 1)
+    ///     .add_clip_marker(marker2, 4)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn add_clip_marker(mut self, marker: Marker, frame_index: usize) -> Self {
+        let clip = self.current_clip_mut();
+
+        if frame_index >= clip.atlas_indices().len() {
+            error!(
+                "{CRATE_NAME}: frame {frame_index} exceeds the clip size ({})",
+                clip.atlas_indices().len()
             );
         } else {
-            let first_column = match column_range.start_bound() {
-                std::ops::Bound::Included(index) => *index,
-                std::ops::Bound::Excluded(_index) => unreachable!(),
-                std::ops::Bound::Unbounded => 0,
-            };
-
-            let end_column = match column_range.end_bound() {
-                std::ops::Bound::Included(index) => (*index).saturating_add(1),
-                std::ops::Bound::Excluded(index) => *index,
-                std::ops::Bound::Unbounded => self.spritesheet.columns(),
-            };
-
-            if first_column >= self.spritesheet.columns() || end_column > self.spritesheet.columns()
-            {
-                error!(
-                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
-                    column_range.start_bound(),
-                    column_range.end_bound(),
-                    self.spritesheet.columns(),
-                    self.spritesheet.rows()
-                );
-            }
-
-            let first_index = row * self.spritesheet.columns()
-                + first_column.clamp(0, self.spritesheet.columns().saturating_sub(1));
-
-            let end_index =
-                row * self.spritesheet.columns() + end_column.clamp(0, self.spritesheet.columns());
+            let frame_markers = clip.markers.entry(frame_index).or_default();
 
-            self.current_clip_mut()
-                .atlas_indices
-                .extend(first_index..end_index);
+            frame_markers.push(marker);
         }
 
         self
     }
 
-    /// Adds all the frames in a column of the spritesheet to the current clip.
+    /// Restarts the easing of the current clip at a specific frame.
     ///
-    /// This is convenient if some spritesheet column contains a single animation.
+    /// The frames of each repetition of the clip are split at the frames with such an easing and each sequence of frames is eased separately: from its first frame until the next frame with an easing, in the order of playback.
+    /// The frames before the first frame with an easing keep the [easing of the clip](AnimationBuilder::set_clip_easing).
+    ///
+    /// This is useful for effects that repeat within a single clip, such as a bouncing ball that eases in for each bounce.
     ///
     /// # Arguments
     ///
-    /// - `column` - the index of the spritesheet column
+    /// - `frame_index` - the index of the frame at which the easing restarts (the index in the clip, not in the whole animation)
+    /// - `easing` - the easing to apply from this frame
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────┐
-    /// // │0 1 2│
-    /// // │3 4 5│
-    /// // └─────┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 3, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// // Two bounces of 4 frames, each easing in
+    ///
+    /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_column(1)
+    ///     .add_indices([0, 1, 2, 3, 0, 1, 2, 3])
+    ///     .set_clip_easing(Easing::In(EasingVariety::Quadratic))
+    ///     .set_clip_frame_easing(4, Easing::In(EasingVariety::Quadratic))
     ///     .build();
-    ///
-    /// // This clip will play frames 1 → 4
-    ///
-    /// let clip = animation.clips().first().unwrap();
-    ///
-    /// assert_eq!(clip.atlas_indices(), vec![1, 4]);
+    /// # }
     /// ```
-    pub fn add_column(mut self, column: usize) -> Self {
-        if column < self.spritesheet.columns() {
-            let cols = self.spritesheet.columns();
-            let rows = self.spritesheet.rows();
+    pub fn set_clip_frame_easing(mut self, frame_index: usize, easing: Easing) -> Self {
+        let clip = self.current_clip_mut();
 
-            self.current_clip_mut()
-                .atlas_indices
-                .extend((0..rows).map(|current_row| column + current_row * cols));
-        } else {
+        if frame_index >= clip.atlas_indices().len() {
             error!(
-                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
-                self.spritesheet.columns(),
-                self.spritesheet.rows()
+                "{CRATE_NAME}: frame {frame_index} exceeds the clip size ({})",
+                clip.atlas_indices().len()
             );
+        } else {
+            clip.frame_easings.insert(frame_index, easing);
         }
 
         self
     }
 
-    /// Adds the frames in a section of a column of the spritesheet to the current clip.
-    ///
-    /// This is convenient if some spritesheet column contains an animation among other unrelated frames.
-    ///
-    /// # Arguments
+    /// Adds the frames at the given indices of the spritesheet to the current clip.
     ///
-    /// - `column` - the index of the spritesheet column to add frames for
-    /// - `row_range` - the range of rows to add frames for
+    /// Indices increase from left-to-right and top-to-bottom.
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────────┐
-    /// // │0   1   2│
-    /// // |3   4   5│
-    /// // │6   7   8│
-    /// // │9  10  11│
-    /// // └─────────┘
+    /// // ┌───┐
+    /// // │0 1│
+    /// // │2 3│
+    /// // └───┘
     ///
     /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
     /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 3, 4)
+    /// let animation = Spritesheet::new(&image, 2, 2)
     ///     .create_animation()
-    ///     .add_partial_column(1, 1..)
+    ///     .add_indices([0, 3])
     ///     .build();
     ///
-    /// // This clip will play frames 4 → 7 → 10
+    /// // This clip will play frames 0 → 3
     ///
     /// let clip = animation.clips().first().unwrap();
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![4, 7, 10]);
+    /// assert_eq!(clip.atlas_indices(), vec![0, 3]);
     /// ```
-    pub fn add_partial_column<R: RangeBounds<usize>>(
-        mut self,
-        column: usize,
-        row_range: R,
-    ) -> Self {
-        let cols = self.spritesheet.columns();
-        let rows = self.spritesheet.rows();
-
-        if column >= cols {
-            error!(
-                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
-                cols, rows
-            );
-        } else {
-            let mut first_row = match row_range.start_bound() {
-                std::ops::Bound::Included(index) => *index,
-                std::ops::Bound::Excluded(_index) => unreachable!(),
-                std::ops::Bound::Unbounded => 0,
-            };
-
-            let mut end_row = match row_range.end_bound() {
-                std::ops::Bound::Included(index) => (*index).saturating_add(1),
-                std::ops::Bound::Excluded(index) => *index,
-                std::ops::Bound::Unbounded => rows,
-            };
-
-            if first_row >= rows || end_row > rows {
+    pub fn add_indices(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        for index in indices {
+            if index >= self.spritesheet.total_frame_count() {
                 error!(
-                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
-                    row_range.start_bound(),
-                    row_range.end_bound(),
-                    cols,
-                    rows
+                    "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
+                    self.spritesheet.total_frame_count()
                 );
-            }
-
-            first_row = first_row.clamp(0, rows.saturating_sub(1));
-
-            end_row = end_row.clamp(0, rows);
+            } else {
+                let atlas_index = self.spritesheet.atlas_index(index);
 
-            self.current_clip_mut()
-                .atlas_indices
-                .extend((first_row..end_row).map(|row| row * cols + column))
+                self.current_clip_mut().atlas_indices.push(atlas_index);
+            }
         }
 
         self
     }
 
-    /// Adds the frames in an horizontal strip of the spritesheet to the current clip, wrapping from row to row.
+    /// Adds the frame at the given index of the spritesheet to the current clip, with some markers.
     ///
-    /// This is convenient if an animation spans several rows of a spritesheet.
+    /// This is a shortcut for [AnimationBuilder::add_indices()] followed by [AnimationBuilder::add_clip_marker()] for each marker.
     ///
     /// # Arguments
     ///
-    /// - `x` - the x position of the beginning of the strip
-    /// - `y` - the y position of the beginning of the strip
-    /// - `count` - the number of frames to add
+    /// - `index` - the index of the frame in the spritesheet
+    /// - `tags` - the markers to add on the frame
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────┐
-    /// // │0 1 2│
-    /// // │3 4 5│
-    /// // └─────┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 3, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let footstep = Marker::new();
+    /// let dust = Marker::new();
+    ///
+    /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_horizontal_strip(2, 0, 3)
+    ///     .add_indices([0, 1])
+    ///     .add_frame_with_tags(2, &[footstep, dust])
+    ///     .add_indices([3])
     ///     .build();
     ///
-    /// // This clip will play frames 2 → 3 → 4
-    ///
     /// let clip = animation.clips().first().unwrap();
     ///
-    /// assert_eq!(clip.atlas_indices(), vec![2, 3, 4]);
+    /// assert_eq!(clip.markers_at(2), &[footstep, dust]);
+    /// # }
     /// ```
-    pub fn add_horizontal_strip(mut self, x: usize, y: usize, count: usize) -> Self {
-        let cols = self.spritesheet.columns();
-        let rows = self.spritesheet.rows();
+    pub fn add_frame_with_tags(self, index: usize, tags: &[Marker]) -> Self {
+        self.add_frames_with_uniform_tags(&[index], tags)
+    }
 
-        if x > cols || y > rows {
-            error!(
-                "{CRATE_NAME}: horizontal strip from {x}/{y} exceeds the spritesheet size ({}, {})",
-                cols, rows
-            );
-        } else {
-            let first_index = y * cols + x;
+    /// Adds the frames at the given indices of the spritesheet to the current clip, with the same markers on each of them.
+    ///
+    /// See [AnimationBuilder::add_frame_with_tags()].
+    ///
+    /// # Arguments
+    ///
+    /// - `indices` - the indices of the frames in the spritesheet
+    /// - `tags` - the markers to add on every frame
+    pub fn add_frames_with_uniform_tags(mut self, indices: &[usize], tags: &[Marker]) -> Self {
+        for index in indices.iter().copied() {
+            if index >= self.spritesheet.total_frame_count() {
+                error!(
+                    "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
+                    self.spritesheet.total_frame_count()
+                );
+            } else {
+                let atlas_index = self.spritesheet.atlas_index(index);
 
-            let last_index = (first_index + count).min(cols * rows);
+                let clip = self.current_clip_mut();
 
-            self.current_clip_mut()
-                .atlas_indices
-                .extend(first_index..last_index);
+                if !tags.is_empty() {
+                    clip.markers
+                        .entry(clip.atlas_indices.len())
+                        .or_default()
+                        .extend_from_slice(tags);
+                }
 
-            if last_index != first_index + count {
-                error!(
-                    "{CRATE_NAME}: horizontal strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
-                    cols, rows
-                );
+                clip.atlas_indices.push(atlas_index);
             }
         }
 
         self
     }
 
-    /// Adds the frames in an vertical strip of the spritesheet to the current clip, wrapping from column to column.
+    /// Holds the last frame of the current clip for some time.
     ///
-    /// This is convenient if an animation spans several columns of a spritesheet.
+    /// This adds a copy of the last frame to the clip, with a fixed duration that ignores the durations of the clip and of the animation.
+    /// This is convenient to sync animations to a beat without adding dummy frames to the spritesheet.
     ///
     /// # Arguments
     ///
-    /// - `x` - the x position of the beginning of the strip
-    /// - `y` - the y position of the beginning of the strip
-    /// - `count` - the number of frames to add
+    /// - `duration_ms` - the duration of the pause in milliseconds
     ///
     /// # Example
     ///
     /// ```
-    /// // ┌─────┐
-    /// // │0 1 2│
-    /// // │3 4 5│
-    /// // └─────┘
-    ///
-    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// # let image = &Handle::default();
-    /// let animation = Spritesheet::new(&image, 3, 2)
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
     ///     .create_animation()
-    ///     .add_vertical_strip(1, 0, 3)
+    ///     // Clip 1: hold the last frame for half a second
+    ///     .add_indices([0, 1, 2])
+    ///     .set_clip_duration(AnimationDuration::PerFrame(100))
+    ///     .add_pause(500)
+    ///     // Clip 2
+    ///     .start_clip()
+    ///     .add_indices([3, 4])
     ///     .build();
-    ///
-    /// // This clip will play frames 1 → 4 → 2
-    ///
-    /// let clip = animation.clips().first().unwrap();
-    ///
-    /// assert_eq!(clip.atlas_indices(), vec![1, 4, 2]);
+    /// # }
     /// ```
-    pub fn add_vertical_strip(mut self, x: usize, y: usize, count: usize) -> Self {
-        let cols = self.spritesheet.columns();
-        let rows = self.spritesheet.rows();
-
-        if x > cols || y > rows {
-            error!(
-                "{CRATE_NAME}: vertical strip from {x}/{y} exceeds the spritesheet size ({}, {})",
-                cols, rows
-            );
-        } else {
-            let available_count = (cols - (x + 1)) * rows + rows - y;
-
-            let clamped_count = count.min(available_count);
-
-            self.current_clip_mut()
-                .atlas_indices
-                .extend((0..clamped_count).map(|i| {
-                    let current_x = x + (y + i) / rows;
-                    let current_y = (y + i) % rows;
+    pub fn add_pause(mut self, duration_ms: u32) -> Self {
+        let clip = self.current_clip_mut();
 
-                    current_y * cols + current_x
-                }));
+        match clip.atlas_indices.last().copied() {
+            Some(atlas_index) => {
+                clip.frame_durations
+                    .insert(clip.atlas_indices.len(), duration_ms);
 
-            if clamped_count != count {
-                error!(
-                    "{CRATE_NAME}: vertical strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
-                    cols, rows
-                );
+                clip.atlas_indices.push(atlas_index);
             }
+            None => error!("{CRATE_NAME}: cannot add a pause to a clip with no frames"),
         }
 
         self
     }
 
-    /// Creates the final animation, ready to be registered as a Bevy asset.
+    /// Adds a frame to the current clip that keeps displaying the previous frame for some time.
     ///
-    /// The resulting animation handle can then be associated with a [SpritesheetAnimation] component to animate a sprite.
+    /// The animation clock advances during the virtual frame but the sprite is not updated, which is convenient for dead times such as a wait before a combo attack.
+    /// Like [pauses](AnimationBuilder::add_pause), the frame has a fixed duration that ignores the durations of the clip and of the animation.
     ///
-    /// # Example
+    /// Unlike pauses, the previous frame is the one displayed just before during playback, so a virtual frame also works at the start of a clip, in clips that play backwards, and holds flipped frames as they are.
+    /// A virtual frame at the very start of an animation holds the last frame of the animation.
     ///
-    /// ```
-    /// # use bevy::prelude::*;
+    /// # Arguments
+    ///
+    /// - `duration_ms` - the duration of the virtual frame in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```
     /// # use bevy_spritesheet_animation::prelude::*;
-    /// fn create_animated_sprite(
-    ///     mut commands: Commands,
-    ///     assets: Res<AssetServer>,
-    ///     mut animations: ResMut<Assets<Animation>>,
-    ///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
-    /// ) {
-    ///     let image = assets.load("character.png");
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     // First attack
+    ///     .add_row(0)
+    ///     // Wait before the combo, still displaying the last frame of the first attack
+    ///     .start_clip()
+    ///     .add_virtual_frame(300)
+    ///     // Second attack
+    ///     .add_row(1)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn add_virtual_frame(mut self, duration_ms: u32) -> Self {
+        let clip = self.current_clip_mut();
+
+        let frame_index = clip.atlas_indices.len();
+
+        clip.frame_durations.insert(frame_index, duration_ms);
+        clip.virtual_frames.insert(frame_index);
+
+        // (the atlas index is a placeholder replaced with the previous frame during playback)
+        clip.atlas_indices
+            .push(clip.atlas_indices.last().copied().unwrap_or_default());
+
+        self
+    }
+
+    /// Adds the frame at the given index of the spritesheet to the current clip several times in a row, with a fixed duration for each copy.
     ///
-    ///     let spritesheet =  Spritesheet::new(&image, 8, 8);
+    /// The durations ignore the durations of the clip and of the animation, like [pauses](AnimationBuilder::add_pause).
+    /// This is convenient to hold a frame, such as an impact, without adding dummy frames to the spritesheet.
     ///
-    ///     let animation = spritesheet
-    ///         .create_animation()
-    ///         .add_row(6)
-    ///         .build();
+    /// Unlike a single frame with a longer duration, each copy is a frame of its own, so it can be [tagged with markers](AnimationBuilder::add_clip_marker) separately.
     ///
-    ///     // Register the animation
+    /// # Arguments
     ///
-    ///     let animation_handle = animations.add(animation);
+    /// - `index` - the index of the frame in the spritesheet
+    /// - `count` - the number of copies of the frame to add
+    /// - `duration_ms` - the duration of each copy in milliseconds
     ///
-    ///     // Spawn the entity with a SpritesheetAnimation component
+    /// # Example
     ///
-    ///     commands.spawn((
-    ///         spritesheet.with_size_hint(800, 800).sprite(& mut atlas_layouts),
-    ///         SpritesheetAnimation::new(animation_handle),
-    ///     ));
-    /// }
     /// ```
-    pub fn build(self) -> Animation {
-        self.into()
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_indices([0, 1])
+    ///     // Hold the impact frame for 200ms
+    ///     .add_frame_loop(2, 4, 50)
+    ///     .add_indices([3, 4])
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn add_frame_loop(mut self, index: usize, count: usize, duration_ms: u32) -> Self {
+        if index >= self.spritesheet.total_frame_count() {
+            error!(
+                "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
+                self.spritesheet.total_frame_count()
+            );
+            return self;
+        }
+
+        let atlas_index = self.spritesheet.atlas_index(index);
+
+        let clip = self.current_clip_mut();
+
+        for _ in 0..count {
+            clip.frame_durations
+                .insert(clip.atlas_indices.len(), duration_ms);
+
+            clip.atlas_indices.push(atlas_index);
+        }
+
+        self
     }
-}
 
-impl From<AnimationBuilder> for Animation {
-    fn from(builder: AnimationBuilder) -> Self {
-        builder.animation
-    }
-}
+    /// Adds the frame at the given index of the spritesheet to the current clip, displayed during a time range of the clip.
+    ///
+    /// This is an alternative to durations and frame rates that is convenient to transcribe timings from an animation software.
+    /// The frame gets a [fixed duration](Clip::frame_durations) of `end_ms - start_ms` that ignores the durations and the easings of the clip and of the animation.
+    ///
+    /// The frames must be added in chronological order and their time ranges cannot overlap.
+    /// If a frame starts after the end of the previous one, the previous frame is held until then.
+    ///
+    /// As the time ranges are relative to the start of the clip, all its frames must be added with this method, starting at 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - the index of the frame in the spritesheet
+    /// - `start_ms` - the time at which the frame starts in the clip, in milliseconds
+    /// - `end_ms` - the time at which the frame ends in the clip, in milliseconds (exclusive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_frame_at_time(0, 0, 100)
+    ///     .add_frame_at_time(1, 100, 400)
+    ///     // Frame 1 is held from 400ms to 500ms
+    ///     .add_frame_at_time(2, 500, 550)
+    ///     .build();
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.frame_durations()[&1], 400);
+    /// # }
+    /// ```
+    pub fn add_frame_at_time(mut self, index: usize, start_ms: u32, end_ms: u32) -> Self {
+        if index >= self.spritesheet.total_frame_count() {
+            error!(
+                "{CRATE_NAME}: index {index} exceeds the spritesheet size ({})",
+                self.spritesheet.total_frame_count()
+            );
+            return self;
+        }
+
+        if end_ms <= start_ms {
+            error!(
+                "{CRATE_NAME}: the time range of frame {index} ({start_ms}ms → {end_ms}ms) is empty"
+            );
+            return self;
+        }
+
+        let atlas_index = self.spritesheet.atlas_index(index);
+
+        let clip = self.current_clip_mut();
+
+        // End of the timeline of the clip so far
+
+        let Some(clip_end_ms) = (0..clip.atlas_indices.len())
+            .map(|frame_index| clip.frame_durations.get(&frame_index))
+            .try_fold(0, |clip_end_ms, duration| {
+                duration.map(|duration| clip_end_ms + duration)
+            })
+        else {
+            error!(
+                "{CRATE_NAME}: cannot add frame {index} at a time range to a clip with frames that have no fixed duration"
+            );
+            return self;
+        };
+
+        if start_ms < clip_end_ms {
+            error!(
+                "{CRATE_NAME}: the time range of frame {index} ({start_ms}ms → {end_ms}ms) overlaps the previous frames that end at {clip_end_ms}ms"
+            );
+            return self;
+        }
+
+        // Hold the previous frame until the new one starts
+
+        if start_ms > clip_end_ms {
+            match clip.atlas_indices.len().checked_sub(1) {
+                Some(last_frame_index) => {
+                    *clip.frame_durations.entry(last_frame_index).or_default() +=
+                        start_ms - clip_end_ms;
+                }
+                None => {
+                    error!(
+                        "{CRATE_NAME}: the first frame of a clip must start at 0ms, not {start_ms}ms"
+                    );
+                    return self;
+                }
+            }
+        }
+
+        clip.frame_durations
+            .insert(clip.atlas_indices.len(), end_ms - start_ms);
+
+        clip.atlas_indices.push(atlas_index);
+
+        self
+    }
+
+    /// Adds all the frames of the spritesheet to the current clip.
+    ///
+    /// This is convenient if the whole spritesheet represents a single animation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───┐
+    /// // │0 1│
+    /// // │2 3│
+    /// // └───┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 2, 2)
+    ///     .create_animation()
+    ///     .add_all_cells()
+    ///     .build();
+    ///
+    /// // This clip will play frames 0 → 1 → 2 → 3
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn add_all_cells(mut self) -> Self {
+        let (cols, rows) = self.spritesheet.grid_dimensions();
+
+        let atlas_indices: Vec<_> = (0..(cols * rows))
+            .map(|index| self.spritesheet.atlas_index(index))
+            .collect();
+
+        self.current_clip_mut().atlas_indices.extend(atlas_indices);
+
+        self
+    }
+
+    /// Adds the frame at coordinates (x, y) of the spritesheet to the current clip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───┐
+    /// // │0 1│
+    /// // │2 3│
+    /// // └───┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 2, 2)
+    ///     .create_animation()
+    ///     .add_cell(1, 1)
+    ///     .add_cell(1, 0)
+    ///     .build();
+    ///
+    /// // This clip will play frames 3 → 1
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![3, 1]);
+    /// ```
+    pub fn add_cell(mut self, x: usize, y: usize) -> Self {
+        let index = y * self.spritesheet.columns() + x;
+
+        if index >= self.spritesheet.total_frame_count() {
+            error!(
+                "{CRATE_NAME}: position ({x}, {y}) exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+        } else {
+            let atlas_index = self.spritesheet.atlas_index(index);
+
+            self.current_clip_mut().atlas_indices.push(atlas_index);
+        }
+
+        self
+    }
+
+    /// Adds the frames at multiple disjoint coordinates of the spritesheet to the current clip.
+    ///
+    /// This is convenient if the frames that you're interested in are scattered all over the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `positions` - the list of (x, y) positions, one per frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───┐
+    /// // │0 1│
+    /// // │2 3│
+    /// // └───┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 2, 2)
+    ///     .create_animation()
+    ///     .add_cells([(1, 0), (0, 1)])
+    ///     .build();
+    ///
+    /// // This clip will play frames 1 → 2
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![1, 2]);
+    /// ```
+    pub fn add_cells(mut self, positions: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        for (x, y) in positions {
+            self = self.add_cell(x, y);
+        }
+
+        self
+    }
+
+    /// Adds the frames at multiple (row, column) positions of the spritesheet to the current clip.
+    ///
+    /// This is the same as [add_cells()](AnimationBuilder::add_cells) but with row-major coordinates, each pair being converted to the atlas index `row * columns + column`.
+    ///
+    /// Positions outside of the spritesheet's grid are ignored, including columns that would overflow into the next row.
+    ///
+    /// # Arguments
+    ///
+    /// - `indices` - the list of (row, column) positions, one per frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_frames_from_sprite_indices_2d(&[(0, 2), (1, 0), (1, 1)])
+    ///     .build();
+    ///
+    /// // This clip will play frames 2 → 3 → 4
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![2, 3, 4]);
+    /// ```
+    pub fn add_frames_from_sprite_indices_2d(mut self, indices: &[(usize, usize)]) -> Self {
+        let (columns, rows) = self.spritesheet.grid_dimensions();
+
+        for &(row, column) in indices {
+            if row >= rows || column >= columns {
+                error!(
+                    "{CRATE_NAME}: position (row {row}, column {column}) exceeds the spritesheet size ({columns} columns, {rows} rows)"
+                );
+            } else {
+                let atlas_index = self.spritesheet.atlas_index(row * columns + column);
+
+                self.current_clip_mut().atlas_indices.push(atlas_index);
+            }
+        }
+
+        self
+    }
+
+    /// Adds all the frames in a row of the spritesheet to the current clip.
+    ///
+    /// This is convenient if some spritesheet row contains a single animation.
+    ///
+    /// # Arguments
+    ///
+    /// - `row` - the index of the spritesheet row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_row(1)
+    ///     .build();
+    ///
+    /// // This clip will play frames 3 → 4 → 5
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![3, 4, 5]);
+    /// ```
+    pub fn add_row(mut self, row: usize) -> Self {
+        if row < self.spritesheet.rows() {
+            let cols = self.spritesheet.columns();
+            let first_index = row * cols;
+
+            let atlas_indices: Vec<_> = (first_index..first_index + cols)
+                .map(|index| self.spritesheet.atlas_index(index))
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+        } else {
+            error!(
+                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+        }
+
+        self
+    }
+
+    /// Adds all the frames in a row of the spritesheet to the current clip in reverse order, flipped horizontally.
+    ///
+    /// This is convenient for spritesheets that only contain the frames facing one direction: a walk cycle facing right can be mirrored to face left.
+    ///
+    /// The mirrored frames are displayed with the `flip_x` attribute of the sprite set.
+    /// Animations that contain mirrored frames control this attribute on every frame, so the other frames are displayed unflipped.
+    ///
+    /// # Arguments
+    ///
+    /// - `row` - the index of the spritesheet row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_row(1)
+    ///     .add_row_mirrored(1)
+    ///     .build();
+    ///
+    /// // This clip will play frames 3 → 4 → 5 and then the flipped frames 5 → 4 → 3
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![3, 4, 5, 5, 4, 3]);
+    /// assert!(clip.flipped_frames().contains(&3));
+    /// assert!(!clip.flipped_frames().contains(&2));
+    /// ```
+    pub fn add_row_mirrored(mut self, row: usize) -> Self {
+        if row < self.spritesheet.rows() {
+            let cols = self.spritesheet.columns();
+            let first_index = row * cols;
+
+            let atlas_indices: Vec<_> = (first_index..first_index + cols)
+                .rev()
+                .map(|index| self.spritesheet.atlas_index(index))
+                .collect();
+
+            let clip = self.current_clip_mut();
+
+            let first_frame = clip.atlas_indices.len();
+
+            clip.flipped_frames
+                .extend(first_frame..first_frame + atlas_indices.len());
+
+            clip.atlas_indices.extend(atlas_indices);
+        } else {
+            error!(
+                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+        }
+
+        self
+    }
+
+    /// Adds all the frames of several rows of the spritesheet to the current clip.
+    ///
+    /// The rows are added in the given order, which is equivalent to calling [add_row()](AnimationBuilder::add_row) for each of them.
+    ///
+    /// This is convenient if an animation spans multiple rows of the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `rows` - the indices of the spritesheet rows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // │6 7 8│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 3)
+    ///     .create_animation()
+    ///     .add_rows(&[2, 0])
+    ///     .build();
+    ///
+    /// // This clip will play frames 6 → 7 → 8 → 0 → 1 → 2
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![6, 7, 8, 0, 1, 2]);
+    /// ```
+    pub fn add_rows(mut self, rows: &[usize]) -> Self {
+        for row in rows {
+            self = self.add_row(*row);
+        }
+
+        self
+    }
+
+    /// Adds the frames in a section of a row of the spritesheet to the current clip.
+    ///
+    /// This is convenient if some spritesheet row contains an animation next to other unrelated frames.
+    ///
+    /// # Arguments
+    ///
+    /// - `row` - the index of the spritesheet row
+    /// - `column_range` - the range of columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────────┐
+    /// // │0 1 2 3 4│
+    /// // │5 6 7 8 9│
+    /// // └─────────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 5, 2);
+    ///
+    /// // This clip will play frames 2 → 3 → 4
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_partial_row(0, 2..)
+    ///     .build();
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![2, 3, 4]);
+    ///
+    /// // This clip will play frames 6 → 7 → 8 → 9
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_partial_row(1, 1..=4)
+    ///     .build();
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![6, 7, 8, 9]);
+    ///
+    /// // This clip will play frames 7 → 8
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_partial_row(1, 2..4)
+    ///     .build();
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![7, 8]);
+    /// ```
+    pub fn add_partial_row<R: RangeBounds<usize>>(mut self, row: usize, column_range: R) -> Self {
+        if row >= self.spritesheet.rows() {
+            error!(
+                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+        } else {
+            let first_column = match column_range.start_bound() {
+                std::ops::Bound::Included(index) => *index,
+                std::ops::Bound::Excluded(_index) => unreachable!(),
+                std::ops::Bound::Unbounded => 0,
+            };
+
+            let end_column = match column_range.end_bound() {
+                std::ops::Bound::Included(index) => (*index).saturating_add(1),
+                std::ops::Bound::Excluded(index) => *index,
+                std::ops::Bound::Unbounded => self.spritesheet.columns(),
+            };
+
+            if first_column >= self.spritesheet.columns() || end_column > self.spritesheet.columns()
+            {
+                error!(
+                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                    column_range.start_bound(),
+                    column_range.end_bound(),
+                    self.spritesheet.columns(),
+                    self.spritesheet.rows()
+                );
+            }
+
+            let first_index = row * self.spritesheet.columns()
+                + first_column.clamp(0, self.spritesheet.columns().saturating_sub(1));
+
+            let end_index =
+                row * self.spritesheet.columns() + end_column.clamp(0, self.spritesheet.columns());
+
+            let atlas_indices: Vec<_> = (first_index..end_index)
+                .map(|index| self.spritesheet.atlas_index(index))
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+        }
+
+        self
+    }
+
+    /// Adds all the frames in a column of the spritesheet to the current clip.
+    ///
+    /// This is convenient if some spritesheet column contains a single animation.
+    ///
+    /// # Arguments
+    ///
+    /// - `column` - the index of the spritesheet column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_column(1)
+    ///     .build();
+    ///
+    /// // This clip will play frames 1 → 4
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![1, 4]);
+    /// ```
+    pub fn add_column(mut self, column: usize) -> Self {
+        if column < self.spritesheet.columns() {
+            let (cols, rows) = self.spritesheet.grid_dimensions();
+
+            let atlas_indices: Vec<_> = (0..rows)
+                .map(|current_row| self.spritesheet.atlas_index(column + current_row * cols))
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+        } else {
+            error!(
+                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+        }
+
+        self
+    }
+
+    /// Adds the frames in a section of a column of the spritesheet to the current clip.
+    ///
+    /// This is convenient if some spritesheet column contains an animation among other unrelated frames.
+    ///
+    /// # Arguments
+    ///
+    /// - `column` - the index of the spritesheet column to add frames for
+    /// - `row_range` - the range of rows to add frames for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────────┐
+    /// // │0   1   2│
+    /// // |3   4   5│
+    /// // │6   7   8│
+    /// // │9  10  11│
+    /// // └─────────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 4)
+    ///     .create_animation()
+    ///     .add_partial_column(1, 1..)
+    ///     .build();
+    ///
+    /// // This clip will play frames 4 → 7 → 10
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![4, 7, 10]);
+    /// ```
+    pub fn add_partial_column<R: RangeBounds<usize>>(
+        mut self,
+        column: usize,
+        row_range: R,
+    ) -> Self {
+        let (cols, rows) = self.spritesheet.grid_dimensions();
+
+        if column >= cols {
+            error!(
+                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
+                cols, rows
+            );
+        } else {
+            let mut first_row = match row_range.start_bound() {
+                std::ops::Bound::Included(index) => *index,
+                std::ops::Bound::Excluded(_index) => unreachable!(),
+                std::ops::Bound::Unbounded => 0,
+            };
+
+            let mut end_row = match row_range.end_bound() {
+                std::ops::Bound::Included(index) => (*index).saturating_add(1),
+                std::ops::Bound::Excluded(index) => *index,
+                std::ops::Bound::Unbounded => rows,
+            };
+
+            if first_row >= rows || end_row > rows {
+                error!(
+                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                    row_range.start_bound(),
+                    row_range.end_bound(),
+                    cols,
+                    rows
+                );
+            }
+
+            first_row = first_row.clamp(0, rows.saturating_sub(1));
+
+            end_row = end_row.clamp(0, rows);
+
+            let atlas_indices: Vec<_> = (first_row..end_row)
+                .map(|row| self.spritesheet.atlas_index(row * cols + column))
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+        }
+
+        self
+    }
+
+    /// Adds the frames in an horizontal strip of the spritesheet to the current clip, wrapping from row to row.
+    ///
+    /// This is convenient if an animation spans several rows of a spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - the x position of the beginning of the strip
+    /// - `y` - the y position of the beginning of the strip
+    /// - `count` - the number of frames to add
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_horizontal_strip(2, 0, 3)
+    ///     .build();
+    ///
+    /// // This clip will play frames 2 → 3 → 4
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![2, 3, 4]);
+    /// ```
+    pub fn add_horizontal_strip(mut self, x: usize, y: usize, count: usize) -> Self {
+        let (cols, rows) = self.spritesheet.grid_dimensions();
+
+        if x > cols || y > rows {
+            error!(
+                "{CRATE_NAME}: horizontal strip from {x}/{y} exceeds the spritesheet size ({}, {})",
+                cols, rows
+            );
+        } else {
+            let first_index = y * cols + x;
+
+            let last_index = (first_index + count).min(cols * rows);
+
+            let atlas_indices: Vec<_> = (first_index..last_index)
+                .map(|index| self.spritesheet.atlas_index(index))
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+
+            if last_index != first_index + count {
+                error!(
+                    "{CRATE_NAME}: horizontal strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
+                    cols, rows
+                );
+            }
+        }
+
+        self
+    }
+
+    /// Adds the frames in an vertical strip of the spritesheet to the current clip, wrapping from column to column.
+    ///
+    /// This is convenient if an animation spans several columns of a spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - the x position of the beginning of the strip
+    /// - `y` - the y position of the beginning of the strip
+    /// - `count` - the number of frames to add
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │0 1 2│
+    /// // │3 4 5│
+    /// // └─────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = &Handle::default();
+    /// let animation = Spritesheet::new(&image, 3, 2)
+    ///     .create_animation()
+    ///     .add_vertical_strip(1, 0, 3)
+    ///     .build();
+    ///
+    /// // This clip will play frames 1 → 4 → 2
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![1, 4, 2]);
+    /// ```
+    pub fn add_vertical_strip(mut self, x: usize, y: usize, count: usize) -> Self {
+        let (cols, rows) = self.spritesheet.grid_dimensions();
+
+        if x > cols || y > rows {
+            error!(
+                "{CRATE_NAME}: vertical strip from {x}/{y} exceeds the spritesheet size ({}, {})",
+                cols, rows
+            );
+        } else {
+            let available_count = (cols - (x + 1)) * rows + rows - y;
+
+            let clamped_count = count.min(available_count);
+
+            let atlas_indices: Vec<_> = (0..clamped_count)
+                .map(|i| {
+                    let current_x = x + (y + i) / rows;
+                    let current_y = (y + i) % rows;
+
+                    self.spritesheet.atlas_index(current_y * cols + current_x)
+                })
+                .collect();
+
+            self.current_clip_mut().atlas_indices.extend(atlas_indices);
+
+            if clamped_count != count {
+                error!(
+                    "{CRATE_NAME}: vertical strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
+                    cols, rows
+                );
+            }
+        }
+
+        self
+    }
+
+    /// Creates the final animation, ready to be registered as a Bevy asset.
+    ///
+    /// The resulting animation handle can then be associated with a [SpritesheetAnimation] component to animate a sprite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn create_animated_sprite(
+    ///     mut commands: Commands,
+    ///     assets: Res<AssetServer>,
+    ///     mut animations: ResMut<Assets<Animation>>,
+    ///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    /// ) {
+    ///     let image = assets.load("character.png");
+    ///
+    ///     let spritesheet =  Spritesheet::new(&image, 8, 8);
+    ///
+    ///     let animation = spritesheet
+    ///         .create_animation()
+    ///         .add_row(6)
+    ///         .build();
+    ///
+    ///     // Register the animation
+    ///
+    ///     let animation_handle = animations.add(animation);
+    ///
+    ///     // Spawn the entity with a SpritesheetAnimation component
+    ///
+    ///     commands.spawn((
+    ///         spritesheet.with_size_hint(800, 800).sprite(& mut atlas_layouts),
+    ///         SpritesheetAnimation::new(animation_handle),
+    ///     ));
+    /// }
+    /// ```
+    pub fn build(self) -> Animation {
+        self.into()
+    }
+
+    /// Creates the final animation like [build()](AnimationBuilder::build) but checks its clips first.
+    ///
+    /// Returns an error for the first clip that:
+    /// - contains atlas indices that exceed the size of the spritesheet (see [validate()](AnimationBuilder::validate))
+    /// - does not contain any frame
+    /// - has an [offset](AnimationBuilder::set_clip_offset) or a [start frame](AnimationBuilder::set_clip_start_frame) that skips all of its frames
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 8, 8);
+    ///
+    /// let animation = spritesheet.create_animation().add_row(2).try_build();
+    ///
+    /// assert!(animation.is_ok());
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(2)
+    ///     .start_clip()
+    ///     .try_build();
+    ///
+    /// assert!(matches!(animation, Err(AnimationError::EmptyClip { .. })));
+    /// ```
+    pub fn try_build(self) -> Result<Animation, AnimationError> {
+        for clip in &self.animation.clips {
+            let frame_count = clip.atlas_indices().len();
+
+            if let Err(indices) = self.validate_clip(clip) {
+                return Err(AnimationError::InvalidFrameIndices {
+                    clip_id: clip.id(),
+                    indices,
+                });
+            }
+
+            if frame_count == 0 {
+                return Err(AnimationError::EmptyClip { clip_id: clip.id() });
+            }
+
+            if let Some(offset) = [clip.offset(), clip.start_frame()]
+                .into_iter()
+                .find(|offset| *offset >= frame_count)
+            {
+                return Err(AnimationError::UnsupportedOffset {
+                    clip_id: clip.id(),
+                    offset,
+                    frame_count,
+                });
+            }
+        }
+
+        Ok(self.build())
+    }
+
+    /// Creates the final animation like [build()](AnimationBuilder::build) and adds it to the animation assets.
+    ///
+    /// Returns the handle of the new asset, ready to be assigned to a [SpritesheetAnimation] component.
+    ///
+    /// # Arguments
+    ///
+    /// - `assets` - the animation assets to add the animation to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn create_animation(
+    ///     mut commands: Commands,
+    ///     mut animations: ResMut<Assets<Animation>>,
+    ///     # spritesheet: &Spritesheet,
+    /// ) {
+    ///     let animation_handle = spritesheet
+    ///         .create_animation()
+    ///         .add_row(6)
+    ///         .build_into(&mut animations);
+    ///
+    ///     commands.spawn(SpritesheetAnimation::new(animation_handle));
+    /// }
+    /// ```
+    pub fn build_into(self, assets: &mut Assets<Animation>) -> Handle<Animation> {
+        assets.add(self.build())
+    }
+
+    /// Checks that the atlas indices of all the clips are within the bounds of the spritesheet.
+    ///
+    /// Indices added with the builder's functions are always valid but clips added with [add_clip()](AnimationBuilder::add_clip) may have been built from another spritesheet.
+    ///
+    /// Returns the out-of-bounds indices as an error.
+    ///
+    /// See [Spritesheet::validate_indices()] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let large_spritesheet = Spritesheet::new(&image, 8, 8);
+    /// let small_spritesheet = Spritesheet::new(&image, 2, 2);
+    ///
+    /// let clip = ClipBuilder::new(&large_spritesheet)
+    ///     .add_indices([1, 5, 9])
+    ///     .build();
+    ///
+    /// let builder = small_spritesheet
+    ///     .create_animation()
+    ///     .add_clip(clip);
+    ///
+    /// assert_eq!(builder.validate(), Err(vec![5, 9]));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<usize>> {
+        let invalid_indices: Vec<usize> = self
+            .animation
+            .clips
+            .iter()
+            .filter_map(|clip| self.validate_clip(clip).err())
+            .flatten()
+            .collect();
+
+        if invalid_indices.is_empty() {
+            Ok(())
+        } else {
+            warn!(
+                "{CRATE_NAME}: indices {invalid_indices:?} exceed the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            );
+
+            Err(invalid_indices)
+        }
+    }
+}
+
+/// Parameters to replace when copying a clip with [AnimationBuilder::copy_clip_with_overrides()].
+///
+/// The parameters left to `None` are kept from the source clip.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ClipOverride {
+    /// The new duration of the clip, see [AnimationBuilder::set_clip_duration()]
+    pub duration: Option<AnimationDuration>,
+    /// The new number of repetitions of the clip, see [AnimationBuilder::set_clip_repetitions()]
+    pub repetitions: Option<usize>,
+    /// The new direction of the clip, see [AnimationBuilder::set_clip_direction()]
+    pub direction: Option<AnimationDirection>,
+    /// The new easing of the clip, see [AnimationBuilder::set_clip_easing()]
+    pub easing: Option<Easing>,
+}
+
+impl From<AnimationBuilder> for Animation {
+    fn from(builder: AnimationBuilder) -> Self {
+        builder.animation
+    }
+}
+
+/// A builder to create standalone clips from a spritesheet.
+///
+/// This is convenient to create clips once and reuse them across several animations with [AnimationBuilder::add_clip()].
+///
+/// The available functions mirror the clip-related functions of [AnimationBuilder].
+///
+/// Call [build()](ClipBuilder::build) to retrieve the final clip.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(spritesheet: &Spritesheet) {
+/// let walk_clip = ClipBuilder::new(spritesheet)
+///     .add_row(2)
+///     .set_duration(AnimationDuration::PerRepetition(800))
+///     .build();
+///
+/// // The same clip can be used in several animations
+///
+/// let walk_animation = spritesheet
+///     .create_animation()
+///     .add_clip(walk_clip.clone())
+///     .build();
+///
+/// let walk_then_jump_animation = spritesheet
+///     .create_animation()
+///     .add_clip(walk_clip)
+///     .start_clip()
+///     .add_row(3)
+///     .build();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ClipBuilder {
+    builder: AnimationBuilder,
+}
+
+impl ClipBuilder {
+    pub fn new(spritesheet: &Spritesheet) -> Self {
+        Self {
+            builder: AnimationBuilder::new(spritesheet.clone()),
+        }
+    }
+
+    /// Names the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_name()].
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.builder = self.builder.set_clip_name(name);
+        self
+    }
+
+    /// Sets the duration of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_duration()].
+    pub fn set_duration(mut self, duration: AnimationDuration) -> Self {
+        self.builder = self.builder.set_clip_duration(duration);
+        self
+    }
+
+    /// Sets the duration of the frames of the clip from a frame rate.
+    ///
+    /// See [AnimationBuilder::set_clip_frame_rate()].
+    pub fn set_frame_rate(mut self, fps: f32) -> Self {
+        self.builder = self.builder.set_clip_frame_rate(fps);
+        self
+    }
+
+    /// Sets the repetitions of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_repetitions()].
+    pub fn set_repetitions(mut self, repetitions: usize) -> Self {
+        self.builder = self.builder.set_clip_repetitions(repetitions);
+        self
+    }
+
+    /// Sets the direction of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_direction()].
+    pub fn set_direction(mut self, direction: AnimationDirection) -> Self {
+        self.builder = self.builder.set_clip_direction(direction);
+        self
+    }
+
+    /// Sets the easing of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_easing()].
+    pub fn set_easing(mut self, easing: Easing) -> Self {
+        self.builder = self.builder.set_clip_easing(easing);
+        self
+    }
+
+    /// Sets an offset for the first repetition of the clip, on every repetition of the animation.
+    ///
+    /// See [AnimationBuilder::set_clip_offset()].
+    pub fn set_offset(mut self, frames: usize) -> Self {
+        self.builder = self.builder.set_clip_offset(frames);
+        self
+    }
+
+    /// Sets the frame at which the clip starts when it is played for the first time.
+    ///
+    /// See [AnimationBuilder::set_clip_start_frame()].
+    pub fn set_start_frame(mut self, frame: usize) -> Self {
+        self.builder = self.builder.set_clip_start_frame(frame);
+        self
+    }
+
+    /// Fades the sprite in at the start of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_blend_in()].
+    pub fn set_blend_in(mut self, ms: u32) -> Self {
+        self.builder = self.builder.set_clip_blend_in(ms);
+        self
+    }
+
+    /// Fades the sprite out at the end of the clip.
+    ///
+    /// See [AnimationBuilder::set_clip_blend_out()].
+    pub fn set_blend_out(mut self, ms: u32) -> Self {
+        self.builder = self.builder.set_clip_blend_out(ms);
+        self
+    }
+
+    /// Adds a marker on a specific frame of the clip.
+    ///
+    /// See [AnimationBuilder::add_clip_marker()].
+    pub fn add_marker(mut self, marker: Marker, frame_index: usize) -> Self {
+        self.builder = self.builder.add_clip_marker(marker, frame_index);
+        self
+    }
+
+    /// Restarts the easing of the clip at a specific frame.
+    ///
+    /// See [AnimationBuilder::set_clip_frame_easing()].
+    pub fn set_frame_easing(mut self, frame_index: usize, easing: Easing) -> Self {
+        self.builder = self.builder.set_clip_frame_easing(frame_index, easing);
+        self
+    }
+
+    /// Adds the frames at the given indices of the spritesheet to the clip.
+    ///
+    /// See [AnimationBuilder::add_indices()].
+    pub fn add_indices(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.builder = self.builder.add_indices(indices);
+        self
+    }
+
+    /// Adds the frame at the given index of the spritesheet to the clip, with some markers.
+    ///
+    /// See [AnimationBuilder::add_frame_with_tags()].
+    pub fn add_frame_with_tags(mut self, index: usize, tags: &[Marker]) -> Self {
+        self.builder = self.builder.add_frame_with_tags(index, tags);
+        self
+    }
+
+    /// Adds the frames at the given indices of the spritesheet to the clip, with the same markers on each of them.
+    ///
+    /// See [AnimationBuilder::add_frames_with_uniform_tags()].
+    pub fn add_frames_with_uniform_tags(mut self, indices: &[usize], tags: &[Marker]) -> Self {
+        self.builder = self.builder.add_frames_with_uniform_tags(indices, tags);
+        self
+    }
+
+    /// Adds the frame at the given index of the spritesheet to the clip, displayed during a time range of the clip.
+    ///
+    /// See [AnimationBuilder::add_frame_at_time()].
+    pub fn add_frame_at_time(mut self, index: usize, start_ms: u32, end_ms: u32) -> Self {
+        self.builder = self.builder.add_frame_at_time(index, start_ms, end_ms);
+        self
+    }
+
+    /// Holds the last frame of the clip for some time.
+    ///
+    /// See [AnimationBuilder::add_pause()].
+    pub fn add_pause(mut self, duration_ms: u32) -> Self {
+        self.builder = self.builder.add_pause(duration_ms);
+        self
+    }
+
+    /// Adds a frame to the clip that keeps displaying the previous frame for some time.
+    ///
+    /// See [AnimationBuilder::add_virtual_frame()].
+    pub fn add_virtual_frame(mut self, duration_ms: u32) -> Self {
+        self.builder = self.builder.add_virtual_frame(duration_ms);
+        self
+    }
+
+    /// Adds the frame at the given index of the spritesheet to the clip several times in a row, with a fixed duration for each copy.
+    ///
+    /// See [AnimationBuilder::add_frame_loop()].
+    pub fn add_frame_loop(mut self, index: usize, count: usize, duration_ms: u32) -> Self {
+        self.builder = self.builder.add_frame_loop(index, count, duration_ms);
+        self
+    }
+
+    /// Adds the frames at multiple (row, column) positions of the spritesheet to the clip.
+    ///
+    /// See [AnimationBuilder::add_frames_from_sprite_indices_2d()].
+    pub fn add_frames_from_sprite_indices_2d(mut self, indices: &[(usize, usize)]) -> Self {
+        self.builder = self.builder.add_frames_from_sprite_indices_2d(indices);
+        self
+    }
+
+    /// Adds all the frames in a row of the spritesheet to the clip.
+    ///
+    /// See [AnimationBuilder::add_row()].
+    pub fn add_row(mut self, row: usize) -> Self {
+        self.builder = self.builder.add_row(row);
+        self
+    }
+
+    /// Adds all the frames in a row of the spritesheet to the clip in reverse order, flipped horizontally.
+    ///
+    /// See [AnimationBuilder::add_row_mirrored()].
+    pub fn add_row_mirrored(mut self, row: usize) -> Self {
+        self.builder = self.builder.add_row_mirrored(row);
+        self
+    }
+
+    /// Adds all the frames of several rows of the spritesheet to the clip.
+    ///
+    /// See [AnimationBuilder::add_rows()].
+    pub fn add_rows(mut self, rows: &[usize]) -> Self {
+        self.builder = self.builder.add_rows(rows);
+        self
+    }
+
+    /// Adds all the frames in a column of the spritesheet to the clip.
+    ///
+    /// See [AnimationBuilder::add_column()].
+    pub fn add_column(mut self, column: usize) -> Self {
+        self.builder = self.builder.add_column(column);
+        self
+    }
+
+    /// Creates the final clip.
+    pub fn build(self) -> Clip {
+        self.into()
+    }
+}
+
+impl From<ClipBuilder> for Clip {
+    fn from(builder: ClipBuilder) -> Self {
+        // The inner animation only ever contains the clip that we're building
+        builder.builder.animation.clips.into_iter().next().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod spritesheet {
+        use super::*;
+
+        struct Tester {
+            spritesheet: Spritesheet,
+        }
+
+        impl Tester {
+            fn new(columns: usize, rows: usize) -> Self {
+                Self {
+                    spritesheet: Spritesheet::new(&Handle::default(), columns, rows),
+                }
+            }
+
+            fn test<F>(&self, f: F, expected_indices: Vec<usize>)
+            where
+                F: Fn(AnimationBuilder) -> AnimationBuilder,
+            {
+                let builder = self.spritesheet.clone().create_animation();
+
+                let animation = f(builder).build();
+
+                let indices: Vec<_> = animation
+                    .clips()
+                    .iter()
+                    .flat_map(|clip| clip.atlas_indices().iter())
+                    .copied()
+                    .collect();
+
+                assert_eq!(indices, expected_indices);
+            }
+        }
+
+        #[test]
+        fn add_indices() {
+            let t = Tester::new(3, 2);
+
+            t.test(|b| b.add_indices([]), vec![]);
+            t.test(|b| b.add_indices([0]), vec![0]);
+            t.test(|b| b.add_indices([1, 3]), vec![1, 3]);
+            t.test(|b| b.add_indices([999]), vec![]);
+            t.test(|b| b.add_indices([2, 999, 4]), vec![2, 4]);
+        }
+
+        #[test]
+        fn frame_rect() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 5, 4);
+            let image_size = UVec2::new(50, 80);
+
+            assert_eq!(
+                spritesheet.frame_rect(0, image_size),
+                Some(URect::new(0, 0, 10, 20))
+            );
+            assert_eq!(
+                spritesheet.frame_rect(19, image_size),
+                Some(URect::new(40, 60, 50, 80))
+            );
+            assert_eq!(spritesheet.frame_rect(20, image_size), None);
+
+            // Sub-grids use their own indices
+
+            let sub_grid = spritesheet.sub_grid(1, 2, 2, 2);
+
+            assert_eq!(
+                sub_grid.frame_rect(1, image_size),
+                Some(URect::new(30, 20, 40, 40))
+            );
+            assert_eq!(sub_grid.frame_rect(4, image_size), None);
+
+            // Same results with a layout
+
+            let layout = TextureAtlasLayout::from_grid(UVec2::new(10, 20), 5, 4, None, None);
+
+            assert_eq!(
+                spritesheet.frame_rect_in_layout(19, &layout),
+                Some(URect::new(40, 60, 50, 80))
+            );
+            assert_eq!(
+                sub_grid.frame_rect_in_layout(1, &layout),
+                Some(URect::new(30, 20, 40, 40))
+            );
+            assert_eq!(sub_grid.frame_rect_in_layout(4, &layout), None);
+
+            // Layouts that do not match the spritesheet
+
+            let small_layout = TextureAtlasLayout::from_grid(UVec2::new(10, 20), 2, 2, None, None);
+
+            assert_eq!(spritesheet.frame_rect_in_layout(19, &small_layout), None);
+        }
+
+        #[test]
+        fn sub_grid() {
+            // 0  1  2  3  4
+            // 5  6  7  8  9
+            // 10 11 12 13 14
+            // 15 16 17 18 19
+
+            let spritesheet = Spritesheet::new(&Handle::default(), 5, 4);
+
+            // 6  7  8
+            // 11 12 13
+
+            let t = Tester {
+                spritesheet: spritesheet.sub_grid(1, 1, 2, 3),
+            };
+
+            assert_eq!(t.spritesheet.grid_dimensions(), (3, 2));
+
+            t.test(|b| b.add_indices([0, 4, 5]), vec![6, 12, 13]);
+            t.test(|b| b.add_indices([6]), vec![]);
+            t.test(|b| b.add_all_cells(), vec![6, 7, 8, 11, 12, 13]);
+            t.test(|b| b.add_row(1), vec![11, 12, 13]);
+            t.test(|b| b.add_column(2), vec![8, 13]);
+            t.test(|b| b.add_partial_row(0, 1..), vec![7, 8]);
+            t.test(|b| b.add_partial_column(0, 1..), vec![11]);
+            t.test(|b| b.add_cell(2, 1), vec![13]);
+            t.test(|b| b.add_frames_from_sprite_indices_2d(&[(1, 0)]), vec![11]);
+            t.test(|b| b.add_horizontal_strip(1, 0, 3), vec![7, 8, 11]);
+            t.test(|b| b.add_vertical_strip(0, 1, 2), vec![11, 7]);
+
+            let mut clip_id = ClipId::dummy();
+
+            let mirrored_animation = t
+                .spritesheet
+                .create_animation()
+                .add_indices([0, 1])
+                .get_current_clip_id(&mut clip_id)
+                .mirror_clip(clip_id, 1)
+                .build();
+
+            assert_eq!(mirrored_animation.clips()[1].atlas_indices(), &[13, 12]);
+
+            // Nested sub-grids
+
+            let nested_sub_grid = t.spritesheet.sub_grid(1, 1, 1, 2);
+
+            assert_eq!(
+                nested_sub_grid
+                    .create_animation()
+                    .add_all_cells()
+                    .build()
+                    .clips()[0]
+                    .atlas_indices(),
+                &[12, 13]
+            );
+
+            // Sub-grids exceeding the spritesheet are truncated
+
+            assert_eq!(spritesheet.sub_grid(3, 3, 4, 4).grid_dimensions(), (2, 1));
+
+            // Validation of the clips
+
+            assert_eq!(
+                t.spritesheet.create_animation().add_row(1).validate(),
+                Ok(())
+            );
+
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_indices([7, 9, 16])
+                .build();
+
+            assert_eq!(
+                t.spritesheet.create_animation().add_clip(clip).validate(),
+                Err(vec![9, 16])
+            );
+        }
+
+        #[test]
+        fn add_row_mirrored() {
+            let t = Tester::new(3, 2);
+
+            t.test(|b| b.add_row_mirrored(0), vec![2, 1, 0]);
+            t.test(|b| b.add_row_mirrored(1), vec![5, 4, 3]);
+            t.test(|b| b.add_row_mirrored(2), vec![]);
+
+            // Only the mirrored frames are flipped
+
+            let animation = t
+                .spritesheet
+                .create_animation()
+                .add_row(0)
+                .add_row_mirrored(0)
+                .add_row_mirrored(100)
+                .build();
+
+            let mut flipped_frames: Vec<_> = animation.clips()[0]
+                .flipped_frames()
+                .iter()
+                .copied()
+                .collect();
+
+            flipped_frames.sort();
+
+            assert_eq!(flipped_frames, vec![3, 4, 5]);
+        }
+
+        #[test]
+        fn validate() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 3, 2);
+
+            assert_eq!(spritesheet.validate_indices(&[]), Ok(()));
+            assert_eq!(spritesheet.validate_indices(&[0, 5, 2]), Ok(()));
+            assert_eq!(
+                spritesheet.validate_indices(&[6, 1, 100]),
+                Err(vec![6, 100])
+            );
+
+            // Builders with valid clips
+
+            assert_eq!(spritesheet.create_animation().validate(), Ok(()));
+            assert_eq!(spritesheet.create_animation().add_row(1).validate(), Ok(()));
+
+            // Builders with clips from a larger spritesheet
+
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 4, 4))
+                .add_indices([2, 7, 15])
+                .build();
+
+            assert_eq!(
+                spritesheet
+                    .create_animation()
+                    .add_row(0)
+                    .start_clip()
+                    .add_clip(clip)
+                    .validate(),
+                Err(vec![7, 15])
+            );
+        }
+
+        #[test]
+        fn try_build() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 3, 2);
+
+            // Valid animations
+
+            assert!(
+                spritesheet
+                    .create_animation()
+                    .add_row(1)
+                    .try_build()
+                    .is_ok()
+            );
+
+            assert!(
+                spritesheet
+                    .create_animation()
+                    .add_row(0)
+                    .set_clip_offset(2)
+                    .try_build()
+                    .is_ok()
+            );
+
+            // Invalid indices
+
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 4, 4))
+                .add_indices([2, 7, 15])
+                .build();
+
+            let clip_id = clip.id();
+
+            assert_eq!(
+                spritesheet.create_animation().add_clip(clip).try_build(),
+                Err(AnimationError::InvalidFrameIndices {
+                    clip_id,
+                    indices: vec![7, 15]
+                })
+            );
+
+            // Empty clips
+
+            let mut clip_id = ClipId::dummy();
+
+            assert_eq!(
+                spritesheet
+                    .create_animation()
+                    .add_row(0)
+                    .start_clip()
+                    .get_current_clip_id(&mut clip_id)
+                    .try_build(),
+                Err(AnimationError::EmptyClip { clip_id })
+            );
+
+            // Offsets skipping all the frames
+
+            assert_eq!(
+                spritesheet
+                    .create_animation()
+                    .add_row(0)
+                    .set_clip_offset(3)
+                    .get_current_clip_id(&mut clip_id)
+                    .try_build(),
+                Err(AnimationError::UnsupportedOffset {
+                    clip_id,
+                    offset: 3,
+                    frame_count: 3
+                })
+            );
+
+            assert_eq!(
+                spritesheet
+                    .create_animation()
+                    .add_row(0)
+                    .set_clip_start_frame(4)
+                    .get_current_clip_id(&mut clip_id)
+                    .try_build(),
+                Err(AnimationError::UnsupportedOffset {
+                    clip_id,
+                    offset: 4,
+                    frame_count: 3
+                })
+            );
+        }
+
+        #[test]
+        fn equality() {
+            use bevy::asset::uuid::Uuid;
+
+            let image1 = Handle::default();
+            let image2 = Handle::Uuid(Uuid::from_u128(42), default());
+
+            let spritesheet = Spritesheet::new(&image1, 3, 2);
+
+            assert_eq!(spritesheet, Spritesheet::new(&image1, 3, 2));
+            assert_ne!(spritesheet, Spritesheet::new(&image1, 2, 3));
+            assert_ne!(spritesheet, Spritesheet::new(&image2, 3, 2));
+
+            // Deduplication
+
+            let spritesheets = std::collections::HashSet::from([
+                Spritesheet::new(&image1, 3, 2),
+                Spritesheet::new(&image1, 3, 2),
+                Spritesheet::new(&image2, 3, 2),
+            ]);
+
+            assert_eq!(spritesheets.len(), 2);
+        }
+
+        #[test]
+        fn add_all_cells() {
+            let t1 = Tester::new(0, 0);
+            t1.test(|b| b.add_all_cells(), vec![]);
+
+            let t2 = Tester::new(2, 3);
+            t2.test(|b| b.add_all_cells(), vec![0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn add_cell() {
+            let t = Tester::new(4, 3);
+
+            t.test(|b| b.add_cell(0, 0), vec![0]);
+            t.test(|b| b.add_cell(2, 2).add_cell(0, 0), vec![10, 0]);
+            t.test(|b| b.add_cell(1000, 1), vec![]);
+            t.test(|b| b.add_cell(2, 1000), vec![]);
+        }
+
+        #[test]
+        fn add_cells() {
+            let t = Tester::new(4, 3);
+
+            t.test(|b| b.add_cells([]), vec![]);
+            t.test(|b| b.add_cells([(0, 0)]), vec![0]);
+            t.test(|b| b.add_cells([(2, 2)]), vec![10]);
+            t.test(|b| b.add_cells([(1, 1), (0, 0), (2, 2)]), vec![5, 0, 10]);
+            t.test(|b| b.add_cells([(100, 100)]), vec![]);
+            t.test(|b| b.add_cells([(1000, 0), (3, 0), (0, 2000)]), vec![3]);
+        }
+
+        #[test]
+        fn add_frames_from_sprite_indices_2d() {
+            let t = Tester::new(4, 3);
+
+            t.test(|b| b.add_frames_from_sprite_indices_2d(&[]), vec![]);
+            t.test(|b| b.add_frames_from_sprite_indices_2d(&[(0, 0)]), vec![0]);
+            t.test(|b| b.add_frames_from_sprite_indices_2d(&[(2, 1)]), vec![9]);
+            t.test(
+                |b| b.add_frames_from_sprite_indices_2d(&[(1, 3), (2, 0), (0, 1)]),
+                vec![7, 8, 1],
+            );
+
+            // Out of bounds, even if the flat index would fit in the spritesheet
+
+            t.test(|b| b.add_frames_from_sprite_indices_2d(&[(0, 4)]), vec![]);
+            t.test(
+                |b| b.add_frames_from_sprite_indices_2d(&[(3, 0), (1, 1), (0, 100)]),
+                vec![5],
+            );
+        }
+
+        #[test]
+        fn add_row() {
+            let t = Tester::new(3, 6);
+
+            t.test(|b| b.add_row(0), vec![0, 1, 2]);
+            t.test(|b| b.add_row(3), vec![9, 10, 11]);
+            t.test(|b| b.add_row(1000), vec![]);
+            t.test(|b| b.add_row(1000).add_row(1), vec![3, 4, 5]);
+        }
+
+        #[test]
+        fn add_rows() {
+            let t = Tester::new(3, 6);
+
+            t.test(|b| b.add_rows(&[]), vec![]);
+            t.test(|b| b.add_rows(&[0]), vec![0, 1, 2]);
+            t.test(|b| b.add_rows(&[3, 1]), vec![9, 10, 11, 3, 4, 5]);
+            t.test(|b| b.add_rows(&[2, 2]), vec![6, 7, 8, 6, 7, 8]);
+            t.test(|b| b.add_rows(&[1000, 5, 6]), vec![15, 16, 17]);
+        }
+
+        #[test]
+        fn add_partial_row() {
+            let t = Tester::new(5, 4);
+
+            // First row, starting at column 0
+
+            t.test(|b| b.add_partial_row(0, 0..0), vec![]);
+            t.test(|b| b.add_partial_row(0, 0..), vec![0, 1, 2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 0..2), vec![0, 1]);
+            t.test(|b| b.add_partial_row(0, 0..5), vec![0, 1, 2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 0..100), vec![0, 1, 2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 0..=0), vec![0]);
+            t.test(|b| b.add_partial_row(0, 0..=2), vec![0, 1, 2]);
+            t.test(|b| b.add_partial_row(0, 0..=100), vec![0, 1, 2, 3, 4]);
+
+            // First row, starting at another column
+
+            t.test(|b| b.add_partial_row(0, 2..2), vec![]);
+            t.test(|b| b.add_partial_row(0, 2..), vec![2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 1..3), vec![1, 2]);
+            t.test(|b| b.add_partial_row(0, 2..5), vec![2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 2..100), vec![2, 3, 4]);
+            t.test(|b| b.add_partial_row(0, 3..=3), vec![3]);
+            t.test(|b| b.add_partial_row(0, 3..=4), vec![3, 4]);
+            t.test(|b| b.add_partial_row(0, 4..=100), vec![4]);
+
+            // Other rows
+
+            t.test(|b| b.add_partial_row(1, 0..0), vec![]);
+            t.test(|b| b.add_partial_row(2, 0..), vec![10, 11, 12, 13, 14]);
+            t.test(|b| b.add_partial_row(3, 0..2), vec![15, 16]);
+            t.test(|b| b.add_partial_row(1, 0..5), vec![5, 6, 7, 8, 9]);
+            t.test(|b| b.add_partial_row(2, 0..100), vec![10, 11, 12, 13, 14]);
+            t.test(|b| b.add_partial_row(3, 0..=0), vec![15]);
+            t.test(|b| b.add_partial_row(3, 0..=2), vec![15, 16, 17]);
+            t.test(|b| b.add_partial_row(2, 0..=100), vec![10, 11, 12, 13, 14]);
+            t.test(|b| b.add_partial_row(100, 0..3), vec![]);
+            t.test(|b| b.add_partial_row(100, 0..=100), vec![]);
+        }
+
+        #[test]
+        fn add_column() {
+            let t = Tester::new(5, 3);
+
+            t.test(|b| b.add_column(0), vec![0, 5, 10]);
+            t.test(|b| b.add_column(1), vec![1, 6, 11]);
+            t.test(|b| b.add_column(1000), vec![]);
+            t.test(|b| b.add_column(1000).add_column(1), vec![1, 6, 11]);
+        }
+
+        #[test]
+        fn add_partial_column() {
+            let t = Tester::new(3, 4);
+
+            // First column, starting at row 0
+
+            t.test(|b| b.add_partial_column(0, 0..0), vec![]);
+            t.test(|b| b.add_partial_column(0, 0..), vec![0, 3, 6, 9]);
+            t.test(|b| b.add_partial_column(0, 0..2), vec![0, 3]);
+            t.test(|b| b.add_partial_column(0, 0..5), vec![0, 3, 6, 9]);
+            t.test(|b| b.add_partial_column(0, 0..100), vec![0, 3, 6, 9]);
+            t.test(|b| b.add_partial_column(0, 0..=0), vec![0]);
+            t.test(|b| b.add_partial_column(0, 0..=2), vec![0, 3, 6]);
+            t.test(|b| b.add_partial_column(0, 0..=100), vec![0, 3, 6, 9]);
+
+            // First column, starting at another row
+
+            t.test(|b| b.add_partial_column(0, 2..2), vec![]);
+            t.test(|b| b.add_partial_column(0, 2..), vec![6, 9]);
+            t.test(|b| b.add_partial_column(0, 1..3), vec![3, 6]);
+            t.test(|b| b.add_partial_column(0, 2..5), vec![6, 9]);
+            t.test(|b| b.add_partial_column(0, 2..100), vec![6, 9]);
+            t.test(|b| b.add_partial_column(0, 3..=3), vec![9]);
+            t.test(|b| b.add_partial_column(0, 2..=4), vec![6, 9]);
+            t.test(|b| b.add_partial_column(0, 3..=100), vec![9]);
+
+            // Other columns
+
+            t.test(|b| b.add_partial_column(1, 0..0), vec![]);
+            t.test(|b| b.add_partial_column(2, 0..), vec![2, 5, 8, 11]);
+            t.test(|b| b.add_partial_column(1, 0..2), vec![1, 4]);
+            t.test(|b| b.add_partial_column(1, 0..5), vec![1, 4, 7, 10]);
+            t.test(|b| b.add_partial_column(2, 0..100), vec![2, 5, 8, 11]);
+            t.test(|b| b.add_partial_column(1, 0..=0), vec![1]);
+            t.test(|b| b.add_partial_column(2, 0..=2), vec![2, 5, 8]);
+            t.test(|b| b.add_partial_column(2, 0..=100), vec![2, 5, 8, 11]);
+            t.test(|b| b.add_partial_column(100, 0..3), vec![]);
+            t.test(|b| b.add_partial_column(100, 0..=100), vec![]);
+        }
+
+        #[test]
+        fn add_horizontal_strip() {
+            let t = Tester::new(8, 8);
+
+            t.test(|b| b.add_horizontal_strip(0, 0, 3), vec![0, 1, 2]);
+            t.test(|b| b.add_horizontal_strip(6, 0, 4), vec![6, 7, 8, 9]);
+            t.test(|b| b.add_horizontal_strip(4, 5, 0), vec![]);
+            t.test(|b| b.add_horizontal_strip(100, 0, 1), vec![]);
+            t.test(|b| b.add_horizontal_strip(0, 100, 1), vec![]);
+            t.test(|b| b.add_horizontal_strip(6, 7, 1000), vec![62, 63]);
+        }
+
+        #[test]
+        fn add_vertical_strip() {
+            let t = Tester::new(4, 3);
+
+            t.test(|b| b.add_vertical_strip(0, 0, 2), vec![0, 4]);
+            t.test(|b| b.add_vertical_strip(1, 1, 6), vec![5, 9, 2, 6, 10, 3]);
+            t.test(|b| b.add_vertical_strip(100, 0, 1), vec![]);
+            t.test(|b| b.add_vertical_strip(0, 100, 1), vec![]);
+            t.test(|b| b.add_vertical_strip(3, 0, 1000), vec![3, 7, 11]);
+        }
+    }
+
+    mod clips {
+        use bevy::platform::collections::HashMap;
+
+        use super::*;
+
+        #[test]
+        fn id() {
+            let mut clip1_id = ClipId::dummy();
+            let mut clip2_id = ClipId::dummy();
+            let mut clip3_id = ClipId::dummy();
+
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .get_current_clip_id(&mut clip1_id)
+                .start_clip()
+                .get_current_clip_id(&mut clip2_id)
+                .start_clip()
+                .get_current_clip_id(&mut clip3_id)
+                .build();
+
+            assert_eq!(animation.clips().first().unwrap().id(), clip1_id);
+            assert_eq!(animation.clips().get(1).unwrap().id(), clip2_id);
+            assert_eq!(animation.clips().get(2).unwrap().id(), clip3_id);
+        }
+
+        #[test]
+        fn atlas_indices() {
+            let animation = Spritesheet::new(&Handle::default(), 3, 6)
+                .create_animation()
+                .add_row(1)
+                .start_clip()
+                .add_row(2)
+                .add_cell(1, 5)
+                .add_cell(2, 4)
+                .build();
+
+            let clip1 = animation.clips().first().unwrap();
+            assert_eq!(clip1.atlas_indices(), [3, 4, 5]);
+
+            let clip2 = animation.clips().last().unwrap();
+            assert_eq!(clip2.atlas_indices(), [6, 7, 8, 16, 14]);
+        }
+
+        #[test]
+        fn animation_fps() {
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(0)
+                .set_fps(24.0)
+                // Invalid frame rates are ignored
+                .set_fps(0.0)
+                .set_fps(f32::INFINITY)
+                .build();
+
+            assert_eq!(
+                animation.duration(),
+                &Some(AnimationDuration::PerFrameExact(1000.0 / 24.0))
+            );
+        }
+
+        #[test]
+        fn frame_rate() {
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(0)
+                .set_clip_frame_rate(25.0)
+                // Invalid frame rates are ignored
+                .start_clip()
+                .add_row(1)
+                .set_clip_frame_rate(0.0)
+                .set_clip_frame_rate(-10.0)
+                .set_clip_frame_rate(f32::NAN)
+                .build();
+
+            assert_eq!(
+                animation.clips()[0].duration(),
+                &Some(AnimationDuration::PerFrameExact(40.0))
+            );
+
+            assert_eq!(animation.clips()[1].duration(), &None);
+        }
+
+        #[test]
+        fn parameters() {
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                // Only the last set_clip_xxx() should apply
+                .set_clip_direction(AnimationDirection::Backwards)
+                .set_clip_direction(AnimationDirection::PingPong)
+                .set_clip_duration(AnimationDuration::PerRepetition(1000))
+                .set_clip_duration(AnimationDuration::PerFrame(2000))
+                .set_clip_repetitions(10)
+                .set_clip_repetitions(20)
+                .set_clip_easing(Easing::Linear)
+                .set_clip_easing(Easing::InOut(EasingVariety::Cubic))
+                // New clip with default parameters
+                .start_clip()
+                // The animation's set_xxx() should not alter the clip's parameters
+                .set_direction(AnimationDirection::Backwards)
+                .set_duration(AnimationDuration::PerFrame(123))
+                .set_repetitions(AnimationRepeat::Times(9))
+                .set_easing(Easing::In(EasingVariety::Quintic))
+                .set_animation_name("first")
+                .set_animation_name("walk")
+                .build();
+
+            // Animation
+
+            assert_eq!(animation.name(), Some("walk"));
+
+            assert!(matches!(
+                animation.direction(),
+                Some(AnimationDirection::Backwards)
+            ));
+
+            assert!(matches!(
+                animation.duration(),
+                Some(AnimationDuration::PerFrame(123))
+            ));
+
+            assert!(matches!(
+                animation.repetitions(),
+                Some(AnimationRepeat::Times(9))
+            ));
+
+            assert!(matches!(
+                animation.easing(),
+                Some(Easing::In(EasingVariety::Quintic))
+            ));
+
+            // Clip 1
+
+            let clip1 = animation.clips().first().unwrap();
+
+            assert!(matches!(
+                clip1.direction(),
+                Some(AnimationDirection::PingPong)
+            ));
+
+            assert!(matches!(
+                clip1.duration(),
+                Some(AnimationDuration::PerFrame(2000))
+            ));
+
+            assert!(matches!(clip1.repetitions(), Some(20)));
+
+            assert!(matches!(
+                clip1.easing(),
+                Some(Easing::InOut(EasingVariety::Cubic))
+            ));
+
+            let clip2 = animation.clips().last().unwrap();
+
+            // Clip 2
+
+            assert!(clip2.direction().is_none());
+            assert!(clip2.duration().is_none());
+            assert!(clip2.repetitions().is_none());
+            assert!(clip2.easing().is_none());
+        }
+
+        #[test]
+        fn add_clip() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+            let marker = Marker::new();
+
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_row(1)
+                .add_column(0)
+                .add_indices([63])
+                .add_marker(marker, 2)
+                .set_duration(AnimationDuration::PerFrame(250))
+                .set_repetitions(3)
+                .set_direction(AnimationDirection::Backwards)
+                .set_easing(Easing::Out(EasingVariety::Sin))
+                .build();
+
+            assert_eq!(
+                clip.atlas_indices(),
+                [
+                    8, 9, 10, 11, 12, 13, 14, 15, 0, 8, 16, 24, 32, 40, 48, 56, 63
+                ]
+            );
+            assert_eq!(clip.markers(), &HashMap::from([(2, vec![marker])]));
+            assert!(matches!(
+                clip.duration(),
+                Some(AnimationDuration::PerFrame(250))
+            ));
+            assert!(matches!(clip.repetitions(), Some(3)));
+            assert!(matches!(
+                clip.direction(),
+                Some(AnimationDirection::Backwards)
+            ));
+            assert!(matches!(
+                clip.easing(),
+                Some(Easing::Out(EasingVariety::Sin))
+            ));
+
+            // The empty default clip is replaced
+
+            let animation = spritesheet
+                .create_animation()
+                .add_clip(clip.clone())
+                .add_clip(clip.clone())
+                .start_clip()
+                .add_row(0)
+                .add_clip(clip.clone())
+                .build();
+
+            assert_eq!(animation.clips().len(), 4);
+            assert_eq!(animation.clips()[0].id(), clip.id());
+            assert_eq!(animation.clips()[1].id(), clip.id());
+            assert_eq!(
+                animation.clips()[2].atlas_indices(),
+                [0, 1, 2, 3, 4, 5, 6, 7]
+            );
+            assert_eq!(animation.clips()[3].id(), clip.id());
+        }
+
+        #[test]
+        fn copy_clip_with_overrides() {
+            let marker = Marker::new();
+
+            let mut clip_id = ClipId::dummy();
+
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(1)
+                .set_clip_duration(AnimationDuration::PerFrame(100))
+                .set_clip_repetitions(3)
+                .set_clip_direction(AnimationDirection::Backwards)
+                .add_clip_marker(marker, 2)
+                .get_current_clip_id(&mut clip_id)
+                // No overrides: exact copy
+                .copy_clip_with_overrides(clip_id, ClipOverride::default())
+                // Some overrides
+                .copy_clip_with_overrides(
+                    clip_id,
+                    ClipOverride {
+                        duration: Some(AnimationDuration::PerRepetition(2000)),
+                        easing: Some(Easing::Out(EasingVariety::Cubic)),
+                        ..default()
+                    },
+                )
+                // Unknown clip
+                .copy_clip_with_overrides(ClipId::dummy(), ClipOverride::default())
+                .build();
+
+            assert_eq!(animation.clips().len(), 3);
+
+            let source = &animation.clips()[0];
+            let copy = &animation.clips()[1];
+            let overridden = &animation.clips()[2];
+
+            assert_eq!(copy, source);
+
+            assert_eq!(overridden.atlas_indices(), source.atlas_indices());
+            assert_eq!(overridden.markers(), source.markers());
+            assert_eq!(
+                overridden.duration(),
+                &Some(AnimationDuration::PerRepetition(2000))
+            );
+            assert_eq!(overridden.repetitions(), &Some(3));
+            assert_eq!(overridden.direction(), &Some(AnimationDirection::Backwards));
+            assert_eq!(
+                overridden.easing(),
+                &Some(Easing::Out(EasingVariety::Cubic))
+            );
+        }
+
+        #[test]
+        fn mirror_clip() {
+            let marker = Marker::new();
+
+            let mut source_clip_id = ClipId::dummy();
+
+            let animation = Spritesheet::new(&Handle::default(), 5, 4)
+                .create_animation()
+                .add_indices([0, 1, 4, 8])
+                .set_clip_duration(AnimationDuration::PerFrame(300))
+                .set_clip_repetitions(2)
+                .add_clip_marker(marker, 1)
+                .get_current_clip_id(&mut source_clip_id)
+                .mirror_clip(source_clip_id, 3)
+                // Invalid row or clip, this should not work
+                .mirror_clip(source_clip_id, 4)
+                .mirror_clip(ClipId::dummy(), 0)
+                .build();
+
+            assert_eq!(animation.clips().len(), 2);
+
+            let mirrored_clip = animation.clips().last().unwrap();
+
+            assert_ne!(mirrored_clip.id(), source_clip_id);
+            assert_eq!(mirrored_clip.atlas_indices(), [19, 18, 15, 16]);
+
+            assert!(matches!(
+                mirrored_clip.duration(),
+                Some(AnimationDuration::PerFrame(300))
+            ));
+            assert_eq!(mirrored_clip.repetitions(), &Some(2));
+            assert_eq!(mirrored_clip.markers(), &HashMap::from([(1, vec![marker])]));
+        }
+
+        #[test]
+        fn named() {
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([1, 2, 3])
+                .set_name("jump")
+                .build();
+
+            assert_eq!(clip.name(), Some("jump"));
+            assert!(format!("{clip:?}").contains("\"jump\""));
+
+            // Clips with the same name still have their own IDs
+
+            let other_clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([4])
+                .set_name("jump")
+                .build();
+
+            assert_ne!(clip.id(), other_clip.id());
+
+            // Copies keep the name
+
+            assert_eq!(clip.with_loop_count(2).name(), Some("jump"));
+
+            // Anonymous clips have no name
+
+            let anonymous_clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([1, 2, 3])
+                .build();
+
+            assert_eq!(anonymous_clip.name(), None);
+        }
+
+        #[test]
+        fn markers_at() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
+
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([1, 2, 3])
+                .add_marker(marker1, 1)
+                .add_marker(marker2, 1)
+                .build();
+
+            assert_eq!(clip.markers_at(0), &[]);
+            assert_eq!(clip.markers_at(1), &[marker1, marker2]);
+            assert_eq!(clip.markers_at(99), &[]);
+        }
+
+        #[test]
+        fn named_markers() {
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([1, 2, 3])
+                .add_marker(Marker::named("footstep"), 1)
+                .add_marker(Marker::new(), 2)
+                .build();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            assert_eq!(clip.markers()[&1], vec![Marker::named("footstep")]);
+            assert_ne!(Marker::named("footstep"), Marker::named("jump"));
 
-    mod spritesheet {
-        use super::*;
+            assert_eq!(clip.markers()[&1][0].display_name(), Some("footstep"));
+            assert_eq!(clip.markers()[&2][0].display_name(), None);
+        }
 
-        struct Tester {
-            spritesheet: Spritesheet,
+        #[test]
+        fn with_loop_count() {
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([1, 2, 3])
+                .set_repetitions(4)
+                .set_easing(Easing::In(EasingVariety::Cubic))
+                .build();
+
+            let looped_clip = clip.with_loop_count(2);
+
+            assert_ne!(looped_clip.id(), clip.id());
+            assert_eq!(looped_clip.repetitions(), &Some(2));
+            assert_eq!(looped_clip.atlas_indices(), clip.atlas_indices());
+            assert_eq!(looped_clip.easing(), clip.easing());
+
+            // The original clip is left untouched
+
+            assert_eq!(clip.repetitions(), &Some(4));
         }
 
-        impl Tester {
-            fn new(columns: usize, rows: usize) -> Self {
-                Self {
-                    spritesheet: Spritesheet::new(&Handle::default(), columns, rows),
-                }
-            }
+        #[test]
+        fn with_frame_shuffled() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
 
-            fn test<F>(&self, f: F, expected_indices: Vec<usize>)
-            where
-                F: Fn(AnimationBuilder) -> AnimationBuilder,
-            {
-                let builder = self.spritesheet.clone().create_animation();
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_row(0)
+                .set_repetitions(4)
+                .add_marker(marker1, 2)
+                .add_marker(marker2, 5)
+                .build();
 
-                let animation = f(builder).build();
+            let shuffled_clip = clip.with_frame_shuffled(1234);
 
-                let indices: Vec<_> = animation
-                    .clips()
-                    .iter()
-                    .flat_map(|clip| clip.atlas_indices().iter())
-                    .copied()
-                    .collect();
+            assert_ne!(shuffled_clip.id(), clip.id());
+            assert_eq!(shuffled_clip.repetitions(), &Some(4));
 
-                assert_eq!(indices, expected_indices);
+            // Same frames in a different order
+
+            assert_ne!(shuffled_clip.atlas_indices(), clip.atlas_indices());
+
+            let mut sorted_indices = shuffled_clip.atlas_indices().to_vec();
+            sorted_indices.sort();
+
+            assert_eq!(sorted_indices, clip.atlas_indices());
+
+            // The markers follow their frames
+
+            for (frame_index, marker) in shuffled_clip.marker_frames() {
+                let expected_atlas_index = if *marker == marker1 { 2 } else { 5 };
+
+                assert_eq!(
+                    shuffled_clip.atlas_indices()[frame_index],
+                    expected_atlas_index
+                );
             }
+
+            assert_eq!(shuffled_clip.marker_frames().count(), 2);
+
+            // Same seed, same order
+
+            assert_eq!(
+                clip.with_frame_shuffled(1234).atlas_indices(),
+                shuffled_clip.atlas_indices()
+            );
+
+            assert_ne!(
+                clip.with_frame_shuffled(5678).atlas_indices(),
+                shuffled_clip.atlas_indices()
+            );
         }
 
         #[test]
-        fn add_indices() {
-            let t = Tester::new(3, 2);
+        fn duration_ms() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
 
-            t.test(|b| b.add_indices([]), vec![]);
-            t.test(|b| b.add_indices([0]), vec![0]);
-            t.test(|b| b.add_indices([1, 3]), vec![1, 3]);
-            t.test(|b| b.add_indices([999]), vec![]);
-            t.test(|b| b.add_indices([2, 999, 4]), vec![2, 4]);
+            let animation = spritesheet
+                .create_animation()
+                // The clip keeps its own duration
+                .add_indices([0, 1, 2])
+                .set_clip_duration(AnimationDuration::PerFrame(50))
+                .set_clip_repetitions(2)
+                .add_pause(300)
+                // The clip uses the duration of the animation
+                .start_clip()
+                .add_indices([3, 4])
+                .set_clip_repetitions(3)
+                .build();
+
+            assert_eq!(
+                animation.clips()[0].duration_ms(&animation),
+                2 * (150 + 300)
+            );
+            assert_eq!(animation.clips()[1].duration_ms(&animation), 3 * 2 * 100);
+
+            // The durations of the animation override the ones of the clips
+
+            let other_animation = spritesheet
+                .create_animation()
+                .add_indices([0])
+                .set_duration(AnimationDuration::PerFrame(20))
+                .build();
+
+            assert_eq!(
+                animation.clips()[0].duration_ms(&other_animation),
+                2 * (60 + 300)
+            );
+
+            // Clips of animations that repeat 0 times do not last
+
+            let empty_animation = spritesheet
+                .create_animation()
+                .add_indices([0, 1])
+                .set_repetitions(AnimationRepeat::Times(0))
+                .build();
+
+            assert_eq!(empty_animation.clips()[0].duration_ms(&empty_animation), 0);
+
+            // Per-repetition durations are shared between the clips, duplicated ones included
+
+            let short_clip = ClipBuilder::new(&spritesheet).add_indices([0, 1]).build();
+            let long_clip = ClipBuilder::new(&spritesheet)
+                .add_indices([2, 3, 4, 5])
+                .build();
+
+            let shared_animation = spritesheet
+                .create_animation()
+                .add_clip(short_clip.clone())
+                .add_clip(long_clip.clone())
+                .add_clip(short_clip.clone())
+                .set_duration(AnimationDuration::PerRepetition(1600))
+                .build();
+
+            assert_eq!(short_clip.duration_ms(&shared_animation), 400);
+            assert_eq!(long_clip.duration_ms(&shared_animation), 800);
         }
 
         #[test]
-        fn add_all_cells() {
-            let t1 = Tester::new(0, 0);
-            t1.test(|b| b.add_all_cells(), vec![]);
+        fn interleave_clip() {
+            let mut clip1_id = ClipId::dummy();
+            let mut clip2_id = ClipId::dummy();
 
-            let t2 = Tester::new(2, 3);
-            t2.test(|b| b.add_all_cells(), vec![0, 1, 2, 3, 4, 5]);
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(0)
+                .get_current_clip_id(&mut clip1_id)
+                .start_clip()
+                .add_row(1)
+                .get_current_clip_id(&mut clip2_id)
+                .interleave_clip(clip1_id, clip2_id, 2)
+                // Unknown clips are ignored
+                .interleave_clip(clip1_id, ClipId::dummy(), 2)
+                .interleave_clip(ClipId::dummy(), clip2_id, 2)
+                .build();
+
+            let clip_ids: Vec<_> = animation.clips().iter().map(|clip| clip.id()).collect();
+
+            assert_eq!(
+                clip_ids,
+                vec![clip1_id, clip2_id, clip1_id, clip2_id, clip1_id, clip2_id]
+            );
         }
 
         #[test]
-        fn add_cell() {
-            let t = Tester::new(4, 3);
+        fn with_frames_filtered() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
 
-            t.test(|b| b.add_cell(0, 0), vec![0]);
-            t.test(|b| b.add_cell(2, 2).add_cell(0, 0), vec![10, 0]);
-            t.test(|b| b.add_cell(1000, 1), vec![]);
-            t.test(|b| b.add_cell(2, 1000), vec![]);
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([10, 11, 12, 13, 14, 15])
+                .set_repetitions(4)
+                .set_offset(3)
+                .add_marker(marker1, 2)
+                .add_marker(marker2, 3)
+                .build();
+
+            let filtered_clip = clip.with_frames_filtered(|_, position| position % 2 == 0);
+
+            assert_ne!(filtered_clip.id(), clip.id());
+            assert_eq!(filtered_clip.repetitions(), &Some(4));
+            assert_eq!(filtered_clip.atlas_indices(), &[10, 12, 14]);
+
+            // The markers of the kept frames follow them
+
+            assert_eq!(filtered_clip.markers_at(1), &[marker1]);
+            assert_eq!(filtered_clip.marker_frames().count(), 1);
+
+            // The offset only counts the kept frames
+
+            assert_eq!(filtered_clip.offset(), 2);
+
+            // Filter by atlas index
+
+            assert_eq!(
+                clip.with_frames_filtered(|atlas_index, _| atlas_index > 13)
+                    .atlas_indices(),
+                &[14, 15]
+            );
         }
 
         #[test]
-        fn add_cells() {
-            let t = Tester::new(4, 3);
+        fn markers() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
+            let marker3 = Marker::new();
 
-            t.test(|b| b.add_cells([]), vec![]);
-            t.test(|b| b.add_cells([(0, 0)]), vec![0]);
-            t.test(|b| b.add_cells([(2, 2)]), vec![10]);
-            t.test(|b| b.add_cells([(1, 1), (0, 0), (2, 2)]), vec![5, 0, 10]);
-            t.test(|b| b.add_cells([(100, 100)]), vec![]);
-            t.test(|b| b.add_cells([(1000, 0), (3, 0), (0, 2000)]), vec![3]);
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                // Clip 1: add markers to different frames
+                .add_row(0)
+                .add_clip_marker(marker1, 0)
+                .add_clip_marker(marker2, 5)
+                // Clip 2: add markers to the same frame
+                .start_clip()
+                .add_row(3)
+                .add_clip_marker(marker3, 2)
+                .add_clip_marker(marker2, 2)
+                // Add out-of-bound markers, this should not work
+                .add_clip_marker(marker1, 100)
+                .add_clip_marker(marker1, 999999)
+                .build();
+
+            let clip1 = animation.clips().first().unwrap();
+
+            assert_eq!(
+                clip1.markers(),
+                &HashMap::from([(0, vec![marker1]), (5, vec![marker2])])
+            );
+
+            let clip2 = animation.clips().last().unwrap();
+
+            assert_eq!(
+                clip2.markers(),
+                &HashMap::from([(2, vec![marker3, marker2])])
+            );
         }
 
         #[test]
-        fn add_row() {
-            let t = Tester::new(3, 6);
+        fn remove_marker() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
 
-            t.test(|b| b.add_row(0), vec![0, 1, 2]);
-            t.test(|b| b.add_row(3), vec![9, 10, 11]);
-            t.test(|b| b.add_row(1000), vec![]);
-            t.test(|b| b.add_row(1000).add_row(1), vec![3, 4, 5]);
+            let mut clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_row(0)
+                .add_marker(marker1, 1)
+                .add_marker(marker2, 1)
+                .add_marker(marker1, 4)
+                .build();
+
+            assert!(clip.remove_marker(1, marker1));
+            assert_eq!(
+                clip.markers(),
+                &HashMap::from([(1, vec![marker2]), (4, vec![marker1])])
+            );
+
+            // Already removed or never added
+
+            assert!(!clip.remove_marker(1, marker1));
+            assert!(!clip.remove_marker(6, marker2));
+
+            // Frames without markers are cleared
+
+            assert!(clip.remove_marker(4, marker1));
+            assert_eq!(clip.markers(), &HashMap::from([(1, vec![marker2])]));
         }
 
         #[test]
-        fn add_partial_row() {
-            let t = Tester::new(5, 4);
+        fn set_marker() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
+
+            let mut animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(0)
+                .add_clip_marker(marker1, 2)
+                .build();
+
+            let clip = animation.clips_mut().first_mut().unwrap();
+
+            clip.set_marker(2, marker2).set_marker(5, marker1);
+
+            assert_eq!(
+                clip.markers(),
+                &HashMap::from([(2, vec![marker1, marker2]), (5, vec![marker1])])
+            );
+
+            // Out of bounds
+
+            clip.set_marker(8, marker2);
+
+            assert_eq!(clip.markers().len(), 2);
+
+            // Clearing
+
+            clip.clear_markers(2).clear_markers(7);
+
+            assert_eq!(clip.markers(), &HashMap::from([(5, vec![marker1])]));
+        }
+
+        #[test]
+        fn prepend_append_frames() {
+            let marker = Marker::new();
+
+            let mut clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([3, 4])
+                .add_pause(200)
+                .add_marker(marker, 1)
+                .set_frame_easing(0, Easing::In(EasingVariety::Quadratic))
+                .set_offset(1)
+                .build();
+
+            let id = clip.id();
+
+            clip.prepend_frames(&[1, 2]).append_frames(&[5, 6]);
+
+            assert_eq!(clip.atlas_indices(), &[1, 2, 3, 4, 4, 5, 6]);
+
+            // The existing frames keep their parameters
+
+            assert_eq!(clip.markers(), &HashMap::from([(3, vec![marker])]));
+            assert_eq!(clip.frame_durations(), &HashMap::from([(4, 200)]));
+            assert_eq!(
+                clip.frame_easings(),
+                &HashMap::from([(2, Easing::In(EasingVariety::Quadratic))])
+            );
+
+            assert_eq!(clip.offset(), 1);
+            assert_eq!(clip.id(), id);
+
+            // No frames
+
+            clip.prepend_frames(&[]).append_frames(&[]);
+
+            assert_eq!(clip.atlas_indices().len(), 7);
+        }
+
+        #[test]
+        fn add_frames_with_tags() {
+            let marker1 = Marker::new();
+            let marker2 = Marker::new();
+
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([0])
+                .add_frame_with_tags(1, &[marker1, marker2])
+                .add_frames_with_uniform_tags(&[2, 3], &[marker2])
+                // Out of bounds
+                .add_frame_with_tags(64, &[marker1])
+                // No markers
+                .add_frames_with_uniform_tags(&[4], &[])
+                .build();
+
+            assert_eq!(clip.atlas_indices(), &[0, 1, 2, 3, 4]);
+
+            assert_eq!(
+                clip.markers(),
+                &HashMap::from([
+                    (1, vec![marker1, marker2]),
+                    (2, vec![marker2]),
+                    (3, vec![marker2])
+                ])
+            );
+        }
+
+        #[test]
+        fn add_frame_at_time() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+            let clip = ClipBuilder::new(&spritesheet)
+                // Not starting at 0
+                .add_frame_at_time(0, 50, 100)
+                .add_frame_at_time(1, 0, 100)
+                .add_frame_at_time(2, 100, 250)
+                // Empty range
+                .add_frame_at_time(3, 250, 250)
+                // Overlap
+                .add_frame_at_time(3, 200, 300)
+                // Out of bounds
+                .add_frame_at_time(64, 250, 300)
+                // Gap that holds the previous frame
+                .add_frame_at_time(4, 300, 310)
+                .build();
+
+            assert_eq!(clip.atlas_indices(), &[1, 2, 4]);
+            assert_eq!(
+                clip.frame_durations(),
+                &HashMap::from([(0, 100), (1, 200), (2, 10)])
+            );
+
+            // Frames without fixed durations
+
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_indices([0])
+                .add_frame_at_time(1, 0, 100)
+                .build();
+
+            assert_eq!(clip.atlas_indices(), &[0]);
+        }
+
+        #[test]
+        fn frame_at() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_indices([3, 4, 5])
+                .build();
+
+            assert_eq!(clip.frame_at(0), Some(3));
+            assert_eq!(clip.frame_at(2), Some(5));
+            assert_eq!(clip.frame_at(3), None);
+
+            let backwards_clip = ClipBuilder::new(&spritesheet)
+                .add_indices([3, 4, 5])
+                .set_direction(AnimationDirection::Backwards)
+                .build();
+
+            assert_eq!(backwards_clip.frame_at(0), Some(5));
+            assert_eq!(backwards_clip.frame_at(2), Some(3));
+            assert_eq!(backwards_clip.frame_at(3), None);
+
+            let ping_pong_clip = ClipBuilder::new(&spritesheet)
+                .add_indices([3, 4, 5])
+                .set_direction(AnimationDirection::PingPong)
+                .build();
 
-            // First row, starting at column 0
+            assert_eq!(ping_pong_clip.frame_at(0), Some(3));
 
-            t.test(|b| b.add_partial_row(0, 0..0), vec![]);
-            t.test(|b| b.add_partial_row(0, 0..), vec![0, 1, 2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 0..2), vec![0, 1]);
-            t.test(|b| b.add_partial_row(0, 0..5), vec![0, 1, 2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 0..100), vec![0, 1, 2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 0..=0), vec![0]);
-            t.test(|b| b.add_partial_row(0, 0..=2), vec![0, 1, 2]);
-            t.test(|b| b.add_partial_row(0, 0..=100), vec![0, 1, 2, 3, 4]);
+            // Virtual frames hold the previous frame in play order
 
-            // First row, starting at another column
+            let virtual_clip = ClipBuilder::new(&spritesheet)
+                .add_virtual_frame(100)
+                .add_indices([3])
+                .add_virtual_frame(100)
+                .add_indices([4])
+                .build();
 
-            t.test(|b| b.add_partial_row(0, 2..2), vec![]);
-            t.test(|b| b.add_partial_row(0, 2..), vec![2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 1..3), vec![1, 2]);
-            t.test(|b| b.add_partial_row(0, 2..5), vec![2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 2..100), vec![2, 3, 4]);
-            t.test(|b| b.add_partial_row(0, 3..=3), vec![3]);
-            t.test(|b| b.add_partial_row(0, 3..=4), vec![3, 4]);
-            t.test(|b| b.add_partial_row(0, 4..=100), vec![4]);
+            assert_eq!(virtual_clip.frame_at(0), Some(4));
+            assert_eq!(virtual_clip.frame_at(2), Some(3));
 
-            // Other rows
+            let backwards_virtual_clip = ClipBuilder::new(&spritesheet)
+                .add_virtual_frame(100)
+                .add_indices([3])
+                .add_virtual_frame(100)
+                .add_indices([4])
+                .set_direction(AnimationDirection::Backwards)
+                .build();
 
-            t.test(|b| b.add_partial_row(1, 0..0), vec![]);
-            t.test(|b| b.add_partial_row(2, 0..), vec![10, 11, 12, 13, 14]);
-            t.test(|b| b.add_partial_row(3, 0..2), vec![15, 16]);
-            t.test(|b| b.add_partial_row(1, 0..5), vec![5, 6, 7, 8, 9]);
-            t.test(|b| b.add_partial_row(2, 0..100), vec![10, 11, 12, 13, 14]);
-            t.test(|b| b.add_partial_row(3, 0..=0), vec![15]);
-            t.test(|b| b.add_partial_row(3, 0..=2), vec![15, 16, 17]);
-            t.test(|b| b.add_partial_row(2, 0..=100), vec![10, 11, 12, 13, 14]);
-            t.test(|b| b.add_partial_row(100, 0..3), vec![]);
-            t.test(|b| b.add_partial_row(100, 0..=100), vec![]);
+            assert_eq!(backwards_virtual_clip.frame_at(1), Some(4));
+            assert_eq!(backwards_virtual_clip.frame_at(3), Some(3));
         }
 
         #[test]
-        fn add_column() {
-            let t = Tester::new(5, 3);
+        fn build_into() {
+            let mut assets = Assets::<Animation>::default();
 
-            t.test(|b| b.add_column(0), vec![0, 5, 10]);
-            t.test(|b| b.add_column(1), vec![1, 6, 11]);
-            t.test(|b| b.add_column(1000), vec![]);
-            t.test(|b| b.add_column(1000).add_column(1), vec![1, 6, 11]);
+            let builder = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(2);
+
+            let animation = builder.clone().build();
+
+            let handle = builder.build_into(&mut assets);
+
+            assert!(handle.is_strong());
+            assert_eq!(assets.get(&handle), Some(&animation));
         }
 
         #[test]
-        fn add_partial_column() {
-            let t = Tester::new(3, 4);
+        fn effective_duration_ms() {
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
 
-            // First column, starting at row 0
-
-            t.test(|b| b.add_partial_column(0, 0..0), vec![]);
-            t.test(|b| b.add_partial_column(0, 0..), vec![0, 3, 6, 9]);
-            t.test(|b| b.add_partial_column(0, 0..2), vec![0, 3]);
-            t.test(|b| b.add_partial_column(0, 0..5), vec![0, 3, 6, 9]);
-            t.test(|b| b.add_partial_column(0, 0..100), vec![0, 3, 6, 9]);
-            t.test(|b| b.add_partial_column(0, 0..=0), vec![0]);
-            t.test(|b| b.add_partial_column(0, 0..=2), vec![0, 3, 6]);
-            t.test(|b| b.add_partial_column(0, 0..=100), vec![0, 3, 6, 9]);
+            let clip = ClipBuilder::new(&spritesheet).add_indices([0, 1]).build();
 
-            // First column, starting at another row
+            // Default duration
 
-            t.test(|b| b.add_partial_column(0, 2..2), vec![]);
-            t.test(|b| b.add_partial_column(0, 2..), vec![6, 9]);
-            t.test(|b| b.add_partial_column(0, 1..3), vec![3, 6]);
-            t.test(|b| b.add_partial_column(0, 2..5), vec![6, 9]);
-            t.test(|b| b.add_partial_column(0, 2..100), vec![6, 9]);
-            t.test(|b| b.add_partial_column(0, 3..=3), vec![9]);
-            t.test(|b| b.add_partial_column(0, 2..=4), vec![6, 9]);
-            t.test(|b| b.add_partial_column(0, 3..=100), vec![9]);
+            assert_eq!(
+                clip.effective_duration_ms(AnimationContext::default()),
+                Some(100)
+            );
+            assert_eq!(
+                clip.effective_duration_ms(AnimationContext {
+                    default_duration: AnimationDuration::PerFrameExact(41.6),
+                    ..default()
+                }),
+                Some(42)
+            );
 
-            // Other columns
+            // Clip duration
 
-            t.test(|b| b.add_partial_column(1, 0..0), vec![]);
-            t.test(|b| b.add_partial_column(2, 0..), vec![2, 5, 8, 11]);
-            t.test(|b| b.add_partial_column(1, 0..2), vec![1, 4]);
-            t.test(|b| b.add_partial_column(1, 0..5), vec![1, 4, 7, 10]);
-            t.test(|b| b.add_partial_column(2, 0..100), vec![2, 5, 8, 11]);
-            t.test(|b| b.add_partial_column(1, 0..=0), vec![1]);
-            t.test(|b| b.add_partial_column(2, 0..=2), vec![2, 5, 8]);
-            t.test(|b| b.add_partial_column(2, 0..=100), vec![2, 5, 8, 11]);
-            t.test(|b| b.add_partial_column(100, 0..3), vec![]);
-            t.test(|b| b.add_partial_column(100, 0..=100), vec![]);
-        }
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_indices([0, 1])
+                .set_duration(AnimationDuration::PerRepetition(500))
+                .build();
 
-        #[test]
-        fn add_horizontal_strip() {
-            let t = Tester::new(8, 8);
+            assert_eq!(
+                clip.effective_duration_ms(AnimationContext::default()),
+                Some(250)
+            );
 
-            t.test(|b| b.add_horizontal_strip(0, 0, 3), vec![0, 1, 2]);
-            t.test(|b| b.add_horizontal_strip(6, 0, 4), vec![6, 7, 8, 9]);
-            t.test(|b| b.add_horizontal_strip(4, 5, 0), vec![]);
-            t.test(|b| b.add_horizontal_strip(100, 0, 1), vec![]);
-            t.test(|b| b.add_horizontal_strip(0, 100, 1), vec![]);
-            t.test(|b| b.add_horizontal_strip(6, 7, 1000), vec![62, 63]);
-        }
+            // Animation duration
 
-        #[test]
-        fn add_vertical_strip() {
-            let t = Tester::new(4, 3);
+            let animation = spritesheet
+                .create_animation()
+                .add_clip(clip.clone())
+                .set_duration(AnimationDuration::PerFrame(40))
+                .build();
 
-            t.test(|b| b.add_vertical_strip(0, 0, 2), vec![0, 4]);
-            t.test(|b| b.add_vertical_strip(1, 1, 6), vec![5, 9, 2, 6, 10, 3]);
-            t.test(|b| b.add_vertical_strip(100, 0, 1), vec![]);
-            t.test(|b| b.add_vertical_strip(0, 100, 1), vec![]);
-            t.test(|b| b.add_vertical_strip(3, 0, 1000), vec![3, 7, 11]);
-        }
-    }
+            assert_eq!(clip.effective_duration_ms((&animation).into()), Some(40));
 
-    mod clips {
-        use bevy::platform::collections::HashMap;
+            // Animation duration shared between the clips
 
-        use super::*;
+            assert_eq!(
+                clip.effective_duration_ms(AnimationContext {
+                    animation_duration: Some(AnimationDuration::PerRepetition(1000)),
+                    ..default()
+                }),
+                Some(500)
+            );
 
-        #[test]
-        fn id() {
-            let mut clip1_id = ClipId::dummy();
-            let mut clip2_id = ClipId::dummy();
-            let mut clip3_id = ClipId::dummy();
+            let other_clip = ClipBuilder::new(&spritesheet)
+                .add_indices([0, 1, 2])
+                .build();
 
-            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+            let animation = spritesheet
                 .create_animation()
-                .get_current_clip_id(&mut clip1_id)
-                .start_clip()
-                .get_current_clip_id(&mut clip2_id)
-                .start_clip()
-                .get_current_clip_id(&mut clip3_id)
+                .add_clip(clip.clone())
+                .add_clip(other_clip.clone())
+                .set_duration(AnimationDuration::PerRepetition(1600))
                 .build();
 
-            assert_eq!(animation.clips().first().unwrap().id(), clip1_id);
-            assert_eq!(animation.clips().get(1).unwrap().id(), clip2_id);
-            assert_eq!(animation.clips().get(2).unwrap().id(), clip3_id);
+            assert_eq!(clip.effective_duration_ms((&animation).into()), Some(500));
+            assert_eq!(
+                other_clip.effective_duration_ms((&animation).into()),
+                Some(200)
+            );
+            assert_eq!(other_clip.duration_ms(&animation), 600);
+
+            // No frames
+
+            let empty_clip = Clip::empty();
+
+            assert_eq!(
+                empty_clip.effective_duration_ms(AnimationContext::default()),
+                Some(100)
+            );
+            assert_eq!(
+                empty_clip.effective_duration_ms(AnimationContext {
+                    default_duration: AnimationDuration::PerRepetition(1000),
+                    ..default()
+                }),
+                None
+            );
         }
 
         #[test]
-        fn atlas_indices() {
-            let animation = Spritesheet::new(&Handle::default(), 3, 6)
-                .create_animation()
-                .add_row(1)
-                .start_clip()
-                .add_row(2)
-                .add_cell(1, 5)
-                .add_cell(2, 4)
+        fn add_pause() {
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                // No frames to hold
+                .add_pause(100)
+                .add_indices([3, 4])
+                .add_pause(200)
+                .add_indices([5])
+                .add_pause(300)
                 .build();
 
-            let clip1 = animation.clips().first().unwrap();
-            assert_eq!(clip1.atlas_indices(), [3, 4, 5]);
+            assert_eq!(clip.atlas_indices(), &[3, 4, 4, 5, 5]);
+            assert_eq!(clip.frame_durations(), &HashMap::from([(2, 200), (4, 300)]));
 
-            let clip2 = animation.clips().last().unwrap();
-            assert_eq!(clip2.atlas_indices(), [6, 7, 8, 16, 14]);
+            // The fixed durations follow their frames
+
+            let shuffled_clip = clip.with_frame_shuffled(3);
+
+            for (frame_index, duration) in shuffled_clip.frame_durations() {
+                assert_eq!(
+                    shuffled_clip.atlas_indices()[*frame_index],
+                    [4, 5][(*duration / 100 - 2) as usize]
+                );
+            }
+
+            assert_eq!(shuffled_clip.frame_durations().len(), 2);
         }
 
         #[test]
-        fn parameters() {
-            let animation = Spritesheet::new(&Handle::default(), 8, 8)
-                .create_animation()
-                // Only the last set_clip_xxx() should apply
-                .set_clip_direction(AnimationDirection::Backwards)
-                .set_clip_direction(AnimationDirection::PingPong)
-                .set_clip_duration(AnimationDuration::PerRepetition(1000))
-                .set_clip_duration(AnimationDuration::PerFrame(2000))
-                .set_clip_repetitions(10)
-                .set_clip_repetitions(20)
-                .set_clip_easing(Easing::Linear)
-                .set_clip_easing(Easing::InOut(EasingVariety::Cubic))
-                // New clip with default parameters
-                .start_clip()
-                // The animation's set_xxx() should not alter the clip's parameters
-                .set_direction(AnimationDirection::Backwards)
-                .set_duration(AnimationDuration::PerFrame(123))
-                .set_repetitions(AnimationRepeat::Times(9))
-                .set_easing(Easing::In(EasingVariety::Quintic))
+        fn display() {
+            let footstep = Marker::named("footstep");
+
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([0, 1, 2, 3, 4, 5, 6])
+                .add_pause(200)
+                .set_repetitions(3)
+                .add_marker(footstep, 4)
                 .build();
 
-            // Animation
+            assert_eq!(
+                clip.to_string(),
+                r#"Clip { frames: 8, duration: 900ms, direction: forwards, repetitions: 3, markers: [(4, Marker("footstep"))] }"#
+            );
 
-            assert!(matches!(
-                animation.direction(),
-                Some(AnimationDirection::Backwards)
-            ));
+            // The clip's own parameters are used if specified
 
-            assert!(matches!(
-                animation.duration(),
-                Some(AnimationDuration::PerFrame(123))
-            ));
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([0, 1])
+                .set_duration(AnimationDuration::PerRepetition(500))
+                .set_direction(AnimationDirection::Backwards)
+                .build();
 
-            assert!(matches!(
-                animation.repetitions(),
-                Some(AnimationRepeat::Times(9))
-            ));
+            assert_eq!(
+                clip.to_string(),
+                "Clip { frames: 2, duration: 500ms, direction: backwards, repetitions: 1, markers: [] }"
+            );
 
-            assert!(matches!(
-                animation.easing(),
-                Some(Easing::In(EasingVariety::Quintic))
-            ));
+            // Per-repetition durations are not rounded per frame
 
-            // Clip 1
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([0, 1, 2])
+                .set_duration(AnimationDuration::PerRepetition(1000))
+                .build();
 
-            let clip1 = animation.clips().first().unwrap();
+            assert_eq!(
+                clip.to_string(),
+                "Clip { frames: 3, duration: 1000ms, direction: forwards, repetitions: 1, markers: [] }"
+            );
 
-            assert!(matches!(
-                clip1.direction(),
-                Some(AnimationDirection::PingPong)
-            ));
+            // Virtual frames are counted apart
 
-            assert!(matches!(
-                clip1.duration(),
-                Some(AnimationDuration::PerFrame(2000))
-            ));
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([0, 1])
+                .add_virtual_frame(300)
+                .build();
 
-            assert!(matches!(clip1.repetitions(), Some(20)));
+            assert_eq!(
+                clip.to_string(),
+                "Clip { frames: 3 (1 virtual), duration: 500ms, direction: forwards, repetitions: 1, markers: [] }"
+            );
+        }
 
-            assert!(matches!(
-                clip1.easing(),
-                Some(Easing::InOut(EasingVariety::Cubic))
-            ));
+        #[test]
+        fn add_frame_loop() {
+            let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+                .add_indices([3])
+                .add_frame_loop(4, 3, 50)
+                .add_frame_loop(6, 0, 50)
+                .add_indices([5])
+                .build();
 
-            let clip2 = animation.clips().last().unwrap();
+            assert_eq!(clip.atlas_indices(), &[3, 4, 4, 4, 5]);
+            assert_eq!(
+                clip.frame_durations(),
+                &HashMap::from([(1, 50), (2, 50), (3, 50)])
+            );
+        }
 
-            // Clip 2
+        #[test]
+        fn add_frame_loop_sub_grid() {
+            // Frames 0..=3 of the sub-grid are atlas indices 10, 11, 18, 19
 
-            assert!(clip2.direction().is_none());
-            assert!(clip2.duration().is_none());
-            assert!(clip2.repetitions().is_none());
-            assert!(clip2.easing().is_none());
+            let spritesheet = Spritesheet::new(&Handle::default(), 8, 8).sub_grid(1, 2, 2, 2);
+
+            let clip = ClipBuilder::new(&spritesheet)
+                .add_frame_loop(1, 2, 50)
+                // Out of range: ignored
+                .add_frame_loop(4, 2, 50)
+                .add_frame_loop(3, 1, 100)
+                .build();
+
+            assert_eq!(clip.atlas_indices(), &[11, 11, 19]);
+            assert_eq!(
+                clip.frame_durations(),
+                &HashMap::from([(0, 50), (1, 50), (2, 100)])
+            );
         }
 
         #[test]
-        fn markers() {
+        fn marker_frames() {
             let marker1 = Marker::new();
             let marker2 = Marker::new();
             let marker3 = Marker::new();
 
             let animation = Spritesheet::new(&Handle::default(), 8, 8)
                 .create_animation()
-                // Clip 1: add markers to different frames
                 .add_row(0)
-                .add_clip_marker(marker1, 0)
-                .add_clip_marker(marker2, 5)
-                // Clip 2: add markers to the same frame
-                .start_clip()
-                .add_row(3)
-                .add_clip_marker(marker3, 2)
-                .add_clip_marker(marker2, 2)
-                // Add out-of-bound markers, this should not work
-                .add_clip_marker(marker1, 100)
-                .add_clip_marker(marker1, 999999)
+                .add_clip_marker(marker1, 6)
+                .add_clip_marker(marker2, 1)
+                .add_clip_marker(marker3, 6)
                 .build();
 
-            let clip1 = animation.clips().first().unwrap();
+            let clip = animation.clips().first().unwrap();
 
             assert_eq!(
-                clip1.markers(),
-                &HashMap::from([(0, vec![marker1]), (5, vec![marker2])])
+                clip.marker_frames().collect::<Vec<_>>(),
+                vec![(1, &marker2), (6, &marker1), (6, &marker3)]
             );
 
-            let clip2 = animation.clips().last().unwrap();
+            // Clips without markers
 
-            assert_eq!(
-                clip2.markers(),
-                &HashMap::from([(2, vec![marker3, marker2])])
-            );
+            let animation = Spritesheet::new(&Handle::default(), 8, 8)
+                .create_animation()
+                .add_row(0)
+                .build();
+
+            assert_eq!(animation.clips()[0].marker_frames().count(), 0);
         }
     }
 }
@@ -6,11 +6,17 @@ use std::{
 };
 
 use crate::{
-    animation::{AnimationDirection, AnimationDuration},
+    CRATE_NAME,
+    animation::{
+        Animation, AnimationContext, AnimationDirection, AnimationDuration, AnimationRepeat,
+    },
     easing::Easing,
     events::Marker,
 };
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
 
 /// An opaque identifier for a [Clip]
 ///
@@ -56,6 +62,8 @@ impl ClipId {
 ///
 /// Create clips with [AnimationBuilder::start_clip()](crate::prelude::AnimationBuilder::start_clip) and [AnimationBuilder::copy_clip()](crate::prelude::AnimationBuilder::copy_clip).
 ///
+/// Clips can also be built separately with a [ClipBuilder](crate::prelude::ClipBuilder) and added to animations with [AnimationBuilder::add_clip()](crate::prelude::AnimationBuilder::add_clip).
+///
 /// # Parameters
 ///
 /// Playback parameters like [duration](crate::prelude::AnimationDuration), repetitions, [direction](crate::prelude::AnimationDirection) and [easing](crate::prelude::Easing) can be specified.
@@ -69,11 +77,14 @@ impl ClipId {
 /// When an animation reaches such a frame, a [MarkerHit](crate::prelude::AnimationEvent::MarkerHit) event will be emitted.
 ///
 /// See [AnimationEvent](crate::prelude::AnimationEvent) for more details.
-#[derive(Debug, Clone, Reflect)]
+#[derive(Debug, Clone, PartialEq, Reflect)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[reflect(Debug)]
+#[reflect(Debug, PartialEq)]
 pub struct Clip {
-    id: ClipId,
+    pub(crate) id: ClipId,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) debug_name: Option<String>,
 
     pub(crate) atlas_indices: Vec<usize>,
 
@@ -82,7 +93,31 @@ pub struct Clip {
     pub(crate) direction: Option<AnimationDirection>,
     pub(crate) easing: Option<Easing>,
 
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) offset: usize,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) start_frame: usize,
+
     pub(crate) markers: HashMap<usize, Vec<Marker>>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) frame_durations: HashMap<usize, u32>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) frame_easings: HashMap<usize, Easing>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) flipped_frames: HashSet<usize>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) virtual_frames: HashSet<usize>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) blend_in: u32,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) blend_out: u32,
 }
 
 impl Clip {
@@ -93,12 +128,29 @@ impl Clip {
     pub(crate) fn empty() -> Self {
         Clip {
             id: ClipId::new(),
+            debug_name: None,
             atlas_indices: Vec::new(),
             duration: None,
             repetitions: None,
             direction: None,
             easing: None,
+            offset: 0,
+            start_frame: 0,
             markers: HashMap::new(),
+            frame_durations: HashMap::new(),
+            frame_easings: HashMap::new(),
+            flipped_frames: HashSet::new(),
+            virtual_frames: HashSet::new(),
+            blend_in: 0,
+            blend_out: 0,
+        }
+    }
+
+    /// Private copy constructor that attributes a new ID to the copy.
+    pub(crate) fn duplicate(&self) -> Self {
+        Clip {
+            id: ClipId::new(),
+            ..self.clone()
         }
     }
 
@@ -109,11 +161,168 @@ impl Clip {
         self.id
     }
 
+    /// The name of this clip, if it was named with [AnimationBuilder::set_clip_name()](crate::prelude::AnimationBuilder::set_clip_name)
+    pub fn name(&self) -> Option<&str> {
+        self.debug_name.as_deref()
+    }
+
     /// Indices into the layout of a TextureAtlas component
     pub fn atlas_indices(&self) -> &[usize] {
         &self.atlas_indices
     }
 
+    /// The atlas index displayed at a position of this clip, in play order.
+    ///
+    /// This accounts for the [direction](Clip::direction) of the clip: the first position of a backwards clip is its last atlas index.
+    /// Ping-pong and shuffled clips are considered during their first repetition.
+    ///
+    /// The direction of the animation that contains the clip is not known here, so a clip without its own direction is considered to play forwards.
+    ///
+    /// [Virtual frames](crate::prelude::ClipBuilder::add_virtual_frame) return the atlas index of the frame displayed before them.
+    /// As the clip is considered on its own, the virtual frames at its start return its last displayed atlas index, as when the clip repeats.
+    ///
+    /// Returns `None` if `position` exceeds the number of frames of the clip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let clip = ClipBuilder::new(spritesheet)
+    ///     .add_indices([3, 4, 5])
+    ///     .set_direction(AnimationDirection::Backwards)
+    ///     .build();
+    ///
+    /// assert_eq!(clip.frame_at(0), Some(5));
+    /// assert_eq!(clip.frame_at(3), None);
+    /// # }
+    /// ```
+    pub fn frame_at(&self, position: usize) -> Option<usize> {
+        let order = self.play_order();
+
+        let frame_index = *order.get(position)?;
+
+        if !self.virtual_frames.contains(&frame_index) {
+            return Some(self.atlas_indices[frame_index]);
+        }
+
+        // Look for the previous displayed frame, wrapping around to the end of the clip
+
+        let displayed_frame_index = order[..position]
+            .iter()
+            .rev()
+            .chain(order[position..].iter().rev())
+            .find(|frame_index| !self.virtual_frames.contains(*frame_index))
+            .unwrap_or(&frame_index);
+
+        Some(self.atlas_indices[*displayed_frame_index])
+    }
+
+    /// The frame indices of this clip in play order, during its first repetition
+    ///
+    /// Shuffled clips leave their virtual frames at their positions, like during playback.
+    fn play_order(&self) -> Vec<usize> {
+        match self.direction.unwrap_or_default() {
+            AnimationDirection::Forwards | AnimationDirection::PingPong => {
+                (0..self.atlas_indices.len()).collect()
+            }
+            AnimationDirection::Backwards => (0..self.atlas_indices.len()).rev().collect(),
+            AnimationDirection::Shuffle(seed) => {
+                let mut shuffled: Vec<usize> = (0..self.atlas_indices.len())
+                    .filter(|frame_index| !self.virtual_frames.contains(frame_index))
+                    .collect();
+
+                fastrand::Rng::with_seed(seed).shuffle(&mut shuffled);
+
+                let mut shuffled = shuffled.into_iter();
+
+                (0..self.atlas_indices.len())
+                    .map(|frame_index| {
+                        if self.virtual_frames.contains(&frame_index) {
+                            frame_index
+                        } else {
+                            shuffled.next().unwrap_or(frame_index)
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// The duration of the frames of this clip in milliseconds, resolved like during playback.
+    ///
+    /// A per-frame duration of the animation takes precedence over the duration of the clip, which itself falls back to the default duration of the context.
+    ///
+    /// Frames with a fixed duration, such as [pauses](crate::prelude::ClipBuilder::add_pause), and easings are not accounted for.
+    ///
+    /// A [per-repetition](AnimationDuration::PerRepetition) duration of the animation is shared between its clips depending on their own durations (see [Clip::duration_ms()]),
+    /// which requires the [total duration of the clips](AnimationContext::clips_duration_ms) of the animation.
+    ///
+    /// Returns `None` if a per-repetition duration is shared by no frames, as the clip is empty.
+    /// The repetitions of the animation, even infinite ones, do not change the duration of the frames.
+    ///
+    /// # Arguments
+    ///
+    /// - `context` - the parameters of the animation that plays this clip
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_indices([0, 1, 2, 3])
+    ///     .set_clip_duration(AnimationDuration::PerRepetition(1000))
+    ///     .build();
+    ///
+    /// let clip = &animation.clips()[0];
+    ///
+    /// assert_eq!(clip.effective_duration_ms((&animation).into()), Some(250));
+    ///
+    /// // Overridden by the animation
+    ///
+    /// let context = AnimationContext {
+    ///     animation_duration: Some(AnimationDuration::PerFrame(100)),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(clip.effective_duration_ms(context), Some(100));
+    /// # }
+    /// ```
+    pub fn effective_duration_ms(&self, context: AnimationContext) -> Option<u64> {
+        let duration = match context.animation_duration {
+            Some(
+                animation_duration @ (AnimationDuration::PerFrame(_)
+                | AnimationDuration::PerFrameExact(_)),
+            ) => animation_duration,
+            Some(AnimationDuration::PerRepetition(animation_duration_ms)) => {
+                if self.atlas_indices.is_empty() {
+                    return None;
+                }
+
+                let clips_duration_ms = context
+                    .clips_duration_ms
+                    .unwrap_or_else(|| self.own_duration_ms());
+
+                return Some(
+                    self.shared_frame_duration_ms(animation_duration_ms, clips_duration_ms) as u64,
+                );
+            }
+            None => self.duration.unwrap_or(context.default_duration),
+        };
+
+        match duration {
+            AnimationDuration::PerFrame(frame_duration_ms) => Some(frame_duration_ms as u64),
+            AnimationDuration::PerFrameExact(frame_duration_ms) => {
+                Some(frame_duration_ms.max(0.0).round() as u64)
+            }
+            AnimationDuration::PerRepetition(repetition_duration_ms) => {
+                (repetition_duration_ms as u64).checked_div(self.atlas_indices.len() as u64)
+            }
+        }
+    }
+
     /// The optional duration of this clip
     pub fn duration(&self) -> &Option<AnimationDuration> {
         &self.duration
@@ -134,6 +343,28 @@ impl Clip {
         &self.easing
     }
 
+    /// The number of frames skipped during the first repetition of this clip, on every repetition of the animation
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The frame at which this clip starts when it is played for the first time, the next times starting from its first frame
+    ///
+    /// See [AnimationBuilder::set_clip_start_frame()](crate::prelude::AnimationBuilder::set_clip_start_frame).
+    pub fn start_frame(&self) -> usize {
+        self.start_frame
+    }
+
+    /// The time in milliseconds during which the sprite fades in at the start of this clip
+    pub fn blend_in(&self) -> u32 {
+        self.blend_in
+    }
+
+    /// The time in milliseconds during which the sprite fades out at the end of this clip
+    pub fn blend_out(&self) -> u32 {
+        self.blend_out
+    }
+
     /// Markers that will trigger [MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events when the corresponding frame is played
     ///
     /// The key is the frame index.
@@ -141,4 +372,632 @@ impl Clip {
     pub fn markers(&self) -> &HashMap<usize, Vec<Marker>> {
         &self.markers
     }
+
+    /// The markers on a frame of this clip, in the order they were added
+    ///
+    /// Returns an empty slice if the frame has no markers or if it does not exist.
+    pub fn markers_at(&self, frame_index: usize) -> &[Marker] {
+        self.markers
+            .get(&frame_index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Fixed durations in milliseconds of some frames of this clip, such as [pauses](crate::prelude::AnimationBuilder::add_pause)
+    ///
+    /// The key is the frame index.
+    /// Those frames keep their duration whatever the duration and the easing of the clip and of its animation.
+    ///
+    /// With a [per-repetition](AnimationDuration::PerRepetition) duration, the duration of the repetition is still divided between all the frames of the clip,
+    /// so the frames with a fixed duration make the repetition last longer or shorter than specified.
+    pub fn frame_durations(&self) -> &HashMap<usize, u32> {
+        &self.frame_durations
+    }
+
+    /// Easings that restart at some frames of this clip, replacing the [easing](Clip::easing) of the clip from those frames
+    ///
+    /// The key is the frame index.
+    /// See [AnimationBuilder::set_clip_frame_easing()](crate::prelude::AnimationBuilder::set_clip_frame_easing) for more details.
+    pub fn frame_easings(&self) -> &HashMap<usize, Easing> {
+        &self.frame_easings
+    }
+
+    /// Frames of this clip that are displayed flipped horizontally, such as [mirrored rows](crate::prelude::AnimationBuilder::add_row_mirrored)
+    ///
+    /// When an animation contains such frames, playing it sets the `flip_x` attribute of the sprites on every frame.
+    pub fn flipped_frames(&self) -> &HashSet<usize> {
+        &self.flipped_frames
+    }
+
+    /// Frames of this clip that keep displaying the previous frame, added with [AnimationBuilder::add_virtual_frame()](crate::prelude::AnimationBuilder::add_virtual_frame)
+    ///
+    /// Their atlas indices are placeholders that are not displayed.
+    pub fn virtual_frames(&self) -> &HashSet<usize> {
+        &self.virtual_frames
+    }
+
+    /// The total duration of this clip in milliseconds when played in an animation, including all of its repetitions.
+    ///
+    /// The duration of the frames depends on the parameters of both the clip and the animation, which are resolved like during playback.
+    /// If this clip is not part of `animation`, its duration is computed as if it was the only clip of the animation.
+    ///
+    /// This accounts for a single repetition of the animation.
+    /// It is computed from the parameters of the clip and of the animation alone: easings, which only redistribute the time between frames, are not accounted for.
+    ///
+    /// # Arguments
+    ///
+    /// - `animation` - the animation that plays this clip
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_indices([0, 1, 2])
+    ///     .set_clip_repetitions(2)
+    ///     .set_duration(AnimationDuration::PerFrame(100))
+    ///     .build();
+    ///
+    /// let clip = &animation.clips()[0];
+    ///
+    /// assert_eq!(clip.duration_ms(&animation), 600);
+    /// # }
+    /// ```
+    pub fn duration_ms(&self, animation: &Animation) -> u64 {
+        let repetitions = self.repetitions.unwrap_or(1);
+
+        if self.atlas_indices.is_empty()
+            || repetitions == 0
+            || matches!(animation.repetitions(), Some(AnimationRepeat::Times(0)))
+        {
+            return 0;
+        }
+
+        let frame_count = self.atlas_indices.len() as u32;
+
+        let frame_duration_ms = match animation.duration() {
+            // No duration is defined for the animation: use the clip's duration
+            None => match self.duration.unwrap_or_default() {
+                AnimationDuration::PerFrame(frame_duration_ms) => frame_duration_ms as f64,
+                AnimationDuration::PerFrameExact(frame_duration_ms) => frame_duration_ms as f64,
+                AnimationDuration::PerRepetition(repetition_duration_ms) => {
+                    (repetition_duration_ms / frame_count) as f64
+                }
+            },
+
+            // The per-frame duration is defined for the animation: it overrides the clip's duration
+            Some(AnimationDuration::PerFrame(frame_duration_ms)) => *frame_duration_ms as f64,
+            Some(AnimationDuration::PerFrameExact(frame_duration_ms)) => *frame_duration_ms as f64,
+
+            // The per-cycle duration of the animation is defined: it is shared between the clips in proportion to their own durations
+            Some(AnimationDuration::PerRepetition(animation_duration_ms)) => {
+                let clips_duration_ms: f64 =
+                    if animation.clips.iter().any(|clip| clip.id == self.id) {
+                        animation.clips.iter().map(Clip::own_duration_ms).sum()
+                    } else {
+                        self.own_duration_ms()
+                    };
+
+                self.shared_frame_duration_ms(*animation_duration_ms, clips_duration_ms)
+            }
+        };
+
+        self.duration_with_repetitions_ms(AnimationDuration::PerFrameExact(
+            frame_duration_ms as f32,
+        ))
+        .round() as u64
+    }
+
+    /// The duration of this clip in milliseconds with its own parameters, used to share the per-repetition duration of an animation between its clips
+    pub(crate) fn own_duration_ms(&self) -> f64 {
+        if self.atlas_indices.is_empty() || self.repetitions == Some(0) {
+            0.0
+        } else {
+            self.duration_with_repetitions_ms(self.duration.unwrap_or_default())
+        }
+    }
+
+    /// The duration of the frames of this clip in milliseconds when it gets its share of the per-repetition duration of an animation, like during playback
+    fn shared_frame_duration_ms(&self, animation_duration_ms: u32, clips_duration_ms: f64) -> f64 {
+        if clips_duration_ms <= 0.0 || self.atlas_indices.is_empty() {
+            return 0.0;
+        }
+
+        let clip_ratio = (self.own_duration_ms() / clips_duration_ms) as f32;
+
+        let repetition_duration_ms = (animation_duration_ms as f32 * clip_ratio
+            / self.repetitions.unwrap_or(1).max(1) as f32)
+            as u32;
+
+        (repetition_duration_ms / self.atlas_indices.len() as u32) as f64
+    }
+
+    /// The duration of this clip in milliseconds, taking its repetitions into account.
+    ///
+    /// For per-repetition durations, this is the duration of a single repetition so that clips can share the duration of an animation proportionally.
+    pub(crate) fn duration_with_repetitions_ms(&self, duration: AnimationDuration) -> f64 {
+        let repetitions = self.repetitions.unwrap_or(1);
+
+        let frame_count_with_repetitions = match self.direction.unwrap_or_default() {
+            AnimationDirection::Forwards
+            | AnimationDirection::Backwards
+            | AnimationDirection::Shuffle(_) => {
+                self.atlas_indices.len() as u32 * repetitions as u32
+            }
+            AnimationDirection::PingPong => {
+                self.atlas_indices.len().saturating_sub(1) as u32 * repetitions as u32 + 1
+            }
+        }
+        // Frames skipped during the first repetition
+        .saturating_sub(self.offset.min(self.atlas_indices.len()) as u32);
+
+        let duration_with_repetitions_ms = match duration {
+            AnimationDuration::PerFrame(frame_duration) => {
+                (frame_duration * frame_count_with_repetitions) as f64
+            }
+            AnimationDuration::PerFrameExact(frame_duration) => {
+                frame_duration as f64 * frame_count_with_repetitions as f64
+            }
+            AnimationDuration::PerRepetition(repetition_duration) => repetition_duration as f64,
+        };
+
+        // Account for the frames with a fixed duration (approximately as the first repetition may skip some)
+
+        let frame_duration_ms = match duration {
+            AnimationDuration::PerFrame(frame_duration) => frame_duration as f64,
+            AnimationDuration::PerFrameExact(frame_duration) => frame_duration as f64,
+            AnimationDuration::PerRepetition(repetition_duration) => {
+                repetition_duration as f64 / self.atlas_indices.len().max(1) as f64
+            }
+        };
+
+        let fixed_durations_correction_ms: f64 = self
+            .frame_durations
+            .values()
+            .map(|fixed_duration| *fixed_duration as f64 - frame_duration_ms)
+            .sum::<f64>()
+            * repetitions as f64;
+
+        (duration_with_repetitions_ms + fixed_durations_correction_ms).max(0.0)
+    }
+
+    /// A rough estimation of the heap memory allocated by this clip, in bytes.
+    ///
+    /// See [Animation::estimated_memory_usage()](crate::prelude::Animation::estimated_memory_usage).
+    pub fn estimated_heap_size(&self) -> usize {
+        // Hash maps are approximated by their capacity, ignoring their control bytes
+
+        fn map_size<K, V>(map: &HashMap<K, V>) -> usize {
+            map.capacity() * size_of::<(K, V)>()
+        }
+
+        let markers_size: usize = self
+            .markers
+            .values()
+            .map(|markers| markers.capacity() * size_of::<Marker>())
+            .sum();
+
+        self.atlas_indices.capacity() * size_of::<usize>()
+            + map_size(&self.markers)
+            + markers_size
+            + map_size(&self.frame_durations)
+            + map_size(&self.frame_easings)
+    }
+
+    /// Creates a copy of this clip with its frames in a random order.
+    ///
+    /// The order only depends on `seed` so that the same seed always gives the same order, which is useful for reproducible replays.
+    ///
+    /// The markers, fixed frame durations, frame easings, flips and virtual frames follow their frames to their new positions.
+    ///
+    /// The new clip keeps the parameters of this clip but gets its own [ClipId].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let clip = ClipBuilder::new(spritesheet).add_row(0).build();
+    ///
+    /// let shuffled_clip = clip.with_frame_shuffled(42);
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_clip(shuffled_clip)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn with_frame_shuffled(&self, seed: u64) -> Clip {
+        // Shuffle the frame positions
+
+        let mut order: Vec<usize> = (0..self.atlas_indices.len()).collect();
+
+        fastrand::Rng::with_seed(seed).shuffle(&mut order);
+
+        // Move the frames and their markers to their new positions
+
+        self.with_frame_order(&order)
+    }
+
+    /// Creates a copy of this clip that only contains the frames accepted by a predicate.
+    ///
+    /// This is typically useful for runtime tools that strip frames from existing clips, such as a level-of-detail system that drops every other frame.
+    ///
+    /// The markers, fixed frame durations, frame easings and flips of the kept frames follow them to their new positions while those of the dropped frames are discarded.
+    ///
+    /// The new clip keeps the parameters of this clip but gets its own [ClipId].
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate` - a function that receives the atlas index of a frame and its position in the clip, and returns whether to keep the frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let clip = ClipBuilder::new(spritesheet).add_indices([4, 5, 6, 7, 8]).build();
+    ///
+    /// // Keep every other frame
+    ///
+    /// let low_detail_clip = clip.with_frames_filtered(|_, position| position % 2 == 0);
+    ///
+    /// assert_eq!(low_detail_clip.atlas_indices(), &[4, 6, 8]);
+    /// # }
+    /// ```
+    pub fn with_frames_filtered(&self, predicate: impl Fn(usize, usize) -> bool) -> Clip {
+        let order: Vec<usize> = self
+            .atlas_indices
+            .iter()
+            .enumerate()
+            .filter(|(frame_index, atlas_index)| predicate(**atlas_index, *frame_index))
+            .map(|(frame_index, _)| frame_index)
+            .collect();
+
+        let mut filtered_clip = self.with_frame_order(&order);
+
+        // Only keep the offset and start frame of the frames that remain
+
+        filtered_clip.offset = order
+            .iter()
+            .take_while(|frame_index| **frame_index < self.offset)
+            .count();
+
+        filtered_clip.start_frame = order
+            .iter()
+            .take_while(|frame_index| **frame_index < self.start_frame)
+            .count();
+
+        filtered_clip
+    }
+
+    /// Private structural comparison with another clip, ignoring their IDs and the identity of their markers.
+    ///
+    /// See [Animation::structural_eq()](crate::prelude::Animation::structural_eq).
+    pub(crate) fn structural_eq(&self, other: &Clip) -> bool {
+        let marker_counts = |clip: &Clip| -> HashMap<usize, usize> {
+            clip.markers
+                .iter()
+                .filter(|(_, markers)| !markers.is_empty())
+                .map(|(frame_index, markers)| (*frame_index, markers.len()))
+                .collect()
+        };
+
+        self.atlas_indices == other.atlas_indices
+            && self.duration == other.duration
+            && self.repetitions == other.repetitions
+            && self.direction == other.direction
+            && self.easing == other.easing
+            && self.offset == other.offset
+            && self.start_frame == other.start_frame
+            && self.frame_durations == other.frame_durations
+            && self.frame_easings == other.frame_easings
+            && self.flipped_frames == other.flipped_frames
+            && self.virtual_frames == other.virtual_frames
+            && self.blend_in == other.blend_in
+            && self.blend_out == other.blend_out
+            && marker_counts(self) == marker_counts(other)
+    }
+
+    /// Private constructor of a copy of this clip made of some of its frames, in the given order.
+    ///
+    /// The markers, fixed frame durations, frame easings and flips follow their frames.
+    fn with_frame_order(&self, order: &[usize]) -> Clip {
+        let mut clip = self.duplicate();
+
+        clip.atlas_indices = order
+            .iter()
+            .map(|frame_index| self.atlas_indices[*frame_index])
+            .collect();
+
+        clip.markers = order
+            .iter()
+            .enumerate()
+            .filter_map(|(new_frame_index, frame_index)| {
+                self.markers
+                    .get(frame_index)
+                    .map(|markers| (new_frame_index, markers.clone()))
+            })
+            .collect();
+
+        clip.frame_durations = order
+            .iter()
+            .enumerate()
+            .filter_map(|(new_frame_index, frame_index)| {
+                self.frame_durations
+                    .get(frame_index)
+                    .map(|duration| (new_frame_index, *duration))
+            })
+            .collect();
+
+        clip.flipped_frames = order
+            .iter()
+            .enumerate()
+            .filter(|(_, frame_index)| self.flipped_frames.contains(*frame_index))
+            .map(|(new_frame_index, _)| new_frame_index)
+            .collect();
+
+        clip.virtual_frames = order
+            .iter()
+            .enumerate()
+            .filter(|(_, frame_index)| self.virtual_frames.contains(*frame_index))
+            .map(|(new_frame_index, _)| new_frame_index)
+            .collect();
+
+        clip.frame_easings = order
+            .iter()
+            .enumerate()
+            .filter_map(|(new_frame_index, frame_index)| {
+                self.frame_easings
+                    .get(frame_index)
+                    .map(|easing| (new_frame_index, *easing))
+            })
+            .collect();
+
+        clip
+    }
+
+    /// Creates a copy of this clip that repeats `n` times.
+    ///
+    /// This is the same as [ClipBuilder::set_repetitions()](crate::prelude::ClipBuilder::set_repetitions) but for clips that are already built, which makes it easy to create variations of a clip.
+    ///
+    /// The new clip keeps the other parameters of this clip but gets its own [ClipId].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let clip = ClipBuilder::new(spritesheet).add_row(0).build();
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_clip(clip.with_loop_count(3))
+    ///     .add_clip(clip.with_loop_count(1))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn with_loop_count(&self, n: usize) -> Clip {
+        let mut clip = self.duplicate();
+
+        clip.repetitions = Some(n);
+
+        clip
+    }
+
+    /// Inserts frames at the start of this clip.
+    ///
+    /// This is the same as adding frames with [ClipBuilder](crate::prelude::ClipBuilder) but for clips that are already built, for instance to assemble animations procedurally at runtime.
+    ///
+    /// The markers, fixed frame durations, frame easings and flips of the existing frames follow them to their new positions.
+    /// The [offset](Clip::offset) and [start frame](Clip::start_frame) are kept as they are.
+    ///
+    /// Unlike with the builder, the atlas indices are not checked against the size of the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `frames` - the atlas indices of the frames to insert
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let mut clip = ClipBuilder::new(spritesheet).add_indices([2, 3]).build();
+    ///
+    /// clip.prepend_frames(&[0, 1]).append_frames(&[4]);
+    ///
+    /// assert_eq!(clip.atlas_indices(), &[0, 1, 2, 3, 4]);
+    /// # }
+    /// ```
+    pub fn prepend_frames(&mut self, frames: &[usize]) -> &mut Self {
+        let shift = frames.len();
+
+        self.atlas_indices.splice(0..0, frames.iter().copied());
+
+        self.markers = self
+            .markers
+            .drain()
+            .map(|(frame_index, markers)| (frame_index + shift, markers))
+            .collect();
+
+        self.frame_durations = self
+            .frame_durations
+            .drain()
+            .map(|(frame_index, duration)| (frame_index + shift, duration))
+            .collect();
+
+        self.frame_easings = self
+            .frame_easings
+            .drain()
+            .map(|(frame_index, easing)| (frame_index + shift, easing))
+            .collect();
+
+        self.flipped_frames = self
+            .flipped_frames
+            .drain()
+            .map(|frame_index| frame_index + shift)
+            .collect();
+
+        self.virtual_frames = self
+            .virtual_frames
+            .drain()
+            .map(|frame_index| frame_index + shift)
+            .collect();
+
+        self
+    }
+
+    /// Inserts frames at the end of this clip.
+    ///
+    /// This is the same as adding frames with [ClipBuilder](crate::prelude::ClipBuilder) but for clips that are already built, for instance to assemble animations procedurally at runtime.
+    ///
+    /// Unlike with the builder, the atlas indices are not checked against the size of the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// - `frames` - the atlas indices of the frames to insert
+    pub fn append_frames(&mut self, frames: &[usize]) -> &mut Self {
+        self.atlas_indices.extend_from_slice(frames);
+        self
+    }
+
+    /// Adds a marker on a frame of this clip.
+    ///
+    /// This is the same as [AnimationBuilder::add_clip_marker()](crate::prelude::AnimationBuilder::add_clip_marker) but for clips that are already built, for instance to edit an animation at runtime.
+    ///
+    /// Multiple markers can be added to the same frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `frame_index` - the index of the frame in the clip
+    /// - `marker` - the marker to add
+    pub fn set_marker(&mut self, frame_index: usize, marker: Marker) -> &mut Self {
+        if frame_index >= self.atlas_indices.len() {
+            error!(
+                "{CRATE_NAME}: frame {frame_index} exceeds the clip size ({})",
+                self.atlas_indices.len()
+            );
+        } else {
+            self.markers.entry(frame_index).or_default().push(marker);
+        }
+
+        self
+    }
+
+    /// Removes all the markers from a frame of this clip.
+    ///
+    /// # Arguments
+    ///
+    /// - `frame_index` - the index of the frame in the clip
+    pub fn clear_markers(&mut self, frame_index: usize) -> &mut Self {
+        self.markers.remove(&frame_index);
+        self
+    }
+
+    /// Removes a marker from a frame of this clip.
+    ///
+    /// If the marker was added several times to the same frame, only one occurrence is removed.
+    ///
+    /// Returns whether the marker was present.
+    ///
+    /// # Arguments
+    ///
+    /// - `frame_index` - the index of the frame in the clip
+    /// - `marker` - the marker to remove
+    pub fn remove_marker(&mut self, frame_index: usize, marker: Marker) -> bool {
+        let Some(markers) = self.markers.get_mut(&frame_index) else {
+            return false;
+        };
+
+        let Some(position) = markers.iter().position(|m| *m == marker) else {
+            return false;
+        };
+
+        markers.remove(position);
+
+        if markers.is_empty() {
+            self.markers.remove(&frame_index);
+        }
+
+        true
+    }
+
+    /// Iterates over the `(frame_index, marker)` pairs of this clip, ordered by frame index
+    ///
+    /// Markers attached to the same frame are returned in the order they were added.
+    pub fn marker_frames(&self) -> impl Iterator<Item = (usize, &Marker)> {
+        let mut frames: Vec<_> = self.markers.iter().collect();
+
+        frames.sort_by_key(|(frame_index, _)| **frame_index);
+
+        frames.into_iter().flat_map(|(frame_index, markers)| {
+            markers.iter().map(move |marker| (*frame_index, marker))
+        })
+    }
+}
+
+/// A human-readable summary of a clip, as opposed to the field-by-field [Debug] representation.
+///
+/// The duration is the one of a single repetition of the clip, as if it was played by an animation without duration of its own.
+///
+/// For instance: `Clip { frames: 8, duration: 800ms, direction: forwards, repetitions: 3, markers: [(4, Marker("footstep"))] }`
+impl fmt::Display for Clip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Markers are printed with their names when they have one
+
+        struct MarkerSummary<'a>(&'a Marker);
+
+        impl fmt::Debug for MarkerSummary<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.0.display_name() {
+                    Some(name) => f.debug_tuple("Marker").field(&name).finish(),
+                    None => f.debug_tuple("Marker").field(&self.0.value).finish(),
+                }
+            }
+        }
+
+        // (the frame duration is not rounded so that per-repetition durations add up exactly)
+
+        let frame_duration_ms = match self
+            .duration
+            .unwrap_or(AnimationContext::default().default_duration)
+        {
+            AnimationDuration::PerFrame(frame_duration_ms) => frame_duration_ms as f64,
+            AnimationDuration::PerFrameExact(frame_duration_ms) => frame_duration_ms as f64,
+            AnimationDuration::PerRepetition(repetition_duration_ms) => {
+                repetition_duration_ms as f64 / self.atlas_indices.len().max(1) as f64
+            }
+        };
+
+        let duration_ms: f64 = (0..self.atlas_indices.len())
+            .map(|frame_index| {
+                self.frame_durations
+                    .get(&frame_index)
+                    .map_or(frame_duration_ms, |duration_ms| *duration_ms as f64)
+            })
+            .sum();
+
+        let markers: Vec<_> = self
+            .marker_frames()
+            .map(|(frame_index, marker)| (frame_index, MarkerSummary(marker)))
+            .collect();
+
+        // Virtual frames are counted apart as they only hold the previous frame
+
+        let frames = match self.virtual_frames.len() {
+            0 => self.atlas_indices.len().to_string(),
+            virtual_frames => format!("{} ({virtual_frames} virtual)", self.atlas_indices.len()),
+        };
+
+        write!(
+            f,
+            "Clip {{ frames: {frames}, duration: {}ms, direction: {}, repetitions: {}, markers: {markers:?} }}",
+            duration_ms.max(0.0).round() as u64,
+            self.direction.unwrap_or_default(),
+            self.repetitions.unwrap_or(1),
+        )
+    }
 }
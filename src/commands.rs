@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::{
+    CRATE_NAME, animation::Animation, components::spritesheet_animation::SpritesheetAnimation,
+};
+
+/// An extension trait to control the animations of entities with [Commands].
+///
+/// The changes are deferred until the commands are applied.
+/// This is convenient in observers and other contexts in which querying the [SpritesheetAnimation] components would be impractical.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Event)]
+/// struct Hit(Entity);
+///
+/// #[derive(Resource)]
+/// struct HurtAnimation(Handle<Animation>);
+///
+/// fn on_hit(hit: On<Hit>, hurt_animation: Res<HurtAnimation>, mut commands: Commands) {
+///     commands
+///         .entity(hit.0)
+///         .play_animation(hurt_animation.0.clone());
+/// }
+/// ```
+pub trait SpritesheetAnimationCommands {
+    /// Plays an animation on the entity from its first frame.
+    ///
+    /// The entity's existing [SpritesheetAnimation] [switches](SpritesheetAnimation::switch) to the new animation.
+    /// A new [SpritesheetAnimation] is inserted if the entity does not have one.
+    ///
+    /// # Arguments
+    ///
+    /// - `animation` - the handle of the animation to play
+    fn play_animation(&mut self, animation: Handle<Animation>) -> &mut Self;
+
+    /// Pauses the animation of the entity.
+    ///
+    /// See [SpritesheetAnimation::pause()].
+    fn pause_animation(&mut self) -> &mut Self;
+
+    /// Resumes the animation of the entity.
+    ///
+    /// See [SpritesheetAnimation::play()].
+    fn resume_animation(&mut self) -> &mut Self;
+
+    /// Resets the animation of the entity to its first frame.
+    ///
+    /// See [SpritesheetAnimation::reset()].
+    fn reset_animation(&mut self) -> &mut Self;
+}
+
+impl SpritesheetAnimationCommands for EntityCommands<'_> {
+    fn play_animation(&mut self, animation: Handle<Animation>) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            match entity.get_mut::<SpritesheetAnimation>() {
+                Some(mut spritesheet_animation) => spritesheet_animation.switch(animation),
+                None => {
+                    entity.insert(SpritesheetAnimation::new(animation));
+                }
+            }
+        })
+    }
+
+    fn pause_animation(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| {
+            update_animation(entity, "pause", SpritesheetAnimation::pause)
+        })
+    }
+
+    fn resume_animation(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| {
+            update_animation(entity, "resume", SpritesheetAnimation::play)
+        })
+    }
+
+    fn reset_animation(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| {
+            update_animation(entity, "reset", SpritesheetAnimation::reset)
+        })
+    }
+}
+
+fn update_animation(
+    mut entity: EntityWorldMut,
+    action: &str,
+    f: impl FnOnce(&mut SpritesheetAnimation),
+) {
+    match entity.get_mut::<SpritesheetAnimation>() {
+        Some(mut spritesheet_animation) => f(&mut spritesheet_animation),
+        None => error!(
+            "{CRATE_NAME}: cannot {action} the animation of entity {} that has no SpritesheetAnimation",
+            entity.id()
+        ),
+    }
+}
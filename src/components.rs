@@ -1,4 +1,7 @@
+pub mod frame_remapper;
 pub mod generator;
+pub mod profiling;
+pub mod recorder;
 #[cfg(feature = "3d")]
 pub mod sprite3d;
 pub mod spritesheet_animation;
@@ -0,0 +1,61 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// A Bevy component that remaps the atlas indices of an animated entity.
+///
+/// When present alongside a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component, the atlas index of each frame is passed through the remapping function before being assigned to the entity's texture atlas.
+///
+/// This is convenient to swap texture atlas pages at runtime (for instance, to procedurally recolor a sprite) without creating new animations.
+///
+/// Replacing or mutating the component immediately updates the current frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn create_animated_sprite(
+///     mut commands: Commands,
+///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+///     # spritesheet: &Spritesheet,
+///     # animation: Handle<Animation>
+/// ) {
+///     // ...omitted: create a spritesheet with 8 columns and an animation
+///
+///     let sprite = spritesheet
+///         .with_size_hint(600, 400)
+///         .sprite(&mut atlas_layouts);
+///
+///     commands.spawn((
+///         sprite,
+///         SpritesheetAnimation::new(animation),
+///         // Use the frames from the row just below the original ones, which contains a recolored version
+///         FrameRemapper::new(|atlas_index| atlas_index + 8),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct FrameRemapper(pub Box<dyn Fn(usize) -> usize + Send + Sync>);
+
+impl FrameRemapper {
+    /// Creates a [FrameRemapper] component.
+    ///
+    /// # Arguments
+    ///
+    /// - `remap` - the function that maps an animation's atlas index to the one to display
+    pub fn new(remap: impl Fn(usize) -> usize + Send + Sync + 'static) -> Self {
+        Self(Box::new(remap))
+    }
+
+    /// Remaps an atlas index.
+    pub fn remap(&self, atlas_index: usize) -> usize {
+        (self.0)(atlas_index)
+    }
+}
+
+impl fmt::Debug for FrameRemapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrameRemapper").finish_non_exhaustive()
+    }
+}
@@ -237,13 +237,15 @@ impl ComponentGenerator {
     /// }
     /// ```
     pub fn atlas(&self, atlas_layouts: &mut Assets<TextureAtlasLayout>) -> TextureAtlas {
-        let cell_width = self.image_width / (self.spritesheet.columns() as u32);
-        let cell_height = self.image_height / (self.spritesheet.rows() as u32);
+        let (columns, rows) = self.spritesheet.image_grid_dimensions();
+
+        let cell_width = self.image_width / (columns as u32);
+        let cell_height = self.image_height / (rows as u32);
 
         let layout = TextureAtlasLayout::from_grid(
             UVec2::new(cell_width, cell_height),
-            self.spritesheet.columns() as u32,
-            self.spritesheet.rows() as u32,
+            columns as u32,
+            rows as u32,
             None,
             None,
         );
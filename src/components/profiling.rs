@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// A Bevy component that measures the time spent playing the animation of an entity.
+///
+/// Add this component to an animated entity to profile it.
+/// Entities without this component are not measured, so profiling has no cost for them.
+///
+/// The measurements of all the profiled entities can be aggregated into Bevy diagnostics with the [SpritesheetAnimationDiagnosticsPlugin](crate::prelude::SpritesheetAnimationDiagnosticsPlugin).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn report(sprites: Query<(Entity, &AnimationProfiling)>) {
+///     for (entity, profiling) in &sprites {
+///         info!(
+///             "{entity}: {:?}",
+///             profiling.last_frame_advance_time()
+///         );
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct AnimationProfiling {
+    /// The time spent advancing the animation during the last update
+    pub(crate) last_frame_advance_time: Duration,
+}
+
+impl AnimationProfiling {
+    /// The time spent advancing the animation of the entity during the last update of the animations
+    pub fn last_frame_advance_time(&self) -> Duration {
+        self.last_frame_advance_time
+    }
+}
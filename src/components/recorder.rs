@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    animation::{Animation, AnimationDuration, AnimationRepeat},
+    clip::Clip,
+};
+
+/// A frame captured by an [AnimationRecorder].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct RecordedFrame {
+    /// The index of the app update, counted from the start of the recording, during which the frame was played
+    pub tick: u64,
+
+    /// The time elapsed from the start of the recording to the moment the frame was played
+    pub time: Duration,
+
+    /// The index of the clip that contains the frame
+    pub clip_index: usize,
+
+    /// The index of the frame in the animation
+    ///
+    /// See [AnimationProgress::frame](crate::prelude::AnimationProgress::frame).
+    pub frame_index: usize,
+
+    /// The atlas index of the frame, before any [FrameRemapper](crate::prelude::FrameRemapper) is applied
+    pub atlas_index: usize,
+}
+
+/// A Bevy component that records the frames played by a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) over time.
+///
+/// Add this component to an animated entity to start recording.
+/// The recording can then be converted into a regular [Animation] with [AnimationRecorder::playback()] to replay it later exactly as it was played, including manual progress updates, speed changes and pauses.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn start_recording(mut commands: Commands, sprite: Single<Entity, With<SpritesheetAnimation>>) {
+///     commands.entity(*sprite).insert(AnimationRecorder::default());
+/// }
+///
+/// fn stop_recording(
+///     mut commands: Commands,
+///     sprite: Single<(Entity, &AnimationRecorder)>,
+///     mut animations: ResMut<Assets<Animation>>,
+/// ) {
+///     let (entity, recorder) = *sprite;
+///
+///     let replay = animations.add(recorder.playback());
+///
+///     commands
+///         .entity(entity)
+///         .remove::<AnimationRecorder>()
+///         .insert(SpritesheetAnimation::new(replay));
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct AnimationRecorder {
+    /// The recorded frames
+    frames: Vec<RecordedFrame>,
+
+    /// The number of updates since the start of the recording
+    ticks: u64,
+
+    /// The time elapsed since the start of the recording
+    elapsed: Duration,
+}
+
+impl AnimationRecorder {
+    /// The frames recorded so far, in the order they were played
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    /// The time elapsed since the start of the recording
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Discards the recorded frames and restarts the recording.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Converts the recording into an animation.
+    ///
+    /// Each recorded frame becomes a single-frame clip lasting until the next recorded frame.
+    /// The last frame lasts until the current time of the recording.
+    ///
+    /// The animation is played once.
+    pub fn playback(&self) -> RecordedAnimation {
+        let mut animation = Animation::empty();
+
+        animation.repetitions = Some(AnimationRepeat::Times(1));
+
+        animation.clips = self
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(index, frame)| {
+                let end = self
+                    .frames
+                    .get(index + 1)
+                    .map(|next_frame| next_frame.time)
+                    .unwrap_or(self.elapsed);
+
+                let mut clip = Clip::empty();
+
+                clip.atlas_indices.push(frame.atlas_index);
+                clip.duration = Some(AnimationDuration::PerFrame(
+                    end.saturating_sub(frame.time).as_millis() as u32,
+                ));
+
+                clip
+            })
+            .collect();
+
+        RecordedAnimation {
+            animation,
+            duration: self.elapsed.saturating_sub(
+                self.frames
+                    .first()
+                    .map(|frame| frame.time)
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Moves the time of the recording forwards.
+    pub(crate) fn tick(&mut self, delta: Duration) {
+        self.ticks += 1;
+        self.elapsed += delta;
+    }
+
+    /// Records a frame that started `time_ago` earlier in the current update.
+    pub(crate) fn record(
+        &mut self,
+        clip_index: usize,
+        frame_index: usize,
+        atlas_index: usize,
+        time_ago: Duration,
+    ) {
+        self.frames.push(RecordedFrame {
+            tick: self.ticks,
+            time: self.elapsed.saturating_sub(time_ago),
+            clip_index,
+            frame_index,
+            atlas_index,
+        });
+    }
+}
+
+/// An animation created from a recording with [AnimationRecorder::playback()].
+///
+/// Convert it into a regular [Animation] with `into()` to register it.
+#[derive(Debug, Clone)]
+pub struct RecordedAnimation {
+    animation: Animation,
+    duration: Duration,
+}
+
+impl RecordedAnimation {
+    /// The recorded animation
+    pub fn animation(&self) -> &Animation {
+        &self.animation
+    }
+
+    /// The total duration of the recorded animation
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl From<RecordedAnimation> for Animation {
+    fn from(recorded_animation: RecordedAnimation) -> Self {
+        recorded_animation.animation
+    }
+}
@@ -1,6 +1,13 @@
+use std::{fmt, sync::Arc, time::Duration};
+
 use bevy::prelude::*;
 
-use crate::animation::Animation;
+use crate::{
+    CRATE_NAME,
+    animation::{Animation, AnimationDirection},
+    animator::cache::{AnimationCache, CacheFrame},
+    clip::ClipId,
+};
 
 /// The progress of an animation being played.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
@@ -40,6 +47,9 @@ impl AnimationProgress {
 ///
 /// It references an [Animation] and contains playback-related attributes.
 ///
+/// The component is reflected, so it can be saved to and loaded from Bevy scenes.
+/// As scenes cannot serialize strong handles, the animation must then be referenced with a stable handle, such as one created with the `uuid_handle!` macro.
+///
 /// # Example
 ///
 /// ```
@@ -86,7 +96,47 @@ pub struct SpritesheetAnimation {
     pub playing: bool,
 
     /// A speed multiplier for the animation (default = `1`)
+    ///
+    /// This is combined with the relative speed of Bevy's virtual clock, [`Time<Virtual>`](Virtual), which applies to all the animations.
     pub speed_factor: f32,
+
+    /// Should [FrameChangedEvents](crate::prelude::FrameChangedEvent) be emitted for this animation? (default = `false`)
+    pub frame_changed_events: bool,
+
+    /// The pre-computed frames of the animation, assigned by the animator when the animation starts playing
+    ///
+    /// They are only valid while [SpritesheetAnimation::animation] is the animation they were computed for (see [SpritesheetAnimation::playing_cache()]).
+    #[reflect(ignore)]
+    pub(crate) playing_animation: PlayingAnimation,
+
+    /// The animation to resume, and where, after an interruption started with [SpritesheetAnimation::play_once_then_resume]
+    pub(crate) resume: Option<(Handle<Animation>, AnimationProgress)>,
+
+    /// The inclusive range of frames to loop over, set with [SpritesheetAnimation::play_segment]
+    pub(crate) segment: Option<(usize, usize)>,
+
+    /// The atlas index to display during the next update instead of the current frame, set with [SpritesheetAnimation::override_frame]
+    pub(crate) frame_override: Option<usize>,
+
+    /// The entity whose animation to catch up with during the next update, set with [SpritesheetAnimation::synchronize_with]
+    pub(crate) synchronization: Option<Entity>,
+}
+
+/// The cached version of the animation currently played by a [SpritesheetAnimation]
+///
+/// This gives access to the structure of the animation without going through the `Animation` assets.
+///
+/// The cache is stored along with the ID of its animation so that it can be discarded as soon as the component switches to another animation.
+#[derive(Clone, Default)]
+pub(crate) struct PlayingAnimation(pub(crate) Option<(AssetId<Animation>, Arc<AnimationCache>)>);
+
+impl fmt::Debug for PlayingAnimation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some((_, cache)) => write!(f, "PlayingAnimation({} frames)", cache.frames.len()),
+            None => write!(f, "PlayingAnimation(none)"),
+        }
+    }
 }
 
 impl SpritesheetAnimation {
@@ -104,9 +154,42 @@ impl SpritesheetAnimation {
             },
             playing: true,
             speed_factor: 1.0,
+            frame_changed_events: false,
+            playing_animation: PlayingAnimation::default(),
+            resume: None,
+            segment: None,
+            frame_override: None,
+            synchronization: None,
         }
     }
 
+    /// Creates a [SpritesheetAnimation] component that displays a single frame of an animation without playing it.
+    ///
+    /// This is typically useful for inventory icons or portraits that reuse the frames of an animation.
+    ///
+    /// The animation is paused on the frame, so it can still be played later with [play()](SpritesheetAnimation::play).
+    /// An invalid frame displays the first frame of the animation instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `animation` - the handle of the animation to display
+    /// - `frame` - the index of the frame to display, like [AnimationProgress::frame]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(mut commands: Commands, sprite: Sprite, animation: Handle<Animation>) {
+    /// commands.spawn((sprite, SpritesheetAnimation::at_frame(animation, 3)));
+    /// # }
+    /// ```
+    pub fn at_frame(animation: Handle<Animation>, frame: usize) -> Self {
+        Self::new(animation)
+            .with_progress(AnimationProgress::with_frame(frame))
+            .with_playing(false)
+    }
+
     pub fn with_progress(mut self, progress: AnimationProgress) -> Self {
         self.progress = progress;
         self
@@ -122,6 +205,11 @@ impl SpritesheetAnimation {
         self
     }
 
+    pub fn with_frame_changed_events(mut self, frame_changed_events: bool) -> Self {
+        self.frame_changed_events = frame_changed_events;
+        self
+    }
+
     /// Resumes the animation.
     pub fn play(&mut self) {
         self.playing = true;
@@ -139,8 +227,495 @@ impl SpritesheetAnimation {
     }
 
     /// Switches to a different animation.
+    ///
+    /// This cancels any pending [play_once_then_resume()](SpritesheetAnimation::play_once_then_resume) and any [segment](SpritesheetAnimation::play_segment).
     pub fn switch(&mut self, animation: Handle<Animation>) {
         self.animation = animation;
         self.reset();
+        self.resume = None;
+        self.segment = None;
+    }
+
+    /// Switches to a different animation, unless it's already the current one.
+    ///
+    /// This is the same as [switch()](SpritesheetAnimation::switch) except that the animation keeps its progress and timing if `animation` is already playing.
+    ///
+    /// This is typically useful for systems that assign animations every frame from the state of an entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Component)]
+    /// struct Character {
+    ///     running: bool,
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Animations {
+    ///     idle: Handle<Animation>,
+    ///     run: Handle<Animation>,
+    /// }
+    ///
+    /// fn update_animations(
+    ///     animations: Res<Animations>,
+    ///     mut characters: Query<(&Character, &mut SpritesheetAnimation)>,
+    /// ) {
+    ///     for (character, mut sprite) in &mut characters {
+    ///         let animation = if character.running {
+    ///             &animations.run
+    ///         } else {
+    ///             &animations.idle
+    ///         };
+    ///
+    ///         // Does not restart the animation if it's already playing
+    ///         sprite.set_animation_keeping_frame(animation.clone());
+    ///     }
+    /// }
+    /// ```
+    pub fn set_animation_keeping_frame(&mut self, animation: Handle<Animation>) {
+        if self.animation != animation {
+            self.switch(animation);
+        }
+    }
+
+    /// Does this component reference the same animation as another one?
+    ///
+    /// Only the animations are compared, regardless of their playback state such as their progress.
+    /// This is convenient to check if an entity already plays the same animation as another one.
+    ///
+    /// # Arguments
+    ///
+    /// - `other` - the other component
+    pub fn is_same_animation(&self, other: &SpritesheetAnimation) -> bool {
+        self.animation.id() == other.animation.id()
+    }
+
+    /// Interrupts the current animation to play another one exactly once, then resumes the current animation.
+    ///
+    /// The interrupting animation stops after its first repetition, regardless of its own [AnimationRepeat](crate::prelude::AnimationRepeat) parameter.
+    /// The interrupted animation then resumes from the frame and repetition at which it was interrupted.
+    ///
+    /// If an interruption is already in progress, it is replaced by the new one and the originally-interrupted animation will still be the one resumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `animation` - the handle of the interrupting animation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Animations {
+    ///     attack: Handle<Animation>,
+    /// }
+    ///
+    /// fn attack(
+    ///     keys: Res<ButtonInput<KeyCode>>,
+    ///     animations: Res<Animations>,
+    ///     mut sprites: Query<&mut SpritesheetAnimation>,
+    /// ) {
+    ///     if keys.just_pressed(KeyCode::Space) {
+    ///         for mut sprite in &mut sprites {
+    ///             // Play the attack animation then go back to whatever was playing
+    ///             sprite.play_once_then_resume(animations.attack.clone());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn play_once_then_resume(&mut self, animation: Handle<Animation>) {
+        if self.resume.is_none() {
+            self.resume = Some((self.animation.clone(), self.progress));
+        }
+
+        self.animation = animation;
+        self.reset();
+    }
+
+    /// Is an animation started with [play_once_then_resume()](SpritesheetAnimation::play_once_then_resume) currently playing?
+    pub fn is_interrupted(&self) -> bool {
+        self.resume.is_some()
+    }
+
+    /// Restricts the playback to a range of frames of the current animation.
+    ///
+    /// The animation jumps to `start_frame` and then loops over the frames from `start_frame` to `end_frame`, included, until [play_full()](SpritesheetAnimation::play_full) is called.
+    /// The [Animation] asset itself is not modified.
+    ///
+    /// This is typically useful when a single animation contains a long strip of frames, different portions of which are played depending on the state of the game.
+    ///
+    /// The frames are the same absolute indices as the ones of [AnimationProgress::frame].
+    /// Frames past the last frame of the current repetition are clamped to it, so the segment never rolls over into the next repetition.
+    /// Looping back to the start of the segment does not emit repetition events.
+    ///
+    /// # Arguments
+    ///
+    /// - `start_frame` - the index of the first frame of the segment
+    /// - `end_frame` - the index of the last frame of the segment
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn charge(mut sprites: Query<&mut SpritesheetAnimation>) {
+    ///     for mut sprite in &mut sprites {
+    ///         // Loop over the "charging" frames of the attack
+    ///         sprite.play_segment(3, 5);
+    ///     }
+    /// }
+    /// ```
+    pub fn play_segment(&mut self, start_frame: usize, end_frame: usize) {
+        if start_frame > end_frame {
+            error!(
+                "{CRATE_NAME}: the start frame of a segment ({start_frame}) must not exceed its end frame ({end_frame})"
+            );
+            return;
+        }
+
+        self.segment = Some((start_frame, end_frame));
+        self.progress.frame = start_frame;
+    }
+
+    /// Plays the whole animation again after a [segment](SpritesheetAnimation::play_segment).
+    ///
+    /// The animation continues from its current frame.
+    pub fn play_full(&mut self) {
+        self.segment = None;
+    }
+
+    /// The inclusive range of frames being looped over, if restricted with [play_segment()](SpritesheetAnimation::play_segment)
+    pub fn segment(&self) -> Option<(usize, usize)> {
+        self.segment
+    }
+
+    /// The segment being looped over, clamped to the frames of the current repetition
+    pub(crate) fn playing_segment(&self) -> Option<(usize, usize)> {
+        let cache = self.playing_cache()?;
+        let (start_frame, end_frame) = self.segment?;
+
+        let last_frame = cache
+            .repetition_frames(self.progress.repetition)
+            .len()
+            .checked_sub(1)?;
+
+        Some((start_frame.min(last_frame), end_frame.min(last_frame)))
+    }
+
+    /// Displays an atlas index instead of the current frame during the next update only.
+    ///
+    /// This is typically useful to force a specific pose for a single tick in response to a gameplay event, such as a hit frame.
+    ///
+    /// The animation does not advance during that update and then resumes normally from the frame it was on, which is displayed again.
+    /// If the animation is over by then, the overridden atlas index stays displayed.
+    ///
+    /// The atlas index is remapped by the [FrameRemapper](crate::prelude::FrameRemapper) of the entity, if any, like the other frames.
+    ///
+    /// # Arguments
+    ///
+    /// - `atlas_index` - the atlas index to display
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// const HIT_FRAME: usize = 42;
+    ///
+    /// fn flash_on_hit(mut sprites: Query<&mut SpritesheetAnimation>) {
+    ///     for mut sprite in &mut sprites {
+    ///         sprite.override_frame(HIT_FRAME);
+    ///     }
+    /// }
+    /// ```
+    pub fn override_frame(&mut self, atlas_index: usize) {
+        self.frame_override = Some(atlas_index);
+    }
+
+    /// Moves the animation to the current frame of another entity's animation during the next update, including the time already spent on that frame.
+    ///
+    /// From then on, both animations advance by the same time on each update and stay in phase as long as they have the same [speed factor](SpritesheetAnimation::speed_factor) and are both playing.
+    /// This is typically useful to animate a group of entities in unison, such as a squad marching together.
+    ///
+    /// The frame is looked up by its [progress](SpritesheetAnimation::progress), so the two entities may play different animations with the same structure, such as variants of the same animation.
+    /// If the other entity does not play an animation, or if its frame does not exist in this animation, an error is logged and this animation is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// - `entity` - the entity with the [SpritesheetAnimation] to synchronize with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Component)]
+    /// struct SquadLeader;
+    ///
+    /// fn join_squad(
+    ///     new_members: Query<&mut SpritesheetAnimation, Added<SpritesheetAnimation>>,
+    ///     leader: Single<Entity, With<SquadLeader>>,
+    /// ) {
+    ///     for mut member in new_members {
+    ///         member.synchronize_with(*leader);
+    ///     }
+    /// }
+    /// ```
+    pub fn synchronize_with(&mut self, entity: Entity) {
+        self.synchronization = Some(entity);
+    }
+
+    /// Is the animation on the first frame of its current repetition?
+    ///
+    /// The frames are considered in the order they are played, so this accounts for the [AnimationDirection] of the animation and its clips.
+    /// For instance, the first frame of a backwards animation is its last atlas index.
+    ///
+    /// While a [segment](SpritesheetAnimation::play_segment) is played, this is the first frame of the segment.
+    ///
+    /// This relies on the state of the animation as of the last update, so `false` is returned until the animation started playing.
+    pub fn is_on_first_frame(&self) -> bool {
+        if let Some((start_frame, _)) = self.playing_segment() {
+            return self.progress.frame == start_frame;
+        }
+
+        self.playing_cache().is_some_and(|cache| {
+            // PingPong animations skip their first frame after the first repetition
+
+            let first_frame = if matches!(cache.animation_direction, AnimationDirection::PingPong)
+                && self.progress.repetition > 0
+            {
+                1
+            } else {
+                0
+            };
+
+            self.progress.frame == first_frame
+        })
+    }
+
+    /// Is the animation on the last frame of its current repetition?
+    ///
+    /// This is typically useful to trigger transitions to other animations.
+    ///
+    /// The frames are considered in the order they are played, so this accounts for the [AnimationDirection] of the animation and its clips.
+    /// For instance, the last frame of a backwards animation is its first atlas index.
+    ///
+    /// While a [segment](SpritesheetAnimation::play_segment) is played, this is the last frame of the segment.
+    ///
+    /// This relies on the state of the animation as of the last update, so `false` is returned until the animation started playing.
+    pub fn is_on_last_frame(&self) -> bool {
+        if let Some((_, end_frame)) = self.playing_segment() {
+            return self.progress.frame == end_frame;
+        }
+
+        self.playing_cache().is_some_and(|cache| {
+            self.progress.frame + 1 == cache.repetition_frames(self.progress.repetition).len()
+        })
+    }
+
+    /// The ID of the clip that contains the current frame.
+    ///
+    /// This can be used to correlate the playing clip with the [ClipIds](ClipId) found in [AnimationEvents](crate::prelude::AnimationEvent) or retrieved when building the animation.
+    ///
+    /// This relies on the state of the animation as of the last update, so `None` is returned until the animation started playing.
+    pub fn active_clip_id(&self) -> Option<ClipId> {
+        self.current_frame().map(|frame| frame.clip_id)
+    }
+
+    /// The index of the clip that contains the current frame in the [clips](crate::prelude::Animation::clips) of the animation.
+    ///
+    /// Unlike [active_clip_id()](SpritesheetAnimation::active_clip_id), this tells apart the occurrences of a clip added several times to the animation.
+    ///
+    /// This relies on the state of the animation as of the last update, so `None` is returned until the animation started playing.
+    pub fn active_clip_index(&self) -> Option<usize> {
+        self.current_frame().map(|frame| frame.clip_index)
+    }
+
+    /// The effective rate at which the animation plays, in frames per second.
+    ///
+    /// This combines the [speed_factor](SpritesheetAnimation::speed_factor) of the animation with the duration of its current frame, which depends on the [AnimationDuration](crate::prelude::AnimationDuration) and [Easing](crate::prelude::Easing) of the animation and its clips.
+    ///
+    /// This is typically useful for debugging or to display the playback rate in a UI.
+    ///
+    /// Returns `0` if the animation is paused or if its current frame has no duration.
+    ///
+    /// This relies on the state of the animation as of the last update, so `0` is also returned until the animation started playing.
+    pub fn playback_rate(&self) -> f32 {
+        if !self.playing {
+            return 0.0;
+        }
+
+        match self.current_frame() {
+            Some(frame) if !frame.duration.is_zero() => {
+                self.speed_factor.max(0.0) / frame.duration.as_secs_f32()
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// The number of frames left to play after the current one, across all the remaining clips and repetitions of the animation.
+    ///
+    /// This is typically useful to display progress bars.
+    ///
+    /// Returns `None` if the animation loops forever or [for a duration](crate::prelude::AnimationRepeat::LoopFor), or while a [segment](SpritesheetAnimation::play_segment) loops.
+    ///
+    /// This relies on the state of the animation as of the last update, so `None` is also returned until the animation started playing.
+    pub fn frames_remaining(&self) -> Option<usize> {
+        let cache = self.playing_cache()?;
+
+        if self.segment.is_some() {
+            return None;
+        }
+
+        // Animations without frames never play, whatever their repetitions
+
+        if cache.frames.is_empty() {
+            return Some(0);
+        }
+
+        let (even_repetitions, odd_repetitions) = self.next_repetitions(cache)?;
+
+        // PingPong animations skip their first frame after the first repetition
+
+        let skipped_frames = if matches!(cache.animation_direction, AnimationDirection::PingPong) {
+            1
+        } else {
+            0
+        };
+
+        let current_repetition_remaining = cache
+            .repetition_frames(self.progress.repetition)
+            .len()
+            .saturating_sub(self.progress.frame + 1);
+
+        let even_frames = cache
+            .repetition_frames(2)
+            .len()
+            .saturating_sub(skipped_frames);
+        let odd_frames = cache
+            .repetition_frames(1)
+            .len()
+            .saturating_sub(skipped_frames);
+
+        Some(
+            current_repetition_remaining
+                .saturating_add(even_frames.saturating_mul(even_repetitions))
+                .saturating_add(odd_frames.saturating_mul(odd_repetitions)),
+        )
+    }
+
+    /// The time left to play the frames after the current one, across all the remaining clips and repetitions of the animation.
+    ///
+    /// This is typically useful to synchronize cutscenes with the end of an animation.
+    ///
+    /// The durations of the frames are divided by the [speed_factor](SpritesheetAnimation::speed_factor) of the animation.
+    /// The relative speed of Bevy's virtual clock is not accounted for.
+    ///
+    /// The remainder of the current frame is not included, so the animation actually completes up to one frame later.
+    ///
+    /// Returns `None` if the animation loops forever or [for a duration](crate::prelude::AnimationRepeat::LoopFor), while a [segment](SpritesheetAnimation::play_segment) loops,
+    /// if its speed factor is not positive, or if the time left is too large to be represented.
+    ///
+    /// This relies on the state of the animation as of the last update, so `None` is also returned until the animation started playing.
+    pub fn expected_completion_time(&self) -> Option<Duration> {
+        let cache = self.playing_cache()?;
+
+        if self.segment.is_some() || self.speed_factor.is_nan() || self.speed_factor <= 0.0 {
+            return None;
+        }
+
+        // Animations without frames never play, whatever their repetitions
+
+        if cache.frames.is_empty() {
+            return Some(Duration::ZERO);
+        }
+
+        let (even_repetitions, odd_repetitions) = self.next_repetitions(cache)?;
+
+        // PingPong animations skip their first frame after the first repetition
+
+        let skipped_frames = if matches!(cache.animation_direction, AnimationDirection::PingPong) {
+            1
+        } else {
+            0
+        };
+
+        // (summed in nanoseconds to detect overflows)
+        let total_nanos = |frames: &[CacheFrame]| -> Option<u128> {
+            frames.iter().try_fold(0u128, |total, frame| {
+                total.checked_add(frame.duration.as_nanos())
+            })
+        };
+
+        let current_repetition_remaining = total_nanos(
+            cache
+                .repetition_frames(self.progress.repetition)
+                .get(self.progress.frame + 1..)
+                .unwrap_or_default(),
+        )?;
+
+        let even_repetition = total_nanos(
+            cache
+                .repetition_frames(2)
+                .get(skipped_frames..)
+                .unwrap_or_default(),
+        )?;
+
+        let odd_repetition = total_nanos(
+            cache
+                .repetition_frames(1)
+                .get(skipped_frames..)
+                .unwrap_or_default(),
+        )?;
+
+        let remaining_nanos = even_repetition
+            .checked_mul(even_repetitions as u128)?
+            .checked_add(odd_repetition.checked_mul(odd_repetitions as u128)?)?
+            .checked_add(current_repetition_remaining)?;
+
+        let remaining = Duration::new(
+            u64::try_from(remaining_nanos / 1_000_000_000).ok()?,
+            (remaining_nanos % 1_000_000_000) as u32,
+        );
+
+        Duration::try_from_secs_f64(remaining.as_secs_f64() / self.speed_factor as f64).ok()
+    }
+
+    /// The numbers of even and odd repetitions left to play after the current one.
+    ///
+    /// Returns `None` if the animation does not have a fixed number of repetitions.
+    fn next_repetitions(&self, cache: &AnimationCache) -> Option<(usize, usize)> {
+        let repetitions = cache.repetitions?;
+
+        let next_repetition = (self.progress.repetition + 1).min(repetitions);
+
+        // (there are (n + 1) / 2 even numbers in 0..n)
+        let even_repetitions = repetitions.div_ceil(2) - next_repetition.div_ceil(2);
+        let odd_repetitions = repetitions - next_repetition - even_repetitions;
+
+        Some((even_repetitions, odd_repetitions))
+    }
+
+    /// The cached version of the current animation, if the animator started playing it
+    ///
+    /// `None` is returned after switching to another animation until the animator picks it up, rather than describing the previous animation.
+    pub(crate) fn playing_cache(&self) -> Option<&AnimationCache> {
+        self.playing_animation
+            .0
+            .as_ref()
+            .filter(|(animation_id, _)| *animation_id == self.animation.id())
+            .map(|(_, cache)| cache.as_ref())
+    }
+
+    /// The cached frame matching the current progress of the animation
+    fn current_frame(&self) -> Option<&CacheFrame> {
+        let cache = self.playing_cache()?;
+
+        cache
+            .repetition_frames(self.progress.repetition)
+            .get(self.progress.frame)
     }
 }
@@ -0,0 +1,25 @@
+//! Constants for the default parameters of animations.
+//!
+//! The parameter types are plain enums so that they can also be used to declare custom constants:
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use bevy_spritesheet_animation::prelude::*;
+//! const WALK_REPEAT: AnimationRepeat = AnimationRepeat::Times(4);
+//! const IDLE_REPEAT: AnimationRepeat = AnimationRepeat::LoopFor(Duration::from_secs(3));
+//! const FAST: AnimationDuration = AnimationDuration::PerFrameExact(1000.0 / 24.0);
+//! ```
+
+use crate::animation::{AnimationDirection, AnimationDuration, AnimationRepeat};
+
+/// The duration of the animations that do not specify one: 100ms per frame
+pub const DEFAULT_DURATION: AnimationDuration = AnimationDuration::PerFrame(100);
+
+/// The repetitions of the animations that do not specify them: loops forever
+pub const DEFAULT_REPEAT: AnimationRepeat = AnimationRepeat::Loop;
+
+/// The direction of the animations that do not specify one: forwards
+pub const DEFAULT_DIRECTION: AnimationDirection = AnimationDirection::Forwards;
+
+/// Plays an animation a single time
+pub const PLAY_ONCE: AnimationRepeat = AnimationRepeat::Times(1);
@@ -0,0 +1,72 @@
+use bevy::{
+    diagnostic::{
+        DEFAULT_MAX_HISTORY_LENGTH, Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic,
+    },
+    prelude::*,
+};
+
+use crate::{components::profiling::AnimationProfiling, plugin::AnimationSystemSet};
+
+/// A Bevy plugin that reports the measurements of the [AnimationProfiling] components as Bevy diagnostics.
+///
+/// The diagnostics can be displayed with Bevy's `LogDiagnosticsPlugin`, for instance.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::{diagnostic::LogDiagnosticsPlugin, prelude::*};
+/// # use bevy_spritesheet_animation::prelude::*;
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         SpritesheetAnimationPlugin,
+///         SpritesheetAnimationDiagnosticsPlugin,
+///         LogDiagnosticsPlugin::default(),
+///     ))
+///     .run();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SpritesheetAnimationDiagnosticsPlugin;
+
+impl SpritesheetAnimationDiagnosticsPlugin {
+    /// The total time in milliseconds spent advancing the animations of the profiled entities during the last update
+    pub const FRAME_ADVANCE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("spritesheet_animation/frame_advance_time");
+
+    /// The number of profiled entities
+    pub const PROFILED_ENTITIES: DiagnosticPath =
+        DiagnosticPath::const_new("spritesheet_animation/profiled_entities");
+
+    /// Aggregates the measurements of the profiled entities
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        profiled_entities: Query<&AnimationProfiling>,
+    ) {
+        diagnostics.add_measurement(&Self::FRAME_ADVANCE_TIME, || {
+            profiled_entities
+                .iter()
+                .map(|profiling| profiling.last_frame_advance_time.as_secs_f64() * 1000.0)
+                .sum()
+        });
+
+        diagnostics.add_measurement(&Self::PROFILED_ENTITIES, || {
+            profiled_entities.iter().count() as f64
+        });
+    }
+}
+
+impl Plugin for SpritesheetAnimationDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AnimationProfiling>()
+            .register_diagnostic(
+                Diagnostic::new(Self::FRAME_ADVANCE_TIME)
+                    .with_suffix("ms")
+                    .with_max_history_length(DEFAULT_MAX_HISTORY_LENGTH),
+            )
+            .register_diagnostic(Diagnostic::new(Self::PROFILED_ENTITIES))
+            .add_systems(
+                PostUpdate,
+                Self::diagnostic_system.after(AnimationSystemSet),
+            );
+    }
+}
@@ -0,0 +1,460 @@
+use bevy::platform::collections::HashMap;
+
+use crate::{
+    animation::{Animation, AnimationDirection, AnimationDuration, AnimationRepeat},
+    clip::{Clip, ClipId},
+    easing::Easing,
+};
+
+/// A single difference between two [Animations](Animation).
+///
+/// See [AnimationDiff] for more details.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationChange {
+    /// The duration of the animation changed to this value
+    Duration(Option<AnimationDuration>),
+    /// The repetitions of the animation changed to this value
+    Repetitions(Option<AnimationRepeat>),
+    /// The direction of the animation changed to this value
+    Direction(Option<AnimationDirection>),
+    /// The easing of the animation changed to this value
+    Easing(Option<Easing>),
+    /// The delay of the animation changed to this value
+    Delay(u32),
+    /// The atlas index displayed once the animation is over changed to this value
+    OnCompleteAtlasIndex(Option<usize>),
+    /// The name of the animation changed to this value
+    Name(Option<String>),
+    /// A clip was added at this index
+    ///
+    /// `occurrence` is the number of clips with the same ID before it in the new animation.
+    ClipAdded {
+        index: usize,
+        occurrence: usize,
+        clip: Clip,
+    },
+    /// A clip was removed
+    ///
+    /// `occurrence` is the number of clips with the same ID before it in the old animation.
+    ClipRemoved { clip_id: ClipId, occurrence: usize },
+    /// The content of a clip changed
+    ///
+    /// `occurrence` is the number of clips with the same ID before it.
+    ClipChanged { occurrence: usize, clip: Clip },
+    /// The clips were reordered, the clips are given in their final order as `(ID, occurrence)` pairs
+    ClipsReordered { clips: Vec<(ClipId, usize)> },
+}
+
+/// A description of what changed between two [Animations](Animation).
+///
+/// Clips are matched by [ClipId], so a clip that keeps its ID is considered changed rather than removed and added again.
+/// As an animation may contain several clips with the same ID, for instance when the same clip is [added](crate::prelude::AnimationBuilder::add_clip) twice, those clips are matched in order: the first one with the first one, the second one with the second one, and so on.
+///
+/// Create one with [Animation::diff()] and replay it on an animation with [AnimationDiff::apply()].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(spritesheet: &Spritesheet) {
+/// let before = spritesheet
+///     .create_animation()
+///     .add_row(0)
+///     .build();
+///
+/// let mut after = before.clone();
+/// after.replace_clip(0, ClipBuilder::new(spritesheet).add_row(1).build());
+///
+/// let diff = before.diff(&after);
+///
+/// // Replaying the diff gives the same animation
+///
+/// let mut replayed = before.clone();
+/// diff.apply(&mut replayed);
+///
+/// assert_eq!(replayed, after);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnimationDiff {
+    /// The individual changes
+    pub changes: Vec<AnimationChange>,
+}
+
+impl AnimationDiff {
+    /// Are the two animations identical?
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Replays the changes on an animation.
+    ///
+    /// Applying the diff of `a.diff(&b)` to `a` makes it equal to `b`.
+    ///
+    /// Applying it to another animation applies the same changes on a best-effort basis: changes that refer to clips that are not part of the animation are ignored.
+    pub fn apply(&self, animation: &mut Animation) {
+        // Label the clips so that they can still be found once the clips before them are removed or added
+
+        let mut clips: Vec<_> = clip_keys(&animation.clips)
+            .into_iter()
+            .zip(std::mem::take(&mut animation.clips))
+            .collect();
+
+        // Parameters and clip removals/changes
+
+        for change in &self.changes {
+            match change {
+                AnimationChange::Duration(duration) => animation.duration = *duration,
+                AnimationChange::Repetitions(repetitions) => animation.repetitions = *repetitions,
+                AnimationChange::Direction(direction) => animation.direction = *direction,
+                AnimationChange::Easing(easing) => animation.easing = *easing,
+                AnimationChange::Delay(delay) => animation.delay = *delay,
+                AnimationChange::OnCompleteAtlasIndex(index) => {
+                    animation.on_complete_atlas_index = *index
+                }
+                AnimationChange::Name(name) => animation.debug_name = name.clone(),
+                AnimationChange::ClipRemoved {
+                    clip_id,
+                    occurrence,
+                } => clips.retain(|(key, _)| *key != (*clip_id, *occurrence)),
+                AnimationChange::ClipChanged { occurrence, clip } => {
+                    if let Some((_, existing_clip)) = clips
+                        .iter_mut()
+                        .find(|(key, _)| *key == (clip.id(), *occurrence))
+                    {
+                        *existing_clip = clip.clone();
+                    }
+                }
+                AnimationChange::ClipAdded { .. } | AnimationChange::ClipsReordered { .. } => {}
+            }
+        }
+
+        // Clip additions, in increasing order of index so that each clip lands at its final position
+
+        for change in &self.changes {
+            if let AnimationChange::ClipAdded {
+                index,
+                occurrence,
+                clip,
+            } = change
+            {
+                clips.insert(
+                    (*index).min(clips.len()),
+                    ((clip.id(), *occurrence), clip.clone()),
+                );
+            }
+        }
+
+        // Reorderings
+
+        for change in &self.changes {
+            if let AnimationChange::ClipsReordered { clips: order } = change {
+                clips.sort_by_key(|(key, _)| {
+                    order
+                        .iter()
+                        .position(|ordered_key| ordered_key == key)
+                        .unwrap_or(usize::MAX)
+                });
+            }
+        }
+
+        animation.clips = clips.into_iter().map(|(_, clip)| clip).collect();
+    }
+}
+
+/// A clip identified by its ID and the number of clips with the same ID before it
+type ClipKey = (ClipId, usize);
+
+/// Identifies each clip by its ID and the number of clips with the same ID before it
+fn clip_keys(clips: &[Clip]) -> Vec<ClipKey> {
+    let mut occurrences: HashMap<ClipId, usize> = HashMap::new();
+
+    clips
+        .iter()
+        .map(|clip| {
+            let occurrence = occurrences.entry(clip.id()).or_default();
+            let key = (clip.id(), *occurrence);
+
+            *occurrence += 1;
+
+            key
+        })
+        .collect()
+}
+
+impl Animation {
+    /// Describes what changed between this animation and another one.
+    ///
+    /// See [AnimationDiff] for more details.
+    pub fn diff(&self, other: &Animation) -> AnimationDiff {
+        let mut changes = Vec::new();
+
+        // Parameters
+
+        if self.duration != other.duration {
+            changes.push(AnimationChange::Duration(other.duration));
+        }
+
+        if self.repetitions != other.repetitions {
+            changes.push(AnimationChange::Repetitions(other.repetitions));
+        }
+
+        if self.direction != other.direction {
+            changes.push(AnimationChange::Direction(other.direction));
+        }
+
+        if self.easing != other.easing {
+            changes.push(AnimationChange::Easing(other.easing));
+        }
+
+        if self.delay != other.delay {
+            changes.push(AnimationChange::Delay(other.delay));
+        }
+
+        if self.on_complete_atlas_index != other.on_complete_atlas_index {
+            changes.push(AnimationChange::OnCompleteAtlasIndex(
+                other.on_complete_atlas_index,
+            ));
+        }
+
+        if self.debug_name != other.debug_name {
+            changes.push(AnimationChange::Name(other.debug_name.clone()));
+        }
+
+        // Removed & changed clips
+
+        let self_keys = clip_keys(&self.clips);
+        let other_keys = clip_keys(&other.clips);
+
+        let find_clip = |keys: &[ClipKey], key: ClipKey| -> Option<usize> {
+            keys.iter().position(|other_key| *other_key == key)
+        };
+
+        for (key, clip) in self_keys.iter().zip(&self.clips) {
+            match find_clip(&other_keys, *key) {
+                None => changes.push(AnimationChange::ClipRemoved {
+                    clip_id: key.0,
+                    occurrence: key.1,
+                }),
+                Some(index) if other.clips[index] != *clip => {
+                    changes.push(AnimationChange::ClipChanged {
+                        occurrence: key.1,
+                        clip: other.clips[index].clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        // Added clips
+
+        for (index, (key, clip)) in other_keys.iter().zip(&other.clips).enumerate() {
+            if find_clip(&self_keys, *key).is_none() {
+                changes.push(AnimationChange::ClipAdded {
+                    index,
+                    occurrence: key.1,
+                    clip: clip.clone(),
+                });
+            }
+        }
+
+        // Reordered clips
+
+        let kept_keys = |from: &[ClipKey], to: &[ClipKey]| -> Vec<ClipKey> {
+            from.iter()
+                .filter(|key| find_clip(to, **key).is_some())
+                .copied()
+                .collect()
+        };
+
+        if kept_keys(&self_keys, &other_keys) != kept_keys(&other_keys, &self_keys) {
+            changes.push(AnimationChange::ClipsReordered { clips: other_keys });
+        }
+
+        AnimationDiff { changes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::{prelude::ClipBuilder, spritesheet::Spritesheet};
+
+    fn spritesheet() -> Spritesheet {
+        Spritesheet::new(&Handle::default(), 8, 8)
+    }
+
+    fn check_round_trip(before: &Animation, after: &Animation) -> AnimationDiff {
+        let diff = before.diff(after);
+
+        let mut replayed = before.clone();
+        diff.apply(&mut replayed);
+
+        assert_eq!(&replayed, after);
+
+        diff
+    }
+
+    #[test]
+    fn identical() {
+        let animation = spritesheet().create_animation().add_row(0).build();
+
+        assert!(animation.diff(&animation).is_empty());
+        assert!(animation.diff(&animation.clone()).is_empty());
+    }
+
+    #[test]
+    fn parameters() {
+        let before = spritesheet()
+            .create_animation()
+            .add_row(0)
+            .set_duration(AnimationDuration::PerFrame(100))
+            .build();
+
+        let mut after = before.clone();
+        after.duration = Some(AnimationDuration::PerRepetition(1000));
+        after.repetitions = Some(AnimationRepeat::Times(3));
+        after.delay = 200;
+        after.on_complete_atlas_index = Some(5);
+        after.debug_name = Some("after".to_owned());
+
+        let diff = check_round_trip(&before, &after);
+
+        assert_eq!(
+            diff.changes,
+            vec![
+                AnimationChange::Duration(Some(AnimationDuration::PerRepetition(1000))),
+                AnimationChange::Repetitions(Some(AnimationRepeat::Times(3))),
+                AnimationChange::Delay(200),
+                AnimationChange::OnCompleteAtlasIndex(Some(5)),
+                AnimationChange::Name(Some("after".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn clips() {
+        let before = spritesheet()
+            .create_animation()
+            .add_row(0)
+            .start_clip()
+            .add_row(1)
+            .start_clip()
+            .add_row(2)
+            .build();
+
+        let clip_ids: Vec<_> = before.clips().iter().map(|clip| clip.id()).collect();
+
+        // Change the first clip, remove the second one and add a new one
+
+        let mut after = before.clone();
+
+        after.clips[0].repetitions = Some(5);
+        after.clips.remove(1);
+
+        let new_clip = ClipBuilder::new(&spritesheet()).add_row(3).build();
+        after.clips.insert(1, new_clip.clone());
+
+        let diff = check_round_trip(&before, &after);
+
+        assert_eq!(
+            diff.changes,
+            vec![
+                AnimationChange::ClipChanged {
+                    occurrence: 0,
+                    clip: after.clips[0].clone()
+                },
+                AnimationChange::ClipRemoved {
+                    clip_id: clip_ids[1],
+                    occurrence: 0
+                },
+                AnimationChange::ClipAdded {
+                    index: 1,
+                    occurrence: 0,
+                    clip: new_clip
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reordered_clips() {
+        let before = spritesheet()
+            .create_animation()
+            .add_row(0)
+            .start_clip()
+            .add_row(1)
+            .start_clip()
+            .add_row(2)
+            .build();
+
+        let mut after = before.clone();
+        after.clips.swap(0, 2);
+        after
+            .clips
+            .push(ClipBuilder::new(&spritesheet()).add_row(3).build());
+
+        let diff = check_round_trip(&before, &after);
+
+        assert!(
+            diff.changes
+                .iter()
+                .any(|change| matches!(change, AnimationChange::ClipsReordered { .. }))
+        );
+    }
+
+    #[test]
+    fn duplicated_clips() {
+        let clip = ClipBuilder::new(&spritesheet()).add_row(0).build();
+
+        let before = spritesheet()
+            .create_animation()
+            .add_clip(clip.clone())
+            .add_clip(ClipBuilder::new(&spritesheet()).add_row(1).build())
+            .add_clip(clip.clone())
+            .build();
+
+        assert!(before.diff(&before).is_empty());
+
+        // Change the second copy only
+
+        let mut after = before.clone();
+        after.clips[2].repetitions = Some(2);
+
+        let diff = check_round_trip(&before, &after);
+
+        assert_eq!(
+            diff.changes,
+            vec![AnimationChange::ClipChanged {
+                occurrence: 1,
+                clip: after.clips[2].clone()
+            }]
+        );
+
+        // Remove the first copy and add a third one
+
+        let mut after = before.clone();
+        after.clips[0].repetitions = Some(3);
+        after.clips.remove(2);
+        after.clips.push(clip.clone());
+        after.clips.push(clip.clone());
+
+        check_round_trip(&before, &after);
+
+        // Move the second copy first
+
+        let mut after = before.clone();
+        after.clips[2].repetitions = Some(4);
+        after.clips.rotate_right(1);
+
+        let diff = check_round_trip(&before, &after);
+
+        assert!(
+            diff.changes
+                .iter()
+                .any(|change| matches!(change, AnimationChange::ClipsReordered { .. }))
+        );
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::f32::consts::PI;
+use std::{
+    f32::consts::PI,
+    hash::{Hash, Hasher},
+};
 
 use bevy::prelude::*;
 
@@ -43,7 +46,7 @@ pub enum EasingVariety {
 ///     .build();
 /// # }
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Default, Clone, Copy, Reflect)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[reflect(Debug, Default, PartialEq, Hash)]
 pub enum Easing {
@@ -56,6 +59,75 @@ pub enum Easing {
     Out(EasingVariety),
     /// Fast at the start and at the end of the animation, slows down in the middle.
     InOut(EasingVariety),
+    /// CSS-style cubic Bézier curve.
+    ///
+    /// The values are the coordinates `(x1, y1, x2, y2)` of the two control points P1 and P2, with P0 = (0, 0) and P3 = (1, 1).
+    ///
+    /// The `x` coordinates are clamped to the [0, 1] range so that the curve remains a function of the progress.
+    ///
+    /// For instance, `Easing::Bezier(0.42, 0.0, 0.58, 1.0)` is the equivalent of CSS' `ease-in-out`.
+    Bezier(f32, f32, f32, f32),
+    /// Damped spring released at rest from 0 that moves towards 1.
+    ///
+    /// - `stiffness` sets how fast the spring reaches its target (`1.0` settles right at the end of the animation)
+    /// - `damping` is the damping ratio: `1.0` is a critically-damped spring that never overshoots, lower values oscillate around the target and higher values approach it more slowly
+    ///
+    /// The output is scaled so that the curve ends exactly at 1, and clamped to the [0, 1] range so overshoots hold on the last frame.
+    Spring { stiffness: f32, damping: f32 },
+}
+
+// Manual implementations as f32 does not implement Eq and Hash: the values are compared bit by bit
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Easing::Linear, Easing::Linear) => true,
+            (Easing::In(a), Easing::In(b))
+            | (Easing::Out(a), Easing::Out(b))
+            | (Easing::InOut(a), Easing::InOut(b)) => a == b,
+            (Easing::Bezier(x1, y1, x2, y2), Easing::Bezier(ox1, oy1, ox2, oy2)) => {
+                [x1, y1, x2, y2]
+                    .iter()
+                    .zip([ox1, oy1, ox2, oy2])
+                    .all(|(a, b)| a.to_bits() == b.to_bits())
+            }
+            (
+                Easing::Spring { stiffness, damping },
+                Easing::Spring {
+                    stiffness: other_stiffness,
+                    damping: other_damping,
+                },
+            ) => {
+                stiffness.to_bits() == other_stiffness.to_bits()
+                    && damping.to_bits() == other_damping.to_bits()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Easing {}
+
+impl Hash for Easing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Easing::Linear => {}
+            Easing::In(variety) | Easing::Out(variety) | Easing::InOut(variety) => {
+                variety.hash(state)
+            }
+            Easing::Bezier(x1, y1, x2, y2) => {
+                for value in [x1, y1, x2, y2] {
+                    value.to_bits().hash(state);
+                }
+            }
+            Easing::Spring { stiffness, damping } => {
+                stiffness.to_bits().hash(state);
+                damping.to_bits().hash(state);
+            }
+        }
+    }
 }
 
 impl Easing {
@@ -150,8 +222,118 @@ impl Easing {
                 }
                 EasingVariety::Sin => -(((x * PI).cos() - 1.0) / 2.0),
             },
+            Easing::Bezier(x1, y1, x2, y2) => {
+                let x1 = x1.clamp(0.0, 1.0);
+                let x2 = x2.clamp(0.0, 1.0);
+
+                cubic_bezier(y1, y2, solve_cubic_bezier(x1, x2, x))
+            }
+            Easing::Spring { stiffness, damping } => {
+                // Natural frequency for which a critically-damped spring with a stiffness of 1 is settled at x = 1
+                let frequency = 10.0 * stiffness.max(0.0).sqrt();
+                let damping = damping.max(0.0);
+
+                // Scale the curve so that it ends at 1 (falls back to linear for springs that do not move)
+
+                let end = spring(frequency, damping, 1.0);
+
+                if end.is_finite() && end > f32::EPSILON {
+                    (spring(frequency, damping, x) / end).clamp(0.0, 1.0)
+                } else {
+                    x
+                }
+            }
+        }
+    }
+}
+
+/// Evaluates the position of a damped spring released at 0 with a target of 1 (the unit step response of a damped harmonic oscillator).
+fn spring(frequency: f32, damping: f32, t: f32) -> f32 {
+    const EPSILON: f32 = 1e-4;
+
+    if (damping - 1.0).abs() < EPSILON {
+        // Critically damped
+        1.0 - (1.0 + frequency * t) * (-frequency * t).exp()
+    } else if damping < 1.0 {
+        // Underdamped: oscillates around the target
+        let damped_frequency = frequency * (1.0 - damping * damping).sqrt();
+
+        1.0 - (-damping * frequency * t).exp()
+            * ((damped_frequency * t).cos()
+                + damping / (1.0 - damping * damping).sqrt() * (damped_frequency * t).sin())
+    } else {
+        // Overdamped: sum of two decaying exponentials
+        let root = (damping * damping - 1.0).sqrt();
+        let r1 = -frequency * (damping - root);
+        let r2 = -frequency * (damping + root);
+
+        1.0 - (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+    }
+}
+
+/// Evaluates a 1D cubic Bézier curve with P0 = 0 and P3 = 1.
+fn cubic_bezier(p1: f32, p2: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+
+    3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+}
+
+/// Derivative of [cubic_bezier] with respect to `t`.
+fn cubic_bezier_derivative(p1: f32, p2: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+
+    3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+}
+
+/// Finds the parameter `t` for which the curve's `x` coordinate equals `x`.
+///
+/// Uses a few Newton-Raphson iterations, which converge quickly in most cases, and falls back to bisection for flat sections of the curve.
+fn solve_cubic_bezier(x1: f32, x2: f32, x: f32) -> f32 {
+    const EPSILON: f32 = 1e-6;
+
+    // Newton-Raphson
+
+    let mut t = x;
+
+    for _ in 0..8 {
+        let error = cubic_bezier(x1, x2, t) - x;
+
+        if error.abs() < EPSILON {
+            return t;
         }
+
+        let derivative = cubic_bezier_derivative(x1, x2, t);
+
+        if derivative.abs() < EPSILON {
+            break;
+        }
+
+        t -= error / derivative;
     }
+
+    // Bisection
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    t = x;
+
+    while high - low > EPSILON {
+        let value = cubic_bezier(x1, x2, t);
+
+        if (value - x).abs() < EPSILON {
+            break;
+        }
+
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        t = (low + high) / 2.0;
+    }
+
+    t
 }
 
 #[cfg(test)]
@@ -585,4 +767,170 @@ mod tests {
             ],
         )
     }
+
+    // Bezier
+
+    #[test]
+    fn bezier_linear() {
+        check(
+            Easing::Bezier(0.0, 0.0, 1.0, 1.0),
+            vec![
+                (-99999.0, 0.0),
+                (0.0, 0.0),
+                (0.13, 0.13),
+                (0.5, 0.5),
+                (0.87, 0.87),
+                (1.0, 1.0),
+                (1.87, 1.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn bezier_ease_in_out() {
+        let easing = Easing::Bezier(0.42, 0.0, 0.58, 1.0);
+
+        // Reference values of CSS' ease-in-out
+
+        for (x, y) in [
+            (0.0, 0.0),
+            (0.1, 0.01972),
+            (0.25, 0.12916),
+            (0.4, 0.33188),
+            (0.5, 0.5),
+            (0.6, 0.66812),
+            (0.75, 0.87084),
+            (0.9, 0.98028),
+            (1.0, 1.0),
+        ] {
+            assert_relative_eq!(easing.get(x), y, epsilon = 0.01);
+        }
+    }
+
+    #[test]
+    fn bezier_steep() {
+        // Control points with x = 0 make the curve flat at the start
+
+        check(
+            Easing::Bezier(0.0, 1.0, 0.0, 1.0),
+            vec![(0.0, 0.0), (0.5, 0.99122), (1.0, 1.0)],
+        );
+
+        // Out-of-range x coordinates are clamped
+
+        assert_eq!(
+            Easing::Bezier(-3.0, 0.2, 5.0, 0.8).get(0.3),
+            Easing::Bezier(0.0, 0.2, 1.0, 0.8).get(0.3)
+        );
+    }
+
+    // Spring
+
+    #[test]
+    fn spring_critically_damped() {
+        let easing = Easing::Spring {
+            stiffness: 1.0,
+            damping: 1.0,
+        };
+
+        assert_eq!(easing.get(0.0), 0.0);
+        assert_eq!(easing.get(1.0), 1.0);
+        assert_eq!(easing.get(1.87), 1.0);
+
+        // Smooth ease-out: increasing, faster than linear once released from rest, and no overshoot
+
+        let values: Vec<f32> = (0..=100).map(|i| easing.get(i as f32 / 100.0)).collect();
+
+        for (i, pair) in values.windows(2).enumerate() {
+            assert!(pair[1] >= pair[0], "not increasing at {i}");
+        }
+
+        for (i, value) in values.iter().enumerate() {
+            assert!(*value <= 1.0);
+
+            if i >= 5 {
+                assert!(*value >= i as f32 / 100.0, "slower than linear at {i}");
+            }
+        }
+
+        assert!(easing.get(0.1) > 0.25);
+        assert!(easing.get(0.5) > 0.95);
+    }
+
+    #[test]
+    fn spring_underdamped() {
+        let easing = Easing::Spring {
+            stiffness: 1.0,
+            damping: 0.2,
+        };
+
+        assert_eq!(easing.get(0.0), 0.0);
+        assert_eq!(easing.get(1.0), 1.0);
+
+        // The overshoot is clamped
+
+        let values: Vec<f32> = (0..=100).map(|i| easing.get(i as f32 / 100.0)).collect();
+
+        assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+        assert!(values[..90].contains(&1.0));
+
+        // Then it springs back below the target
+
+        assert!(
+            values[..90]
+                .iter()
+                .skip_while(|value| **value < 1.0)
+                .any(|value| *value < 0.9)
+        );
+    }
+
+    #[test]
+    fn spring_overdamped() {
+        let easing = Easing::Spring {
+            stiffness: 1.0,
+            damping: 3.0,
+        };
+
+        assert_eq!(easing.get(0.0), 0.0);
+        assert_relative_eq!(easing.get(1.0), 1.0);
+
+        // Slower than a critically-damped spring
+
+        let critical = Easing::Spring {
+            stiffness: 1.0,
+            damping: 1.0,
+        };
+
+        assert!(easing.get(0.2) < critical.get(0.2));
+    }
+
+    #[test]
+    fn spring_static() {
+        // No stiffness: linear
+
+        check(
+            Easing::Spring {
+                stiffness: 0.0,
+                damping: 1.0,
+            },
+            vec![(0.0, 0.0), (0.3, 0.3), (1.0, 1.0)],
+        );
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            Easing::Bezier(0.1, 0.2, 0.3, 0.4),
+            Easing::Bezier(0.1, 0.2, 0.3, 0.4)
+        );
+        assert_ne!(
+            Easing::Bezier(0.1, 0.2, 0.3, 0.4),
+            Easing::Bezier(0.1, 0.2, 0.3, 0.5)
+        );
+        assert_ne!(Easing::Linear, Easing::Bezier(0.0, 0.0, 1.0, 1.0));
+        assert_ne!(
+            Easing::In(EasingVariety::Sin),
+            Easing::Out(EasingVariety::Sin)
+        );
+    }
 }
@@ -0,0 +1,60 @@
+use std::{error, fmt};
+
+use crate::clip::ClipId;
+
+/// An error that makes an [Animation](crate::prelude::Animation) invalid.
+///
+/// Returned by [AnimationBuilder::try_build()](crate::prelude::AnimationBuilder::try_build) and [Animation::with_duration_scaled()](crate::prelude::Animation::with_duration_scaled).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationError {
+    /// Some atlas indices of a clip exceed the size of the spritesheet
+    InvalidFrameIndices {
+        /// The ID of the clip containing the indices
+        clip_id: ClipId,
+        /// The out-of-bounds indices
+        indices: Vec<usize>,
+    },
+    /// A clip does not contain any frame
+    EmptyClip {
+        /// The ID of the empty clip
+        clip_id: ClipId,
+    },
+    /// The offset or the start frame of a clip skips all of its frames
+    UnsupportedOffset {
+        /// The ID of the clip
+        clip_id: ClipId,
+        /// The offset or the start frame of the clip
+        offset: usize,
+        /// The number of frames in the clip
+        frame_count: usize,
+    },
+    /// The factor to scale the durations of an animation with is not strictly positive
+    InvalidDurationFactor,
+}
+
+impl fmt::Display for AnimationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFrameIndices { clip_id, indices } => {
+                write!(
+                    f,
+                    "{clip_id:?} contains indices {indices:?} that exceed the spritesheet size"
+                )
+            }
+            Self::EmptyClip { clip_id } => write!(f, "{clip_id:?} does not contain any frame"),
+            Self::UnsupportedOffset {
+                clip_id,
+                offset,
+                frame_count,
+            } => write!(
+                f,
+                "the offset of {clip_id:?} ({offset}) must be less than its frame count ({frame_count})"
+            ),
+            Self::InvalidDurationFactor => {
+                write!(f, "the factor to scale durations with must be positive")
+            }
+        }
+    }
+}
+
+impl error::Error for AnimationError {}
@@ -2,12 +2,15 @@
 use serde::{Deserialize, Serialize};
 use std::{
     fmt,
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::{
+        LazyLock,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
 
 use bevy::prelude::*;
 
-use crate::{animation::Animation, clip::ClipId};
+use crate::{animation::Animation, clip::ClipId, names::NameRegistry};
 
 /// A Bevy event emitted when an animation reaches a point of interest:
 /// - When a clip repetition ends
@@ -141,25 +144,93 @@ pub enum AnimationEvent {
         // TODO add animation_repetition
     },
     /// A repetition of an animation has ended
+    ///
+    /// This is emitted at the end of every repetition, including the last one, in which case it comes along with [AnimationEnd](AnimationEvent::AnimationEnd).
+    /// `animation_repetition` is the index of the repetition that ended.
     AnimationRepetitionEnd {
         entity: Entity,
         animation: Handle<Animation>,
         animation_repetition: usize,
     },
     /// An animation has ended
+    ///
+    /// This is only emitted once, after the last repetition of the animation, so never for animations that loop forever.
     AnimationEnd {
         entity: Entity,
         animation: Handle<Animation>,
     },
 }
 
+/// A Bevy event emitted when the clip being played changes.
+///
+/// This is emitted when playback moves from one clip of an animation to another, including when a looping animation jumps back from its last clip to its first one.
+///
+/// Repeating the same clip does not emit this event (see [ClipRepetitionEnd](AnimationEvent::ClipRepetitionEnd) instead).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn swap_shadows(mut messages: MessageReader<ClipChangedEvent>) {
+///     for message in messages.read() {
+///         // The clips are identified by their index in the animation
+///         if message.to_clip == 1 {
+///             // ... omitted: update the shadow of message.entity
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClipChangedEvent {
+    pub entity: Entity,
+    /// The index of the previous clip in the animation
+    pub from_clip: usize,
+    /// The index of the new clip in the animation
+    pub to_clip: usize,
+    pub animation: Handle<Animation>,
+}
+
+/// A Bevy event emitted every time a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) plays a new frame.
+///
+/// This is useful for effects that must follow the animation closely, like footstep sounds or particles, without having to place [markers](Marker) on every frame.
+///
+/// To avoid needless overhead, this event is only emitted for the entities that opt in with [SpritesheetAnimation::with_frame_changed_events()](crate::prelude::SpritesheetAnimation::with_frame_changed_events).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn spawn_dust(mut messages: MessageReader<FrameChangedEvent>) {
+///     for message in messages.read() {
+///         if message.clip_index == 0 {
+///             // ... omitted: spawn some dust particles near message.entity
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameChangedEvent {
+    pub entity: Entity,
+    /// The index of the clip that contains the frame
+    pub clip_index: usize,
+    /// The index of the frame in the animation
+    ///
+    /// See [AnimationProgress::frame](crate::prelude::AnimationProgress::frame).
+    pub frame_index: usize,
+    /// The atlas index of the frame, before any [FrameRemapper](crate::prelude::FrameRemapper) is applied
+    pub atlas_index: usize,
+}
+
 /// A marker that designates a point of interest in an animation.
 ///
 /// [MarkerHit](AnimationEvent::MarkerHit) events containing this marker are emitted when the corresponding frame is played.
 ///
 /// Add markers to a clip with [AnimationBuilder::add_clip_marker()](crate::prelude::AnimationBuilder::add_clip_marker).
+///
+/// Markers created with [Marker::named()] are identified by their name, which is also how they are serialized.
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Reflect)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 #[reflect(Debug, PartialEq, Hash)]
 pub struct Marker {
     pub(crate) value: usize,
@@ -167,6 +238,8 @@ pub struct Marker {
 
 static NEXT_MARKER: AtomicUsize = AtomicUsize::new(0);
 
+static MARKER_NAMES: LazyLock<NameRegistry> = LazyLock::new(default);
+
 impl Marker {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
@@ -174,10 +247,229 @@ impl Marker {
             value: NEXT_MARKER.fetch_add(1, Ordering::Relaxed),
         }
     }
+
+    /// Returns the marker associated to a name.
+    ///
+    /// The same name always gives the same marker so that markers can be shared without keeping them around, for instance between animation files and the systems that react to them.
+    ///
+    /// Named markers are serialized as their names, which stay the same across runs.
+    /// Their underlying values are allocated on first use like the ones of [Marker::new()], so they may differ from one run to another.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn play_footsteps(mut messages: MessageReader<AnimationEvent>) {
+    ///     for message in messages.read() {
+    ///         if let AnimationEvent::MarkerHit { marker, .. } = message
+    ///             && *marker == Marker::named("footstep")
+    ///         {
+    ///             // ... omitted: play a sound
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn named(name: &str) -> Self {
+        Marker {
+            value: MARKER_NAMES.value(name, || Self::new().value),
+        }
+    }
+
+    /// The name of this marker if it was created with [Marker::named()]
+    pub fn display_name(&self) -> Option<&'static str> {
+        MARKER_NAMES.name(self.value)
+    }
+
+    /// Returns the group of the named markers that belong to a family.
+    ///
+    /// A family contains the marker named `name` and the markers whose names start with `name/`.
+    /// For instance, the `footstep` family contains `footstep`, `footstep/left` and `footstep/knight/right`.
+    ///
+    /// Markers are matched by name, so the group also matches the markers named after it is created, for instance in animations loaded later on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn f(spritesheet: &Spritesheet) {
+    /// let footsteps = Marker::group("footstep");
+    ///
+    /// let animation = spritesheet
+    ///     .create_animation()
+    ///     .add_row(0)
+    ///     .add_clip_marker(Marker::named("footstep/left"), 1)
+    ///     .add_clip_marker(Marker::named("footstep/right"), 5)
+    ///     .build();
+    ///
+    /// assert!(footsteps.matches(&Marker::named("footstep/left")));
+    /// assert!(!footsteps.matches(&Marker::named("jump")));
+    /// # }
+    /// ```
+    pub fn group(name: &str) -> MarkerGroup {
+        MarkerGroup::Family(name.to_owned())
+    }
+}
+
+/// A set of related [markers](Marker) that can be listened to collectively.
+///
+/// Create one from a family of named markers with [Marker::group()] or from arbitrary markers.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Resource)]
+/// struct Footsteps(MarkerGroup);
+///
+/// fn play_footsteps(mut messages: MessageReader<AnimationEvent>, footsteps: Res<Footsteps>) {
+///     for message in messages.read() {
+///         if let AnimationEvent::MarkerHit { marker, .. } = message
+///             && footsteps.0.matches(marker)
+///         {
+///             // ... omitted: play a sound
+///         }
+///     }
+/// }
+///
+/// # let left_foot = Marker::new();
+/// # let right_foot = Marker::new();
+/// let footsteps = Footsteps(MarkerGroup::Markers(vec![left_foot, right_foot]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum MarkerGroup {
+    /// Some specific markers
+    Markers(Vec<Marker>),
+    /// The named markers of a family, see [Marker::group()]
+    Family(String),
+}
+
+impl Default for MarkerGroup {
+    fn default() -> Self {
+        MarkerGroup::Markers(Vec::new())
+    }
+}
+
+impl MarkerGroup {
+    /// Does this group contain a marker?
+    ///
+    /// This is typically used on the marker of a [MarkerHit](AnimationEvent::MarkerHit) event.
+    pub fn matches(&self, event_marker: &Marker) -> bool {
+        match self {
+            MarkerGroup::Markers(markers) => markers.contains(event_marker),
+            MarkerGroup::Family(family) => event_marker.display_name().is_some_and(|name| {
+                name.strip_prefix(family.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            }),
+        }
+    }
 }
 
 impl fmt::Debug for Marker {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "marker{}", self.value)
+        match self.display_name() {
+            Some(name) => write!(f, "marker{}({name})", self.value),
+            None => write!(f, "marker{}", self.value),
+        }
+    }
+}
+
+// Named markers are serialized as their name, the others as their value
+//
+// Human-readable formats use the bare name or value, which reads the plain values serialized by 5.1.0.
+// Other formats use an explicitly tagged representation so that non-self-describing formats are supported.
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+enum SerializedMarker {
+    Named(String),
+    Anonymous(usize),
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Marker {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.display_name(), serializer.is_human_readable()) {
+            (Some(name), true) => serializer.serialize_str(name),
+            (None, true) => self.value.serialize(serializer),
+            (Some(name), false) => SerializedMarker::Named(name.to_owned()).serialize(serializer),
+            (None, false) => SerializedMarker::Anonymous(self.value).serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Marker {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MarkerVisitor;
+
+        impl serde::de::Visitor<'_> for MarkerVisitor {
+            type Value = Marker;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a marker name or value")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Marker, E> {
+                Ok(Marker::named(name))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Marker, E> {
+                usize::try_from(value)
+                    .map(|value| Marker { value })
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Marker, E> {
+                usize::try_from(value)
+                    .map(|value| Marker { value })
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(MarkerVisitor)
+        } else {
+            Ok(match SerializedMarker::deserialize(deserializer)? {
+                SerializedMarker::Named(name) => Marker::named(&name),
+                SerializedMarker::Anonymous(value) => Marker { value },
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_markers() {
+        let named_marker = Marker::named("footstep");
+        let anonymous_marker = Marker::new();
+
+        let serialized = ron::to_string(&[named_marker, anonymous_marker]).unwrap();
+
+        assert_eq!(
+            serialized,
+            format!("(\"footstep\",{})", anonymous_marker.value)
+        );
+
+        let deserialized: [Marker; 2] = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, [named_marker, anonymous_marker]);
+    }
+
+    #[test]
+    fn deserialize_plain_markers() {
+        // Markers serialized as plain values by previous versions
+
+        let deserialized: Marker = ron::from_str("3").unwrap();
+
+        assert_eq!(deserialized, Marker { value: 3 });
+
+        assert!(ron::from_str::<Marker>("-3").is_err());
     }
 }
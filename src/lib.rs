@@ -24,33 +24,56 @@
 pub mod animation;
 pub mod builder;
 pub mod clip;
+pub mod commands;
 pub mod components;
+pub mod consts;
+pub mod diagnostics;
+pub mod diff;
 pub mod easing;
+pub mod error;
 pub mod events;
 pub mod plugin;
+pub mod query;
 pub mod spritesheet;
 
 pub mod prelude {
     pub use crate::{
-        animation::{Animation, AnimationDirection, AnimationDuration, AnimationRepeat},
-        builder::AnimationBuilder,
+        animation::{
+            Animation, AnimationContext, AnimationDirection, AnimationDuration, AnimationRepeat,
+        },
+        builder::{AnimationBuilder, ClipBuilder, ClipOverride},
         clip::{Clip, ClipId},
+        commands::SpritesheetAnimationCommands,
         components::{
+            frame_remapper::FrameRemapper,
             generator::ComponentGenerator,
+            profiling::AnimationProfiling,
+            recorder::{AnimationRecorder, RecordedAnimation, RecordedFrame},
             spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
         },
+        diagnostics::SpritesheetAnimationDiagnosticsPlugin,
+        diff::{AnimationChange, AnimationDiff},
         easing::{Easing, EasingVariety},
+        error::AnimationError,
         events::AnimationEvent,
+        events::ClipChangedEvent,
+        events::FrameChangedEvent,
         events::Marker,
-        plugin::SpritesheetAnimationPlugin,
+        events::MarkerGroup,
+        plugin::{
+            AnimationSystemOrder, AnimationSystemSet, SpritesheetAnimationPlugin,
+            SpritesheetAnimationPluginBuilder,
+        },
+        query::{AnimationQuery, AnimationView, AnimationViews},
         spritesheet::Spritesheet,
     };
 
     #[cfg(feature = "3d")]
-    pub use crate::components::sprite3d::Sprite3d;
+    pub use crate::{components::sprite3d::Sprite3d, plugin::Sprite3dSystemSet};
 }
 
 mod animator;
+mod names;
 mod systems;
 
 const CRATE_NAME: &str = "bevy_spritesheet_animation";
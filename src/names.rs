@@ -0,0 +1,48 @@
+use std::sync::RwLock;
+
+use bevy::platform::collections::HashMap;
+
+/// A registry of interned names, each one associated to a unique value
+///
+/// This is used for named [Markers](crate::prelude::Marker) so that they can stay [Copy].
+#[derive(Default)]
+pub(crate) struct NameRegistry {
+    names: RwLock<Names>,
+}
+
+#[derive(Default)]
+struct Names {
+    values: HashMap<&'static str, usize>,
+    names: HashMap<usize, &'static str>,
+}
+
+impl NameRegistry {
+    /// Returns the value associated to a name, registering the name with a new value if needed
+    pub fn value(&self, name: &str, new_value: impl FnOnce() -> usize) -> usize {
+        if let Some(value) = self.names.read().unwrap().values.get(name) {
+            return *value;
+        }
+
+        let mut names = self.names.write().unwrap();
+
+        // The name may have been registered in the meantime
+
+        if let Some(value) = names.values.get(name) {
+            return *value;
+        }
+
+        let value = new_value();
+
+        let name: &'static str = Box::leak(name.into());
+
+        names.values.insert(name, value);
+        names.names.insert(value, name);
+
+        value
+    }
+
+    /// Returns the name associated to a value, if any
+    pub fn name(&self, value: usize) -> Option<&'static str> {
+        self.names.read().unwrap().names.get(&value).copied()
+    }
+}
@@ -1,8 +1,18 @@
-use bevy::prelude::*;
+use std::time::Duration;
+
+use bevy::{asset::AssetEventSystems, prelude::*, transform::TransformSystems};
 
 use crate::{
-    animation::Animation, animator::Animator,
-    components::spritesheet_animation::SpritesheetAnimation, events::AnimationEvent,
+    CRATE_NAME,
+    animation::{Animation, AnimationDirection, AnimationDuration, AnimationRepeat},
+    animator::Animator,
+    clip::{Clip, ClipId},
+    components::{
+        profiling::AnimationProfiling, recorder::AnimationRecorder,
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    easing::{Easing, EasingVariety},
+    events::{AnimationEvent, ClipChangedEvent, FrameChangedEvent, Marker},
     systems::spritesheet_animation,
 };
 
@@ -12,9 +22,51 @@ use crate::components::sprite3d::Sprite3d;
 use crate::systems::sprite3d;
 
 /// Set for systems that update animations
+///
+/// The animation systems run in [PostUpdate], or in the schedule selected with [SpritesheetAnimationPlugin::with_system_order()] for the playback.
+///
+/// Order your own systems relative to this set to run custom logic right before or after the animations are updated, without depending on the plugin's internal systems.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn sync_animations_from_network(mut animations: Query<&mut SpritesheetAnimation>) {
+///     // ...
+/// }
+///
+/// fn create_app() {
+///     let app = App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(SpritesheetAnimationPlugin)
+///         .add_systems(
+///             PostUpdate,
+///             sync_animations_from_network.before(AnimationSystemSet),
+///         );
+///
+///     // ...
+/// }
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub struct AnimationSystemSet;
 
+/// When the animations are played within an app update, relative to physics and transforms
+///
+/// Physics engines typically step in the fixed-timestep schedules, which Bevy runs between [PreUpdate] and [Update].
+///
+/// Configure it with [SpritesheetAnimationPlugin::with_system_order()].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationSystemOrder {
+    /// Plays the animations in [PreUpdate], before the physics and the game logic
+    BeforePhysics,
+    /// Plays the animations in [PostUpdate], before the transforms are propagated
+    AfterPhysics,
+    /// Plays the animations in [PostUpdate], after the transforms are propagated (default)
+    #[default]
+    AfterTransform,
+}
+
 /// Set for systems that manage 3D sprites
 #[cfg(feature = "3d")]
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
@@ -24,6 +76,9 @@ pub struct Sprite3dSystemSet;
 ///
 /// This plugin injects the systems required for running animations and inserts the `Assets<Animation>` resource through which you can create new animations.
 ///
+/// The animations follow Bevy's virtual clock, [`Time<Virtual>`](Virtual), like the other game systems.
+/// As such, [pausing](Time::<Virtual>::pause) the virtual time pauses the animations and [changing its relative speed](Time::<Virtual>::set_relative_speed) makes them play faster or slower.
+///
 /// # Examples
 ///
 /// ```
@@ -53,26 +108,225 @@ pub struct Sprite3dSystemSet;
 ///     // ...
 /// }
 /// ```
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct SpritesheetAnimationPlugin;
 
+impl SpritesheetAnimationPlugin {
+    /// Plays the animations on a fixed timestep instead of once per app update.
+    ///
+    /// See [SpritesheetAnimationPluginBuilder::with_fixed_timestep()].
+    pub fn with_fixed_timestep(self, hz: f64) -> SpritesheetAnimationPluginBuilder {
+        SpritesheetAnimationPluginBuilder::default().with_fixed_timestep(hz)
+    }
+
+    /// Sets when the animations are played within an app update.
+    ///
+    /// See [SpritesheetAnimationPluginBuilder::with_system_order()].
+    pub fn with_system_order(
+        self,
+        order: AnimationSystemOrder,
+    ) -> SpritesheetAnimationPluginBuilder {
+        SpritesheetAnimationPluginBuilder::default().with_system_order(order)
+    }
+
+    /// Disables the [MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events for all the animations.
+    ///
+    /// See [SpritesheetAnimationPluginBuilder::disable_marker_events()].
+    pub fn disable_marker_events(self) -> SpritesheetAnimationPluginBuilder {
+        SpritesheetAnimationPluginBuilder::default().disable_marker_events()
+    }
+}
+
 impl Plugin for SpritesheetAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        SpritesheetAnimationPluginBuilder::default().build(app);
+    }
+}
+
+/// A configured [SpritesheetAnimationPlugin], to add to Bevy apps in its place.
+///
+/// Create one with the configuration methods of [SpritesheetAnimationPlugin].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn create_app() {
+///     let app = App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(
+///             SpritesheetAnimationPlugin
+///                 .with_fixed_timestep(30.0)
+///                 .disable_marker_events(),
+///         );
+///
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SpritesheetAnimationPluginBuilder {
+    fixed_timestep: Option<Duration>,
+    marker_events_disabled: bool,
+    system_order: AnimationSystemOrder,
+}
+
+impl SpritesheetAnimationPluginBuilder {
+    /// Plays the animations on a fixed timestep instead of once per app update.
+    ///
+    /// The animations are then only advanced by whole timesteps, the time left being accumulated for the next app updates.
+    /// This is useful for retro aesthetics, for instance to animate sprites at 30hz in a game rendered at 144hz.
+    ///
+    /// The animations keep being played in the schedule selected with [with_system_order()](SpritesheetAnimationPluginBuilder::with_system_order).
+    /// Bevy's [`Time<Fixed>`](Fixed) clock is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// - `hz` - the number of animation updates per second
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hz` is not a positive frequency, like [`Time::<Fixed>::from_hz()`](Time::<Fixed>::from_hz).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn create_app() {
+    ///     let app = App::new()
+    ///         .add_plugins(DefaultPlugins)
+    ///         .add_plugins(SpritesheetAnimationPlugin.with_fixed_timestep(30.0));
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    pub fn with_fixed_timestep(mut self, hz: f64) -> Self {
+        let timestep = Duration::try_from_secs_f64(1.0 / hz)
+            .ok()
+            .filter(|timestep| !timestep.is_zero());
+
+        assert!(
+            timestep.is_some(),
+            "{CRATE_NAME}: invalid fixed timestep frequency {hz}, it must be positive and finite"
+        );
+
+        self.fixed_timestep = timestep;
+        self
+    }
+
+    /// Sets when the animations are played within an app update (default = [AnimationSystemOrder::AfterTransform]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn create_app() {
+    ///     let app = App::new()
+    ///         .add_plugins(DefaultPlugins)
+    ///         .add_plugins(
+    ///             SpritesheetAnimationPlugin.with_system_order(AnimationSystemOrder::BeforePhysics),
+    ///         );
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    pub fn with_system_order(mut self, order: AnimationSystemOrder) -> Self {
+        self.system_order = order;
+        self
+    }
+
+    /// Disables the [MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events for all the animations.
+    ///
+    /// The markers are then removed from the animations when they start playing so that they have no overhead.
+    /// This is useful in scenes with many animated sprites when nothing reads the marker events.
+    ///
+    /// The other [AnimationEvents](crate::prelude::AnimationEvent) are still emitted.
+    /// A warning is logged when an animation that contains markers is played.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn create_app() {
+    ///     let app = App::new()
+    ///         .add_plugins(DefaultPlugins)
+    ///         .add_plugins(SpritesheetAnimationPlugin.disable_marker_events());
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    pub fn disable_marker_events(mut self) -> Self {
+        self.marker_events_disabled = true;
+        self
+    }
+}
+
+impl Plugin for SpritesheetAnimationPluginBuilder {
     fn build(&self, app: &mut App) {
         app
             // Register animation assets
-            // TODO other types?
             .init_asset::<Animation>()
             .register_type::<Animation>()
+            .register_type::<AnimationDuration>()
+            .register_type::<AnimationRepeat>()
+            .register_type::<AnimationDirection>()
+            .register_type::<Clip>()
+            .register_type::<ClipId>()
+            .register_type::<Marker>()
+            .register_type::<Easing>()
+            .register_type::<EasingVariety>()
             .register_type::<SpritesheetAnimation>()
+            .register_type::<AnimationRecorder>()
+            .register_type::<AnimationProfiling>()
             // Main animation system
-            .init_resource::<Animator>()
+            .insert_resource(Animator::new(
+                self.marker_events_disabled,
+                self.fixed_timestep,
+            ))
             .register_type::<Animator>()
             .add_systems(
                 PostUpdate,
-                spritesheet_animation::play_animations.in_set(AnimationSystemSet),
+                spritesheet_animation::invalidate_animations
+                    .in_set(AnimationSystemSet)
+                    // Apply the changes to the animation assets on the same frame
+                    .after(AssetEventSystems),
             )
             // Animations events
-            .add_message::<AnimationEvent>();
+            .add_message::<AnimationEvent>()
+            .add_message::<ClipChangedEvent>()
+            .add_message::<FrameChangedEvent>();
+
+        // Play the animations
+
+        match self.system_order {
+            AnimationSystemOrder::BeforePhysics => {
+                app.add_systems(
+                    PreUpdate,
+                    spritesheet_animation::play_animations.in_set(AnimationSystemSet),
+                );
+            }
+            AnimationSystemOrder::AfterPhysics => {
+                app.add_systems(
+                    PostUpdate,
+                    spritesheet_animation::play_animations
+                        .in_set(AnimationSystemSet)
+                        .after(spritesheet_animation::invalidate_animations)
+                        .before(TransformSystems::Propagate),
+                );
+            }
+            AnimationSystemOrder::AfterTransform => {
+                app.add_systems(
+                    PostUpdate,
+                    spritesheet_animation::play_animations
+                        .in_set(AnimationSystemSet)
+                        .after(spritesheet_animation::invalidate_animations)
+                        .after(TransformSystems::Propagate),
+                );
+            }
+        }
 
         #[cfg(feature = "3d")]
         app
@@ -0,0 +1,168 @@
+use bevy::{
+    ecs::{query::QueryData, system::SystemParam},
+    prelude::*,
+};
+
+#[cfg(feature = "3d")]
+use crate::components::sprite3d::Sprite3d;
+use crate::{
+    animation::Animation,
+    clip::{Clip, ClipId},
+    components::spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
+};
+
+/// A read-only query data type that bundles the animation state of an entity.
+///
+/// This spares you from listing the animation-related components in your own queries.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn log_animations(animations: Query<AnimationQuery>) {
+///     for animation in &animations {
+///         info!(
+///             "{}: frame {:?} of {:?}",
+///             animation.entity,
+///             animation.atlas_index(),
+///             animation.active_clip_id()
+///         );
+///     }
+/// }
+/// ```
+#[derive(QueryData)]
+#[query_data(derive(Debug))]
+pub struct AnimationQuery {
+    /// The animated entity
+    pub entity: Entity,
+    /// The animation component of the entity
+    pub animation: &'static SpritesheetAnimation,
+    /// The sprite of the entity, if any
+    pub sprite: Option<&'static Sprite>,
+    /// The 3D sprite of the entity, if any
+    #[cfg(feature = "3d")]
+    pub sprite3d: Option<&'static Sprite3d>,
+    /// The UI image of the entity, if any
+    pub image_node: Option<&'static ImageNode>,
+}
+
+impl AnimationQueryItem<'_, '_> {
+    /// The progress of the animation.
+    ///
+    /// See [SpritesheetAnimation::progress].
+    pub fn progress(&self) -> AnimationProgress {
+        self.animation.progress
+    }
+
+    /// Is the animation currently playing?
+    ///
+    /// See [SpritesheetAnimation::playing].
+    pub fn is_playing(&self) -> bool {
+        self.animation.playing
+    }
+
+    /// The ID of the clip that contains the current frame.
+    ///
+    /// See [SpritesheetAnimation::active_clip_id()].
+    pub fn active_clip_id(&self) -> Option<ClipId> {
+        self.animation.active_clip_id()
+    }
+
+    /// The index of the clip that contains the current frame.
+    ///
+    /// See [SpritesheetAnimation::active_clip_index()].
+    pub fn active_clip_index(&self) -> Option<usize> {
+        self.animation.active_clip_index()
+    }
+
+    /// The atlas index currently displayed by the entity, whatever the kind of sprite that is animated.
+    pub fn atlas_index(&self) -> Option<usize> {
+        let sprite_atlas = self.sprite.and_then(|sprite| sprite.texture_atlas.as_ref());
+
+        #[cfg(feature = "3d")]
+        let sprite_atlas = sprite_atlas.or_else(|| {
+            self.sprite3d
+                .and_then(|sprite| sprite.texture_atlas.as_ref())
+        });
+
+        sprite_atlas
+            .or_else(|| {
+                self.image_node
+                    .and_then(|image| image.texture_atlas.as_ref())
+            })
+            .map(|atlas| atlas.index)
+    }
+}
+
+/// A system parameter that combines the [AnimationQuery] of the animated entities with their [Animation] assets.
+///
+/// This spares you from querying the entities and looking up their animations separately.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Resource)]
+/// struct Player(Entity);
+///
+/// fn log_player_animation(views: AnimationViews, player: Res<Player>) {
+///     if let Some(view) = views.get(player.0) {
+///         info!(
+///             "{:?}: clip {:?}",
+///             view.animation.name(),
+///             view.active_clip().map(|clip| clip.id())
+///         );
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct AnimationViews<'w, 's> {
+    query: Query<'w, 's, AnimationQuery>,
+    animations: Res<'w, Assets<Animation>>,
+}
+
+impl<'w, 's> AnimationViews<'w, 's> {
+    /// The animation state of an entity along with its animation.
+    ///
+    /// Returns `None` if the entity has no [SpritesheetAnimation] component or if its animation does not exist.
+    pub fn get(&self, entity: Entity) -> Option<AnimationView<'_, 's>> {
+        let state = self.query.get(entity).ok()?;
+
+        self.view(state)
+    }
+
+    /// Iterates over the animation states of all the animated entities along with their animations.
+    ///
+    /// Entities whose animations do not exist are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = AnimationView<'_, 's>> {
+        self.query.iter().filter_map(|state| self.view(state))
+    }
+
+    fn view<'a>(&'a self, state: AnimationQueryItem<'a, 's>) -> Option<AnimationView<'a, 's>> {
+        let animation = self.animations.get(&state.animation.animation)?;
+
+        Some(AnimationView { state, animation })
+    }
+}
+
+/// A read-only view on the animation of an entity, returned by [AnimationViews].
+#[derive(Debug)]
+pub struct AnimationView<'w, 's> {
+    /// The animation state of the entity
+    pub state: AnimationQueryItem<'w, 's>,
+    /// The animation played by the entity
+    pub animation: &'w Animation,
+}
+
+impl<'w> AnimationView<'w, '_> {
+    /// The clip that contains the current frame.
+    ///
+    /// See [SpritesheetAnimation::active_clip_index()].
+    pub fn active_clip(&self) -> Option<&'w Clip> {
+        let clip_index = self.state.active_clip_index()?;
+
+        self.animation.clips().get(clip_index)
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{builder::AnimationBuilder, components::generator::ComponentGenerator};
+use crate::{CRATE_NAME, builder::AnimationBuilder, components::generator::ComponentGenerator};
 
 /// A spritesheet image that is split into cells, each one representing an animation frame.
 ///
@@ -61,7 +61,9 @@ use crate::{builder::AnimationBuilder, components::generator::ComponentGenerator
 ///     ));
 /// }
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Two spritesheets are equal if they share the same image and cover the same cells of its grid, so they can be used as keys to deduplicate animations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Spritesheet {
     /// The spritesheet image
     image: Handle<Image>,
@@ -71,6 +73,12 @@ pub struct Spritesheet {
 
     /// The number of rows in the spritesheet
     rows: usize,
+
+    /// The position and size of the spritesheet in the grid of its image, which differ for [sub-grids](Spritesheet::sub_grid)
+    row_offset: usize,
+    column_offset: usize,
+    image_columns: usize,
+    image_rows: usize,
 }
 
 impl Spritesheet {
@@ -86,6 +94,71 @@ impl Spritesheet {
             image: image.clone(),
             columns,
             rows,
+            row_offset: 0,
+            column_offset: 0,
+            image_columns: columns,
+            image_rows: rows,
+        }
+    }
+
+    /// Creates a spritesheet that covers a rectangular part of this spritesheet.
+    ///
+    /// This is useful when a single image contains the spritesheets of several characters.
+    ///
+    /// The sub-grid shares the image and the texture atlas layout of this spritesheet but its frames are indexed relative to its top-left corner.
+    /// The animations created from it still contain the atlas indices of the whole image.
+    ///
+    /// If the sub-grid exceeds this spritesheet, it is truncated to fit.
+    ///
+    /// # Arguments
+    ///
+    /// - `row_offset` - the row of the top-left corner of the sub-grid
+    /// - `col_offset` - the column of the top-left corner of the sub-grid
+    /// - `rows` - the number of rows in the sub-grid
+    /// - `cols` - the number of columns in the sub-grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───────────┐
+    /// // │0 1 2 3 4 5│
+    /// // │6 7 8 9 ...│
+    /// // └───────────┘
+    ///
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 6, 2);
+    ///
+    /// // The second character occupies the last 3 columns
+    ///
+    /// let character = spritesheet.sub_grid(0, 3, 2, 3);
+    ///
+    /// let animation = character.create_animation().add_row(1).build();
+    ///
+    /// let clip = animation.clips().first().unwrap();
+    ///
+    /// assert_eq!(clip.atlas_indices(), vec![9, 10, 11]);
+    /// ```
+    pub fn sub_grid(&self, row_offset: usize, col_offset: usize, rows: usize, cols: usize) -> Self {
+        let available_rows = self.rows.saturating_sub(row_offset);
+        let available_columns = self.columns.saturating_sub(col_offset);
+
+        if rows > available_rows || cols > available_columns {
+            error!(
+                "{CRATE_NAME}: sub-grid ({rows} rows, {cols} columns at row {row_offset}, column {col_offset}) exceeds the spritesheet size ({}, {})",
+                self.columns, self.rows
+            );
+        }
+
+        Self {
+            image: self.image.clone(),
+            columns: cols.min(available_columns),
+            rows: rows.min(available_rows),
+            row_offset: self.row_offset + row_offset.min(self.rows),
+            column_offset: self.column_offset + col_offset.min(self.columns),
+            image_columns: self.image_columns,
+            image_rows: self.image_rows,
         }
     }
 
@@ -101,6 +174,169 @@ impl Spritesheet {
         self.rows
     }
 
+    /// The dimensions of the spritesheet's grid as `(columns, rows)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 8, 4);
+    ///
+    /// let (columns, rows) = spritesheet.grid_dimensions();
+    ///
+    /// assert_eq!(columns * rows, 32);
+    /// ```
+    pub fn grid_dimensions(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// The total number of frames in the spritesheet, that is `columns * rows`.
+    ///
+    /// Valid atlas indices range from 0 to this count, excluded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 8, 4);
+    ///
+    /// assert_eq!(spritesheet.total_frame_count(), 32);
+    /// ```
+    pub fn total_frame_count(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// The dimensions of the grid of the whole image as `(columns, rows)`, which differ from [Spritesheet::grid_dimensions()] for sub-grids
+    pub(crate) fn image_grid_dimensions(&self) -> (usize, usize) {
+        (self.image_columns, self.image_rows)
+    }
+
+    /// Converts an index relative to this spritesheet to an index in the grid of the whole image
+    pub(crate) fn atlas_index(&self, index: usize) -> usize {
+        let row = self.row_offset + index / self.columns.max(1);
+        let column = self.column_offset + index % self.columns.max(1);
+
+        row * self.image_columns + column
+    }
+
+    /// Is an index in the grid of the whole image part of this spritesheet?
+    pub(crate) fn contains_atlas_index(&self, atlas_index: usize) -> bool {
+        let row = atlas_index / self.image_columns.max(1);
+        let column = atlas_index % self.image_columns.max(1);
+
+        (self.row_offset..self.row_offset + self.rows).contains(&row)
+            && (self.column_offset..self.column_offset + self.columns).contains(&column)
+    }
+
+    /// The column relative to this spritesheet of an index in the grid of the whole image
+    pub(crate) fn column_of(&self, atlas_index: usize) -> usize {
+        (atlas_index % self.image_columns.max(1)).saturating_sub(self.column_offset)
+    }
+
+    /// The pixel rectangle of a frame of the spritesheet, given the size of its image.
+    ///
+    /// This is typically useful to compute hit-boxes or draw debug gizmos.
+    ///
+    /// The size of the image is required as spritesheets only describe the layout of the grid and the image might not be loaded yet.
+    /// The frames are assumed to evenly divide the image, like in the layouts created by a [ComponentGenerator].
+    ///
+    /// Returns `None` if the index is out of bounds.
+    ///
+    /// For [sub-grids](Spritesheet::sub_grid), the index is relative to the sub-grid.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - the index of the frame
+    /// - `image_size` - the size of the image in pixels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 4, 2);
+    ///
+    /// assert_eq!(
+    ///     spritesheet.frame_rect(5, UVec2::new(128, 64)),
+    ///     Some(URect::new(32, 32, 64, 64))
+    /// );
+    /// ```
+    pub fn frame_rect(&self, index: usize, image_size: UVec2) -> Option<URect> {
+        if index >= self.total_frame_count() {
+            return None;
+        }
+
+        let atlas_index = self.atlas_index(index);
+
+        let cell_size = UVec2::new(
+            image_size.x / self.image_columns.max(1) as u32,
+            image_size.y / self.image_rows.max(1) as u32,
+        );
+
+        let min = UVec2::new(
+            (atlas_index % self.image_columns.max(1)) as u32,
+            (atlas_index / self.image_columns.max(1)) as u32,
+        ) * cell_size;
+
+        Some(URect::from_corners(min, min + cell_size))
+    }
+
+    /// The pixel rectangle of a frame of the spritesheet in a texture atlas layout.
+    ///
+    /// This is the same as [frame_rect()](Spritesheet::frame_rect) except that the rectangle is read from a layout, such as the one created by a [ComponentGenerator].
+    ///
+    /// Returns `None` if the index is out of bounds of the spritesheet or of the layout.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - the index of the frame
+    /// - `layout` - the texture atlas layout of the spritesheet's image
+    pub fn frame_rect_in_layout(&self, index: usize, layout: &TextureAtlasLayout) -> Option<URect> {
+        if index >= self.total_frame_count() {
+            return None;
+        }
+
+        layout.textures.get(self.atlas_index(index)).copied()
+    }
+
+    /// Checks that some atlas indices are within the bounds of the spritesheet.
+    ///
+    /// This can be used as a pre-flight check before building an animation from indices coming from external data.
+    ///
+    /// Returns the out-of-bounds indices as an error, in the order they were given.
+    ///
+    /// For [sub-grids](Spritesheet::sub_grid), the indices are relative to the sub-grid like the ones given to [AnimationBuilder::add_indices()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let image = Handle::default();
+    /// let spritesheet = Spritesheet::new(&image, 2, 2);
+    ///
+    /// assert_eq!(spritesheet.validate_indices(&[0, 1, 3]), Ok(()));
+    /// assert_eq!(spritesheet.validate_indices(&[0, 4, 2, 10]), Err(vec![4, 10]));
+    /// ```
+    pub fn validate_indices(&self, indices: &[usize]) -> Result<(), Vec<usize>> {
+        let invalid_indices: Vec<usize> = indices
+            .iter()
+            .copied()
+            .filter(|index| *index >= self.total_frame_count())
+            .collect();
+
+        if invalid_indices.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_indices)
+        }
+    }
+
     /// Creates a new animation that uses that spritesheet.
     ///
     /// This returns an [AnimationBuilder] with which you can extract frames from the spritesheet and set playback parameters.
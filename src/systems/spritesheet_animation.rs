@@ -3,15 +3,39 @@ use bevy::prelude::*;
 use crate::{
     animation::Animation,
     animator::{Animator, SpritesheetAnimationQuery},
-    events::AnimationEvent,
+    events::{AnimationEvent, ClipChangedEvent, FrameChangedEvent},
 };
 
+pub fn invalidate_animations(
+    mut animator: ResMut<Animator>,
+    mut asset_events: MessageReader<AssetEvent<Animation>>,
+) {
+    // Discard the animations that changed
+
+    for event in asset_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            animator.invalidate(*id);
+        }
+    }
+}
+
 pub fn play_animations(
     time: Res<Time>,
+    real_time: Res<Time<Real>>,
     mut animator: ResMut<Animator>,
     mut message_writer: MessageWriter<AnimationEvent>,
+    mut clip_changed_writer: MessageWriter<ClipChangedEvent>,
+    mut frame_changed_writer: MessageWriter<FrameChangedEvent>,
     mut query: Query<SpritesheetAnimationQuery>,
     mut animations: ResMut<Assets<Animation>>,
 ) {
-    animator.update(&time, &mut message_writer, &mut query, &mut animations);
+    animator.update(
+        &time,
+        &real_time,
+        &mut message_writer,
+        &mut clip_changed_writer,
+        &mut frame_changed_writer,
+        &mut query,
+        &mut animations,
+    );
 }
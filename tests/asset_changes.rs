@@ -0,0 +1,196 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn replace_clip() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2, 3])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .start_clip()
+            .add_indices([4, 5])
+    });
+
+    ctx.run(50);
+    ctx.check(1, []);
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+
+    // Replace the first clip
+
+    let new_clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+        .add_indices([10, 20, 30])
+        .build();
+
+    let mut animations = ctx
+        .app
+        .world_mut()
+        .get_resource_mut::<Assets<Animation>>()
+        .unwrap();
+
+    let animation_asset = animations.get_mut(&animation).unwrap();
+
+    let old_clip = animation_asset.replace_clip(0, new_clip.clone());
+
+    assert_eq!(
+        old_clip.map(|clip| clip.atlas_indices().to_vec()),
+        Some(vec![1, 2, 3])
+    );
+    assert_eq!(
+        animation_asset.clip_at(0).map(|clip| clip.id()),
+        Some(new_clip.id())
+    );
+
+    // Out-of-bounds replacements are ignored
+
+    assert!(animation_asset.replace_clip(2, new_clip.clone()).is_none());
+    assert_eq!(animation_asset.num_clips(), 2);
+
+    // The animation resumes from the same progress with the new clip
+
+    ctx.run(10); // 160
+    ctx.check(20, []);
+
+    ctx.run(100); // 260
+    ctx.check(30, []);
+
+    ctx.run(100); // 360
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(&animation, new_clip.id(), 0),
+            ctx.clip_end(&animation, new_clip.id()),
+        ],
+    );
+}
+
+#[test]
+fn append_and_prepend_clips() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2])
+            .get_current_clip_id(&mut clip_id)
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+    });
+
+    ctx.run(50);
+    ctx.check(1, []);
+
+    // Append a clip
+
+    let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+    let appended_clip = ClipBuilder::new(&spritesheet).add_indices([3]).build();
+
+    let mut animations = ctx
+        .app
+        .world_mut()
+        .get_resource_mut::<Assets<Animation>>()
+        .unwrap();
+
+    animations
+        .get_mut(&animation)
+        .unwrap()
+        .append_clip(appended_clip.clone());
+
+    // The animation resumes from the same progress and plays the new clip at the end
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+
+    ctx.run(100); // 250
+    ctx.check(
+        3,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+        ],
+    );
+
+    // Prepend a clip
+
+    let prepended_clip = ClipBuilder::new(&spritesheet).add_indices([10, 20]).build();
+
+    let mut animations = ctx
+        .app
+        .world_mut()
+        .get_resource_mut::<Assets<Animation>>()
+        .unwrap();
+
+    let animation_asset = animations.get_mut(&animation).unwrap();
+
+    animation_asset.prepend_clip(prepended_clip.clone());
+
+    assert_eq!(
+        animation_asset
+            .clips()
+            .iter()
+            .map(|clip| clip.atlas_indices().to_vec())
+            .collect::<Vec<_>>(),
+        vec![vec![10, 20], vec![1, 2], vec![3]]
+    );
+
+    // Restart from the new first clip
+
+    ctx.get_sprite(|sprite| sprite.reset());
+
+    ctx.run(10); // 260
+    ctx.check(10, []);
+
+    ctx.run(100); // 360
+    ctx.check(20, []);
+
+    ctx.run(100); // 460
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(&animation, prepended_clip.id(), 0),
+            ctx.clip_end(&animation, prepended_clip.id()),
+        ],
+    );
+}
+
+#[test]
+fn remove_playing_animation() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2, 3])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+    ctx.check(1, []);
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Assets<Animation>>()
+        .remove(&animation);
+
+    // The sprite stops playing on its current frame
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    ctx.run(100); // 250
+    ctx.check(1, []);
+
+    // Restarting the removed animation does nothing either
+
+    ctx.get_sprite(|sprite| sprite.reset());
+
+    ctx.run(100); // 350
+    ctx.check(1, []);
+}
@@ -0,0 +1,81 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn clip_changes_emit_events() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            // Clip 0
+            .add_indices([0, 1])
+            .set_clip_repetitions(2)
+            // Clip 1
+            .start_clip()
+            .add_indices([5])
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+    ctx.check_clip_changes([]);
+
+    ctx.run(100); // 150
+    ctx.check_clip_changes([]);
+
+    // The clip repeats, this is not a change
+
+    ctx.run(100); // 250
+    ctx.check_clip_changes([]);
+
+    ctx.run(100); // 350
+    ctx.check_clip_changes([]);
+
+    // Next clip
+
+    ctx.run(100); // 450
+    ctx.check_clip_changes([(0, 1)]);
+
+    // Loop back to the first clip
+
+    ctx.run(100); // 550
+    ctx.check_clip_changes([(1, 0)]);
+}
+
+#[test]
+fn single_clip_loop_does_not_change_clip() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| builder.add_indices([0, 1, 2]));
+
+    for _ in 0..10 {
+        ctx.run(100);
+        ctx.check_clip_changes([]);
+    }
+}
+
+#[test]
+fn manual_progress_changes_clip() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(1000))
+            .add_indices([0, 1])
+            .start_clip()
+            .add_indices([2, 3])
+    });
+
+    ctx.run(100);
+    ctx.check_clip_changes([]);
+
+    ctx.get_sprite(|sprite| {
+        sprite.progress.frame = 3;
+    });
+
+    ctx.run(100);
+    ctx.check(3, []);
+    ctx.check_clip_changes([(0, 1)]);
+}
@@ -0,0 +1,85 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn animation_commands() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .add_indices([1, 2, 3])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    let other_animation = ctx.create_animation(|builder| {
+        builder
+            .add_indices([6, 7])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    // Inserts the component
+
+    run_command(&mut ctx, |mut entity| {
+        entity.play_animation(animation.clone());
+    });
+
+    ctx.run(50);
+    ctx.check(1, []);
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+
+    // Pause
+
+    run_command(&mut ctx, |mut entity| {
+        entity.pause_animation();
+    });
+
+    ctx.run(200); // 350
+    ctx.check(2, []);
+
+    // Resume
+
+    run_command(&mut ctx, |mut entity| {
+        entity.resume_animation();
+    });
+
+    ctx.run(30); // 380
+    ctx.check(2, []);
+
+    ctx.run(100); // 480
+    ctx.check(3, []);
+
+    // Reset
+
+    run_command(&mut ctx, |mut entity| {
+        entity.reset_animation();
+    });
+
+    ctx.run(10); // 490
+    ctx.check(1, []);
+
+    // Switches to another animation
+
+    run_command(&mut ctx, |mut entity| {
+        entity.play_animation(other_animation.clone());
+    });
+
+    ctx.run(10); // 500
+    ctx.check(6, []);
+
+    ctx.get_sprite(|sprite| {
+        assert_eq!(sprite.animation, other_animation);
+    });
+}
+
+fn run_command<F: FnOnce(EntityCommands)>(ctx: &mut Context, f: F) {
+    let world = ctx.app.world_mut();
+
+    f(world.commands().entity(ctx.sprite_entity));
+
+    world.flush();
+}
@@ -20,6 +20,11 @@ pub struct Context {
 impl Context {
     // Creates the test context
     pub fn new() -> Self {
+        Self::with_plugin(SpritesheetAnimationPlugin)
+    }
+
+    // Creates the test context with a specific configuration of the plugin
+    pub fn with_plugin(plugin: impl Plugin) -> Self {
         // Create the app
 
         let mut app = App::new();
@@ -38,7 +43,7 @@ impl Context {
                     .into(),
                     ..default()
                 }),
-            SpritesheetAnimationPlugin,
+            plugin,
         ))
         // Insert a manual update strategy to control time
         .insert_resource(TimeUpdateStrategy::ManualInstant(Instant::now()));
@@ -118,6 +123,18 @@ impl Context {
 
         events_resources.clear();
 
+        self.app
+            .world_mut()
+            .get_resource_mut::<Messages<ClipChangedEvent>>()
+            .unwrap()
+            .clear();
+
+        self.app
+            .world_mut()
+            .get_resource_mut::<Messages<FrameChangedEvent>>()
+            .unwrap()
+            .clear();
+
         // Move time forwards
 
         let mut time_strategy = self
@@ -179,6 +196,54 @@ impl Context {
         assert_eq!(events, HashSet::from_iter(expected_events));
     }
 
+    // Tests the clip changes emitted during the last update
+    pub fn check_clip_changes(
+        &mut self,
+        expected_changes: impl IntoIterator<Item = (usize, usize)>,
+    ) {
+        let messages = self
+            .app
+            .world()
+            .get_resource::<Messages<ClipChangedEvent>>()
+            .unwrap();
+
+        let changes: Vec<_> = messages
+            .get_cursor()
+            .read(messages)
+            .map(|message| {
+                assert_eq!(message.entity, self.sprite_entity);
+
+                (message.from_clip, message.to_clip)
+            })
+            .collect();
+
+        assert_eq!(changes, Vec::from_iter(expected_changes));
+    }
+
+    // Tests the frame changes emitted during the last update, as (clip index, frame index, atlas index)
+    pub fn check_frame_changes(
+        &mut self,
+        expected_changes: impl IntoIterator<Item = (usize, usize, usize)>,
+    ) {
+        let messages = self
+            .app
+            .world()
+            .get_resource::<Messages<FrameChangedEvent>>()
+            .unwrap();
+
+        let changes: Vec<_> = messages
+            .get_cursor()
+            .read(messages)
+            .map(|message| {
+                assert_eq!(message.entity, self.sprite_entity);
+
+                (message.clip_index, message.frame_index, message.atlas_index)
+            })
+            .collect();
+
+        assert_eq!(changes, Vec::from_iter(expected_changes));
+    }
+
     // Gets the sprites to inspect or update it
     pub fn get_sprite<F: FnMut(&mut SpritesheetAnimation)>(&mut self, mut f: F) {
         let mut sprite = self
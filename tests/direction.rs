@@ -1,5 +1,6 @@
 pub mod context;
 
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -469,3 +470,381 @@ fn animation_backwards_clip_pingpong() {
     ctx.run(100);
     ctx.check(1, []);
 }
+
+// PingPong in multi-clip animations
+//
+// Clip 0: 0 → 1 → 2, clip 1: 5 → 6
+
+fn check_sequence(ctx: &mut Context, expected_atlas_indices: &[usize]) {
+    ctx.run(50);
+
+    for (frame, expected_atlas_index) in expected_atlas_indices.iter().enumerate() {
+        let atlas_index = ctx
+            .app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .and_then(|sprite| sprite.texture_atlas.as_ref())
+            .map(|atlas| atlas.index);
+
+        assert_eq!(atlas_index, Some(*expected_atlas_index), "frame {frame}");
+
+        ctx.run(100);
+    }
+}
+
+#[test]
+fn multiple_clips_forwards() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+            .start_clip()
+            .add_indices([5, 6])
+    });
+
+    check_sequence(&mut ctx, &[0, 1, 2, 5, 6, 0, 1, 2, 5, 6]);
+}
+
+#[test]
+fn multiple_clips_clip_pingpong() {
+    let mut ctx = Context::new();
+
+    // Only the first clip ping-pongs, the animation itself moves forwards
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+            .set_clip_direction(AnimationDirection::PingPong)
+            .set_clip_repetitions(2)
+            .start_clip()
+            .add_indices([5, 6])
+    });
+
+    check_sequence(
+        &mut ctx,
+        &[
+            0, 1, 2, 1, 0, 5, 6, // First repetition
+            0, 1, 2, 1, 0, 5, 6, // Second repetition
+        ],
+    );
+}
+
+#[test]
+fn multiple_clips_animation_pingpong() {
+    let mut ctx = Context::new();
+
+    // The whole sequence of clips ping-pongs
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_direction(AnimationDirection::PingPong)
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+            .start_clip()
+            .add_indices([5, 6])
+    });
+
+    check_sequence(
+        &mut ctx,
+        &[
+            0, 1, 2, 5, 6, // Ping
+            5, 2, 1, 0, // Pong
+            1, 2, 5, 6, // Ping
+        ],
+    );
+}
+
+#[test]
+fn multiple_clips_animation_pingpong_clip_pingpong() {
+    let mut ctx = Context::new();
+
+    // The whole sequence of clips ping-pongs, including the clip that ping-pongs itself
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_direction(AnimationDirection::PingPong)
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+            .set_clip_direction(AnimationDirection::PingPong)
+            .set_clip_repetitions(2)
+            .start_clip()
+            .add_indices([5, 6])
+    });
+
+    check_sequence(
+        &mut ctx,
+        &[
+            0, 1, 2, 1, 0, 5, 6, // Ping
+            5, 0, 1, 2, 1, 0, // Pong
+            1, 2, 1, 0, 5, 6, // Ping
+        ],
+    );
+}
+
+// Mirrored rows
+
+#[test]
+fn mirrored_row() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+            .add_row_mirrored(1)
+    });
+
+    let is_flipped = |ctx: &mut Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .flip_x
+    };
+
+    ctx.run(50);
+    ctx.check(0, []);
+    assert!(!is_flipped(&mut ctx));
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+    assert!(!is_flipped(&mut ctx));
+
+    // The row plays backwards, flipped
+
+    ctx.run(100); // 250
+    ctx.check(15, []);
+    assert!(is_flipped(&mut ctx));
+
+    ctx.run(100); // 350
+    ctx.check(14, []);
+    assert!(is_flipped(&mut ctx));
+
+    ctx.run(600); // 950
+    ctx.check(8, []);
+    assert!(is_flipped(&mut ctx));
+
+    // The unflipped frames are restored when the animation loops
+
+    ctx.run(100); // 1050
+    assert!(!is_flipped(&mut ctx));
+}
+
+#[test]
+fn flip_untouched_without_mirrored_rows() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .world_mut()
+        .get_mut::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .flip_x = true;
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    assert!(
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .flip_x
+    );
+}
+
+// Shuffle
+
+fn record_sequence(ctx: &mut Context, frame_count: usize) -> Vec<usize> {
+    ctx.run(50);
+
+    (0..frame_count)
+        .map(|_| {
+            let atlas_index = ctx
+                .app
+                .world()
+                .get::<Sprite>(ctx.sprite_entity)
+                .and_then(|sprite| sprite.texture_atlas.as_ref())
+                .map(|atlas| atlas.index)
+                .unwrap();
+
+            ctx.run(100);
+
+            atlas_index
+        })
+        .collect()
+}
+
+fn sorted(atlas_indices: &[usize]) -> Vec<usize> {
+    let mut atlas_indices = atlas_indices.to_vec();
+    atlas_indices.sort();
+    atlas_indices
+}
+
+#[test]
+fn clip_shuffle() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices(0..8)
+            .set_clip_direction(AnimationDirection::Shuffle(7))
+            .set_clip_repetitions(2)
+            .start_clip()
+            .add_indices([10, 11])
+    });
+
+    let sequence = record_sequence(&mut ctx, 18);
+
+    // The first repetition matches the order reported by the clip
+
+    let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+        .add_indices(0..8)
+        .set_direction(AnimationDirection::Shuffle(7))
+        .build();
+
+    let expected: Vec<_> = (0..8)
+        .map(|position| clip.frame_at(position).unwrap())
+        .collect();
+
+    assert_eq!(sequence[0..8], expected);
+
+    // The second repetition is shuffled differently
+
+    assert_eq!(sorted(&sequence[8..16]), (0..8).collect::<Vec<_>>());
+    assert_ne!(sequence[0..8], sequence[8..16]);
+
+    // The other clips are not shuffled
+
+    assert_eq!(sequence[16..18], [10, 11]);
+}
+
+#[test]
+fn animation_shuffle() {
+    let create_context = || {
+        let mut ctx = Context::new();
+
+        ctx.attach_animation(|builder| {
+            builder
+                .set_direction(AnimationDirection::Shuffle(42))
+                .set_duration(AnimationDuration::PerFrame(100))
+                .add_indices(0..4)
+                .start_clip()
+                .add_indices(4..8)
+        });
+
+        ctx
+    };
+
+    let sequence = record_sequence(&mut create_context(), 24);
+
+    // The clips are shuffled separately
+
+    for repetition in sequence.chunks(8) {
+        assert_eq!(sorted(&repetition[0..4]), [0, 1, 2, 3]);
+        assert_eq!(sorted(&repetition[4..8]), [4, 5, 6, 7]);
+    }
+
+    // Each repetition is shuffled differently
+
+    assert_ne!(sequence[0..8], sequence[8..16]);
+    assert_ne!(sequence[8..16], sequence[16..24]);
+
+    // The same seed plays the same sequence
+
+    assert_eq!(record_sequence(&mut create_context(), 24), sequence);
+}
+
+#[test]
+fn shuffle_virtual_frames() {
+    // Animation shuffle: the virtual frame stays last and holds the last shuffled frame
+
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_direction(AnimationDirection::Shuffle(42))
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices(0..4)
+            .add_virtual_frame(100)
+    });
+
+    let sequence = record_sequence(&mut ctx, 15);
+
+    for repetition in sequence.chunks(5) {
+        assert_eq!(sorted(&repetition[0..4]), [0, 1, 2, 3]);
+        assert_eq!(repetition[4], repetition[3]);
+    }
+
+    // Clip shuffle: the sequence matches the order reported by the clip
+
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices(0..4)
+            .add_virtual_frame(100)
+            .add_indices(4..8)
+            .set_clip_direction(AnimationDirection::Shuffle(7))
+    });
+
+    let sequence = record_sequence(&mut ctx, 9);
+
+    let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+        .add_indices(0..4)
+        .add_virtual_frame(100)
+        .add_indices(4..8)
+        .set_direction(AnimationDirection::Shuffle(7))
+        .build();
+
+    let expected: Vec<_> = (0..9)
+        .map(|position| clip.frame_at(position).unwrap())
+        .collect();
+
+    assert_eq!(sequence, expected);
+    assert_eq!(sequence[4], sequence[3]);
+}
+
+// Reversed animations
+
+#[test]
+fn into_reversed() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+            .start_clip()
+            .add_indices([5, 6])
+            .set_clip_direction(AnimationDirection::Backwards)
+    });
+
+    let reversed_animation = {
+        let mut animations = ctx.app.world_mut().resource_mut::<Assets<Animation>>();
+
+        let reversed = animations.get(&animation).unwrap().clone().into_reversed();
+
+        animations.add(reversed)
+    };
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(SpritesheetAnimation::new(reversed_animation));
+
+    check_sequence(&mut ctx, &[5, 6, 2, 1, 0, 5, 6, 2, 1, 0]);
+}
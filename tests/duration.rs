@@ -1,5 +1,6 @@
 pub mod context;
 
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -335,3 +336,458 @@ fn speed_factor() {
     ctx.run(100); // 520
     ctx.check(6, []);
 }
+
+#[test]
+fn delay() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .add_indices([3, 4])
+            .get_current_clip_id(&mut clip_id)
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_delay(500)
+    });
+
+    // The first frame is held during the delay
+
+    ctx.run(300);
+    ctx.check(3, []);
+
+    ctx.run(250); // 550
+    ctx.check(3, []);
+
+    // Then the animation plays normally
+
+    ctx.run(100); // 650
+    ctx.check(4, []);
+
+    // The delay does not apply to the next repetitions
+
+    ctx.run(100); // 750
+    ctx.check(
+        3,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+            ctx.anim_rep_end(&animation, 0),
+        ],
+    );
+
+    ctx.run(100); // 850
+    ctx.check(4, []);
+}
+
+#[test]
+fn clip_frame_rate() {
+    let mut ctx = Context::new();
+
+    // 24 fps: 41.666ms per frame, not rounded down to 41ms
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([5, 1, 7])
+            .set_clip_frame_rate(24.0)
+            .set_repetitions(AnimationRepeat::Times(1))
+    });
+
+    ctx.run(10);
+    ctx.check(5, []);
+
+    ctx.run(33); // 43
+    ctx.check(1, []);
+
+    ctx.run(40); // 83
+    ctx.check(1, []);
+
+    ctx.run(2); // 85
+    ctx.check(7, []);
+}
+
+#[test]
+fn pause() {
+    let mut ctx = Context::new();
+
+    let mut clip1_id = ClipId::dummy();
+    let mut clip2_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+            .add_indices([0, 1])
+            .add_pause(500)
+            .get_current_clip_id(&mut clip1_id)
+            .start_clip()
+            .add_indices([2])
+            .get_current_clip_id(&mut clip2_id)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // The last frame is held for 100ms + 500ms
+
+    ctx.run(100); // 250
+    ctx.check(1, []);
+
+    ctx.run(440); // 690
+    ctx.check(1, []);
+
+    ctx.run(20); // 710
+    ctx.check(
+        2,
+        [
+            ctx.clip_rep_end(&animation, clip1_id, 0),
+            ctx.clip_end(&animation, clip1_id),
+        ],
+    );
+
+    // The pause is played as a regular frame
+
+    ctx.get_sprite(|sprite| assert_eq!(sprite.progress.frame, 3));
+}
+
+#[test]
+fn frame_at_time() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            // Ignored by the timed frames
+            .set_duration(AnimationDuration::PerFrame(1000))
+            .add_frame_at_time(0, 0, 100)
+            .add_frame_at_time(1, 100, 400)
+            .add_frame_at_time(2, 500, 550)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // Frame 1 is held until frame 2 starts
+
+    ctx.run(300); // 450
+    ctx.check(1, []);
+
+    ctx.run(70); // 520
+    ctx.check(2, []);
+}
+
+#[test]
+fn frame_at_time_with_easing() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_frame_at_time(0, 0, 100)
+            .add_frame_at_time(1, 100, 400)
+            .add_frame_at_time(2, 400, 500)
+            // Ignored by the timed frames
+            .set_clip_easing(Easing::In(EasingVariety::Cubic))
+            .set_easing(Easing::Out(EasingVariety::Cubic))
+    });
+
+    ctx.run(90);
+    ctx.check(0, []);
+
+    ctx.run(20); // 110
+    ctx.check(1, []);
+
+    ctx.run(280); // 390
+    ctx.check(1, []);
+
+    ctx.run(20); // 410
+    ctx.check(2, []);
+}
+
+#[test]
+fn clip_blend() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+            .set_clip_blend_in(100)
+            .set_clip_blend_out(50)
+            .get_current_clip_id(&mut clip_id)
+            .start_clip()
+            .add_indices([2])
+    });
+
+    let alpha = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .color
+            .alpha()
+    };
+
+    // Fading in
+
+    ctx.run(50);
+    ctx.check(0, []);
+    assert!((alpha(&ctx) - 0.5).abs() < 0.01);
+
+    ctx.run(75); // 125
+    ctx.check(1, []);
+    assert_eq!(alpha(&ctx), 1.0);
+
+    // Fading out
+
+    ctx.run(50); // 175
+    ctx.check(1, []);
+    assert!((alpha(&ctx) - 0.5).abs() < 0.01);
+
+    // The next clip is opaque
+
+    ctx.run(75); // 250
+    ctx.check(
+        2,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+        ],
+    );
+    assert_eq!(alpha(&ctx), 1.0);
+}
+
+#[test]
+fn clip_blend_restores_alpha() {
+    let mut ctx = Context::new();
+
+    let alpha = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .color
+            .alpha()
+    };
+
+    // Switching to a plain animation while fading out
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+            .set_clip_blend_out(100)
+    });
+
+    ctx.run(150);
+    assert!((alpha(&ctx) - 0.5).abs() < 0.01);
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([2, 3])
+    });
+
+    ctx.run(50);
+    ctx.check(2, []);
+    assert_eq!(alpha(&ctx), 1.0);
+
+    // The fades apply to the original alpha of the sprite, which is restored once the animation is over
+
+    ctx.app
+        .world_mut()
+        .get_mut::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .color
+        .set_alpha(0.5);
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+            .add_indices([4, 5])
+            .set_clip_blend_in(100)
+            .set_clip_blend_out(100)
+    });
+
+    ctx.run(50);
+    ctx.check(4, []);
+    assert!((alpha(&ctx) - 0.25).abs() < 0.01);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+    assert!((alpha(&ctx) - 0.25).abs() < 0.01);
+
+    ctx.run(200); // 350
+    ctx.get_sprite(|sprite| assert_eq!(sprite.frames_remaining(), Some(0)));
+    assert!((alpha(&ctx) - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn virtual_frame() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+    let mut clip2_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+            .get_current_clip_id(&mut clip_id)
+            // Starts by holding the last frame of the previous clip
+            .start_clip()
+            .add_virtual_frame(200)
+            .add_indices([2])
+            .get_current_clip_id(&mut clip2_id)
+            // Holds a frame of a backwards clip
+            .start_clip()
+            .add_indices([3, 4])
+            .add_virtual_frame(100)
+            .set_clip_direction(AnimationDirection::Backwards)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    ctx.run(100); // 250
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+        ],
+    );
+
+    ctx.run(100); // 350
+    ctx.check(1, []);
+
+    ctx.run(100); // 450
+    ctx.check(2, []);
+
+    // The virtual frame plays first in the backwards clip
+
+    ctx.run(100); // 550
+    ctx.check(
+        2,
+        [
+            ctx.clip_rep_end(&animation, clip2_id, 0),
+            ctx.clip_end(&animation, clip2_id),
+        ],
+    );
+
+    ctx.run(100); // 650
+    ctx.check(4, []);
+}
+
+#[test]
+fn frame_easing() {
+    // Record the atlas indices when updating the app every 10ms
+    fn record_atlas_indices<F>(builder: F) -> Vec<usize>
+    where
+        F: Fn(AnimationBuilder) -> AnimationBuilder,
+    {
+        let mut ctx = Context::new();
+
+        ctx.attach_animation(|animation_builder| {
+            builder(animation_builder.set_duration(AnimationDuration::PerFrame(100)))
+        });
+
+        (0..60)
+            .map(|_| {
+                ctx.run(10);
+
+                ctx.app
+                    .world()
+                    .get::<Sprite>(ctx.sprite_entity)
+                    .and_then(|sprite| sprite.texture_atlas.as_ref())
+                    .map(|atlas| atlas.index)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    let easing = Easing::In(EasingVariety::Cubic);
+
+    // Restarting the easing within a clip is the same as splitting the clip
+
+    let with_frame_easing = record_atlas_indices(|builder| {
+        builder
+            .add_indices([0, 1, 2, 3, 4, 5])
+            .set_clip_easing(easing)
+            .set_clip_frame_easing(3, easing)
+    });
+
+    let with_split_clips = record_atlas_indices(|builder| {
+        builder
+            .add_indices([0, 1, 2])
+            .set_clip_easing(easing)
+            .start_clip()
+            .add_indices([3, 4, 5])
+            .set_clip_easing(easing)
+    });
+
+    assert_eq!(with_frame_easing, with_split_clips);
+
+    // ... which differs from easing the whole clip
+
+    let without_frame_easing = record_atlas_indices(|builder| {
+        builder
+            .add_indices([0, 1, 2, 3, 4, 5])
+            .set_clip_easing(easing)
+    });
+
+    assert_ne!(with_frame_easing, without_frame_easing);
+}
+
+#[test]
+fn virtual_time() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2, 3, 4])
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    // Twice faster
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_relative_speed(2.0);
+
+    ctx.run(50); // 150 virtual
+    ctx.check(1, []);
+
+    ctx.run(50); // 250 virtual
+    ctx.check(2, []);
+
+    // Paused
+
+    ctx.app.world_mut().resource_mut::<Time<Virtual>>().pause();
+
+    ctx.run(1000);
+    ctx.check(2, []);
+
+    // Back to normal
+
+    let mut virtual_time = ctx.app.world_mut().resource_mut::<Time<Virtual>>();
+    virtual_time.unpause();
+    virtual_time.set_relative_speed(1.0);
+
+    ctx.run(100); // 350 virtual
+    ctx.check(3, []);
+}
@@ -0,0 +1,93 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn disabled_by_default() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+    });
+
+    for _ in 0..10 {
+        ctx.run(100);
+        ctx.check_frame_changes([]);
+    }
+}
+
+#[test]
+fn frame_changes_emit_events() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(2))
+            // Clip 0
+            .add_indices([3, 4])
+            // Clip 1
+            .start_clip()
+            .add_indices([7])
+    });
+
+    ctx.get_sprite(|sprite| sprite.frame_changed_events = true);
+
+    // The first frame is a change too
+
+    ctx.run(50);
+    ctx.check(3, []);
+    ctx.check_frame_changes([(0, 0, 3)]);
+
+    ctx.run(100); // 150
+    ctx.check_frame_changes([(0, 1, 4)]);
+
+    ctx.run(100); // 250
+    ctx.check_frame_changes([(1, 2, 7)]);
+
+    // Second repetition
+
+    ctx.run(100); // 350
+    ctx.check_frame_changes([(0, 0, 3)]);
+
+    // Several frames during the same update
+
+    ctx.run(200); // 550
+    ctx.check_frame_changes([(0, 1, 4), (1, 2, 7)]);
+
+    // Over
+
+    ctx.run(100); // 650
+    ctx.check_frame_changes([]);
+}
+
+#[test]
+fn opt_out() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+    });
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(SpritesheetAnimation::new(animation).with_frame_changed_events(true));
+
+    ctx.run(50);
+    ctx.check_frame_changes([(0, 0, 0)]);
+
+    ctx.run(100); // 150
+    ctx.check_frame_changes([(0, 1, 1)]);
+
+    ctx.get_sprite(|sprite| sprite.frame_changed_events = false);
+
+    ctx.run(100); // 250
+    ctx.check(2, []);
+    ctx.check_frame_changes([]);
+}
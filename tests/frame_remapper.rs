@@ -0,0 +1,39 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn frames_are_remapped() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([0, 1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(FrameRemapper::new(|atlas_index| atlas_index + 8));
+
+    ctx.run(50);
+    ctx.check(8, []);
+
+    ctx.run(100); // 150
+    ctx.check(9, []);
+
+    // Replacing the remapper immediately updates the current frame
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(FrameRemapper::new(|atlas_index| atlas_index * 10));
+
+    ctx.run(10); // 160
+    ctx.check(10, []);
+
+    ctx.run(100); // 260
+    ctx.check(20, []);
+}
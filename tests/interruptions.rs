@@ -0,0 +1,101 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn play_once_then_resume() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2, 3])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    let mut attack_clip_id = ClipId::dummy();
+
+    let attack = ctx.create_animation(|builder| {
+        builder
+            .add_indices([7, 8])
+            .get_current_clip_id(&mut attack_clip_id)
+            .set_duration(AnimationDuration::PerFrame(100))
+            // Should be ignored
+            .set_repetitions(AnimationRepeat::Loop)
+    });
+
+    ctx.run(150);
+    ctx.check(2, []);
+
+    // Interrupt the animation
+
+    ctx.get_sprite(|sprite| {
+        sprite.play_once_then_resume(attack.clone());
+        assert!(sprite.is_interrupted());
+    });
+
+    ctx.run(10); // 160
+    ctx.check(7, []);
+
+    ctx.run(105); // 265
+    ctx.check(8, []);
+
+    // The attack ends after a single repetition
+
+    ctx.run(105); // 370
+    ctx.check(
+        8,
+        [
+            ctx.clip_rep_end(&attack, attack_clip_id, 0),
+            ctx.clip_end(&attack, attack_clip_id),
+            ctx.anim_rep_end(&attack, 0),
+            ctx.anim_end(&attack),
+        ],
+    );
+
+    ctx.get_sprite(|sprite| {
+        assert!(!sprite.is_interrupted());
+    });
+
+    // The interrupted animation resumes where it stopped
+
+    ctx.run(10); // 380
+    ctx.check(2, []);
+
+    ctx.run(150); // 530
+    ctx.check(3, []);
+}
+
+#[test]
+fn switch_cancels_resume() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2, 3])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    let attack = ctx.create_animation(|builder| {
+        builder
+            .add_indices([7, 8])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    let other = ctx.create_animation(|builder| {
+        builder
+            .add_indices([5])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+
+    ctx.get_sprite(|sprite| {
+        sprite.play_once_then_resume(attack.clone());
+        sprite.switch(other.clone());
+        assert!(!sprite.is_interrupted());
+    });
+
+    ctx.run(10);
+    ctx.check(5, []);
+}
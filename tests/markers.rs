@@ -88,3 +88,67 @@ fn markers_emit_events() {
         ],
     );
 }
+
+#[test]
+fn marker_group() {
+    let mut ctx = Context::new();
+
+    let other_marker = Marker::new();
+
+    // The group matches the markers named after it is created
+
+    let group = Marker::group("group_test_hit");
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2, 3])
+            .add_clip_marker(Marker::named("group_test_hit"), 0)
+            .add_clip_marker(Marker::named("group_test_hit/left"), 1)
+            .add_clip_marker(Marker::named("group_test_hits"), 2)
+            .add_clip_marker(other_marker, 3)
+    });
+
+    // Collect the markers of the group that are hit
+
+    let mut hits = Vec::new();
+
+    ctx.run(50);
+
+    for _ in 0..4 {
+        let messages = ctx
+            .app
+            .world()
+            .resource::<bevy::prelude::Messages<AnimationEvent>>();
+
+        for message in messages.get_cursor().read(messages) {
+            if let AnimationEvent::MarkerHit { marker, .. } = message
+                && group.matches(marker)
+            {
+                hits.push(*marker);
+            }
+        }
+
+        ctx.run(100);
+    }
+
+    assert_eq!(
+        hits,
+        [
+            Marker::named("group_test_hit"),
+            Marker::named("group_test_hit/left")
+        ]
+    );
+
+    // Groups of specific markers
+
+    let group = MarkerGroup::Markers(vec![other_marker]);
+
+    assert!(group.matches(&other_marker));
+    assert!(!group.matches(&Marker::named("group_test_hit")));
+
+    // Empty group
+
+    assert!(!Marker::group("group_test_unknown").matches(&other_marker));
+    assert!(!MarkerGroup::default().matches(&other_marker));
+}
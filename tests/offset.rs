@@ -0,0 +1,102 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn clip_offset() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .start_clip()
+            .add_indices([3, 4, 5])
+            .set_clip_repetitions(2)
+            .set_clip_offset(1)
+    });
+
+    let mut atlas_indices = Vec::new();
+
+    ctx.run(50);
+
+    for _ in 0..14 {
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+
+        atlas_indices.push(sprite.texture_atlas.as_ref().unwrap().index);
+
+        ctx.run(100);
+    }
+
+    // The first repetition of the second clip skips its first frame, on every repetition of the animation
+
+    assert_eq!(atlas_indices, [1, 2, 4, 5, 3, 4, 5, 1, 2, 4, 5, 3, 4, 5]);
+}
+
+#[test]
+fn clip_offset_backwards() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([3, 4, 5])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_clip_direction(AnimationDirection::Backwards)
+            .set_clip_offset(2)
+            .set_repetitions(AnimationRepeat::Times(1))
+    });
+
+    ctx.run(50);
+    ctx.check(3, []);
+}
+
+#[test]
+fn clip_offset_too_large() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_clip_offset(10)
+            .start_clip()
+            .add_indices([6])
+    });
+
+    // The first clip has no frames left so the animation starts with the second clip
+
+    ctx.run(50);
+    ctx.check(6, []);
+}
+
+#[test]
+fn clip_start_frame() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .start_clip()
+            .add_indices([3, 4, 5])
+            .set_clip_start_frame(1)
+    });
+
+    let mut atlas_indices = Vec::new();
+
+    ctx.run(50);
+
+    for _ in 0..14 {
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+
+        atlas_indices.push(sprite.texture_atlas.as_ref().unwrap().index);
+
+        ctx.run(100);
+    }
+
+    // The second clip only skips its first frame the first time the animation is played
+
+    assert_eq!(atlas_indices, [1, 2, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4, 5]);
+}
@@ -1,6 +1,6 @@
 pub mod context;
 
-use bevy::prelude::*;
+use bevy::{diagnostic::DiagnosticsStore, prelude::*};
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -25,3 +25,329 @@ fn animation_events_available_as_a_resource() {
             .is_some()
     );
 }
+
+#[test]
+fn systems_can_run_before_the_animation_system_set() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([10, 11, 12, 13])
+            .set_duration(AnimationDuration::PerFrame(1000))
+    });
+
+    // Jump to the third frame right before the animations are updated
+
+    fn jump_to_third_frame(mut animations: Query<&mut SpritesheetAnimation>) {
+        for mut animation in &mut animations {
+            animation.progress.frame = 2;
+        }
+    }
+
+    ctx.app
+        .add_systems(PostUpdate, jump_to_third_frame.before(AnimationSystemSet));
+
+    // The sprite is updated in the same app update
+
+    ctx.run(100);
+    ctx.check(12, []);
+}
+
+#[test]
+fn many_entities_are_animated() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .add_indices([3, 4])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+    });
+
+    let entities: Vec<_> = (0..1000)
+        .map(|_| {
+            ctx.app
+                .world_mut()
+                .spawn((
+                    Sprite::from_atlas_image(
+                        Handle::default(),
+                        TextureAtlas {
+                            layout: Handle::default(),
+                            index: 0,
+                        },
+                    ),
+                    SpritesheetAnimation::new(animation.clone()),
+                ))
+                .id()
+        })
+        .collect();
+
+    ctx.run(150);
+    ctx.run(100);
+
+    // All the animations ended
+
+    let ended_entities: Vec<_> = ctx
+        .app
+        .world()
+        .resource::<Messages<AnimationEvent>>()
+        .iter_current_update_messages()
+        .filter_map(|event| match event {
+            AnimationEvent::AnimationEnd { entity, .. } => Some(*entity),
+            _ => None,
+        })
+        .collect();
+
+    // Each entity sent its event exactly once
+
+    assert_eq!(ended_entities.len(), entities.len());
+
+    for entity in entities {
+        assert!(ended_entities.contains(&entity));
+
+        let sprite = ctx.app.world().get::<Sprite>(entity).unwrap();
+
+        assert_eq!(sprite.texture_atlas.as_ref().unwrap().index, 4);
+    }
+}
+
+#[test]
+fn fixed_timestep() {
+    // Count the visible frame changes when updating the app every 10ms for one second
+    fn count_frame_changes(ctx: &mut Context) -> usize {
+        ctx.attach_animation(|builder| {
+            builder
+                .add_indices([0, 1, 2, 3, 4, 5, 6, 7])
+                .set_duration(AnimationDuration::PerFrame(100))
+        });
+
+        let mut changes = 0;
+        let mut last_atlas_index = None;
+
+        for _ in 0..100 {
+            ctx.run(10);
+
+            let atlas_index = ctx
+                .app
+                .world()
+                .get::<Sprite>(ctx.sprite_entity)
+                .and_then(|sprite| sprite.texture_atlas.as_ref())
+                .map(|atlas| atlas.index);
+
+            if last_atlas_index.is_some() && atlas_index != last_atlas_index {
+                changes += 1;
+            }
+
+            last_atlas_index = atlas_index;
+        }
+
+        changes
+    }
+
+    // Every app update: the frames change every 100ms
+
+    let mut ctx = Context::new();
+
+    assert!((9..=10).contains(&count_frame_changes(&mut ctx)));
+
+    // At 4hz: the frames only change every 250ms, skipping some of them
+
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin.with_fixed_timestep(4.0));
+
+    assert!((3..=4).contains(&count_frame_changes(&mut ctx)));
+
+    // Bevy's fixed clock is left untouched
+
+    assert_eq!(
+        ctx.app.world().resource::<Time<Fixed>>().timestep(),
+        Time::<Fixed>::default().timestep()
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid fixed timestep frequency")]
+fn invalid_fixed_timestep() {
+    let _ = SpritesheetAnimationPlugin.with_fixed_timestep(0.0);
+}
+
+#[test]
+fn disable_marker_events() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin.disable_marker_events());
+
+    let mut clip_id = ClipId::dummy();
+    let marker = Marker::new();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+            .add_indices([0, 1])
+            .add_clip_marker(marker, 0)
+            .add_clip_marker(marker, 1)
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    // No marker events
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // The other events are still emitted
+
+    ctx.run(100); // 250
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+            ctx.anim_rep_end(&animation, 0),
+            ctx.anim_end(&animation),
+        ],
+    );
+}
+
+#[test]
+fn system_order() {
+    // Record the atlas index seen by the game logic in Update
+    #[derive(Resource, Default)]
+    struct SeenAtlasIndex(Option<usize>);
+
+    fn record_atlas_index(sprites: Query<&Sprite>, mut seen: ResMut<SeenAtlasIndex>) {
+        seen.0 = sprites
+            .iter()
+            .find_map(|sprite| sprite.texture_atlas.as_ref())
+            .map(|atlas| atlas.index);
+    }
+
+    fn seen_atlas_index(order: AnimationSystemOrder) -> Option<usize> {
+        let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin.with_system_order(order));
+
+        ctx.app
+            .init_resource::<SeenAtlasIndex>()
+            .add_systems(Update, record_atlas_index);
+
+        ctx.attach_animation(|builder| {
+            builder
+                .add_indices([3, 4])
+                .set_duration(AnimationDuration::PerFrame(100))
+        });
+
+        ctx.run(50);
+        ctx.run(100); // 150, switched to the second frame
+
+        ctx.app.world().resource::<SeenAtlasIndex>().0
+    }
+
+    // Played before the game logic: it sees the new frame
+
+    assert_eq!(
+        seen_atlas_index(AnimationSystemOrder::BeforePhysics),
+        Some(4)
+    );
+
+    // Played after the game logic: it sees the previous frame
+
+    assert_eq!(
+        seen_atlas_index(AnimationSystemOrder::AfterPhysics),
+        Some(3)
+    );
+    assert_eq!(
+        seen_atlas_index(AnimationSystemOrder::AfterTransform),
+        Some(3)
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let mut ctx = Context::new();
+
+    ctx.app.add_plugins(SpritesheetAnimationDiagnosticsPlugin);
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+    });
+
+    let profiled_entities = |ctx: &Context| {
+        ctx.app
+            .world()
+            .resource::<DiagnosticsStore>()
+            .get(&SpritesheetAnimationDiagnosticsPlugin::PROFILED_ENTITIES)
+            .and_then(|diagnostic| diagnostic.value())
+    };
+
+    // Entities are not profiled by default
+
+    ctx.run(50);
+
+    assert_eq!(profiled_entities(&ctx), Some(0.0));
+
+    // Opt-in
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(AnimationProfiling::default());
+
+    ctx.run(100);
+
+    assert_eq!(profiled_entities(&ctx), Some(1.0));
+
+    let frame_advance_time = ctx
+        .app
+        .world()
+        .resource::<DiagnosticsStore>()
+        .get(&SpritesheetAnimationDiagnosticsPlugin::FRAME_ADVANCE_TIME)
+        .and_then(|diagnostic| diagnostic.value());
+
+    let profiling = ctx
+        .app
+        .world()
+        .get::<AnimationProfiling>(ctx.sprite_entity)
+        .unwrap();
+
+    assert_eq!(
+        frame_advance_time,
+        Some(profiling.last_frame_advance_time().as_secs_f64() * 1000.0)
+    );
+}
+
+#[test]
+fn idle_animations_are_not_marked_as_changed() {
+    #[derive(Resource, Default)]
+    struct AnimationChanged(bool);
+
+    fn record_changes(
+        animations: Query<Ref<SpritesheetAnimation>>,
+        mut changed: ResMut<AnimationChanged>,
+    ) {
+        changed.0 = animations.iter().any(|animation| animation.is_changed());
+    }
+
+    let mut ctx = Context::new();
+
+    ctx.app
+        .init_resource::<AnimationChanged>()
+        .add_systems(Update, record_changes);
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([0, 1])
+            .set_duration(AnimationDuration::PerFrame(1000))
+    });
+
+    ctx.run(50);
+    ctx.run(50);
+
+    // The frame does not change so the component does not either
+
+    for _ in 0..5 {
+        ctx.run(50);
+
+        assert!(!ctx.app.world().resource::<AnimationChanged>().0);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod context;
 
+use std::time::Duration;
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -137,6 +140,27 @@ fn manual_control_startup_paused() {
     });
 }
 
+#[test]
+fn at_frame() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .add_indices([4, 5, 6])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(SpritesheetAnimation::at_frame(animation, 2));
+
+    for _ in 0..10 {
+        ctx.run(100);
+        ctx.check(6, []);
+    }
+}
+
 #[test]
 fn manual_control_invalid_frame() {
     let mut ctx = Context::new();
@@ -211,3 +235,665 @@ fn manual_control_invalid_repetition() {
         assert_eq!(sprite.progress.repetition, 0);
     });
 }
+
+#[test]
+fn first_and_last_frames() {
+    let mut ctx = Context::new();
+
+    fn check_frames(ctx: &mut Context, expected_first: bool, expected_last: bool) {
+        ctx.get_sprite(|sprite| {
+            assert_eq!(sprite.is_on_first_frame(), expected_first);
+            assert_eq!(sprite.is_on_last_frame(), expected_last);
+        });
+    }
+
+    // Backwards: 6 → 5 → 4
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([4, 5, 6])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_direction(AnimationDirection::Backwards)
+    });
+
+    // Not played yet
+
+    check_frames(&mut ctx, false, false);
+
+    ctx.run(50);
+    ctx.check(6, []);
+    check_frames(&mut ctx, true, false);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+    check_frames(&mut ctx, false, false);
+
+    ctx.run(100); // 250
+    ctx.check(4, []);
+    check_frames(&mut ctx, false, true);
+
+    ctx.run(100); // 350, looped
+    check_frames(&mut ctx, true, false);
+
+    // PingPong: 4 → 5 → 6 → 5 → 4
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([4, 5, 6])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_direction(AnimationDirection::PingPong)
+    });
+
+    ctx.run(50);
+    ctx.check(4, []);
+    check_frames(&mut ctx, true, false);
+
+    ctx.run(100); // 150
+    check_frames(&mut ctx, false, false);
+
+    ctx.run(100); // 250
+    ctx.check(6, []);
+    check_frames(&mut ctx, false, true);
+
+    ctx.run(100); // 350, second repetition skips the repeated frame
+    check_frames(&mut ctx, true, false);
+
+    ctx.run(100); // 450
+    check_frames(&mut ctx, false, true);
+}
+
+#[test]
+fn frames_remaining() {
+    let mut ctx = Context::new();
+
+    fn check_remaining(ctx: &mut Context, expected: Option<usize>) {
+        ctx.get_sprite(|sprite| assert_eq!(sprite.frames_remaining(), expected));
+    }
+
+    // Looping forever
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([0, 1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+    check_remaining(&mut ctx, None);
+
+    // Several clips and repetitions: (0 → 1) + (2 → 3) × 2, repeated twice
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(2))
+            .add_indices([0, 1])
+            .start_clip()
+            .add_indices([2, 3])
+            .set_clip_repetitions(2)
+    });
+
+    // Not played yet
+
+    check_remaining(&mut ctx, None);
+
+    ctx.run(50);
+    ctx.check(0, []);
+    check_remaining(&mut ctx, Some(11));
+
+    for expected in (0..11).rev() {
+        ctx.run(100);
+        check_remaining(&mut ctx, Some(expected));
+    }
+
+    // Over
+
+    ctx.run(1000);
+    check_remaining(&mut ctx, Some(0));
+
+    // PingPong: 4 → 5 → 6 → 5 → 4 → 5 → 6
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([4, 5, 6])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_direction(AnimationDirection::PingPong)
+            .set_repetitions(AnimationRepeat::Times(3))
+    });
+
+    ctx.run(50);
+    ctx.check(4, []);
+    check_remaining(&mut ctx, Some(6));
+
+    for expected in (0..6).rev() {
+        ctx.run(100);
+        check_remaining(&mut ctx, Some(expected));
+    }
+
+    ctx.check(6, []);
+
+    // Switched to another animation: unknown until it starts playing
+
+    let animation = ctx.create_animation(|builder| {
+        builder
+            .add_indices([0, 1])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+    });
+
+    ctx.get_sprite(|sprite| sprite.switch(animation.clone()));
+    check_remaining(&mut ctx, None);
+
+    ctx.run(50);
+    check_remaining(&mut ctx, Some(1));
+
+    // No repetitions
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([4, 5, 6])
+            .set_repetitions(AnimationRepeat::Times(0))
+    });
+
+    ctx.run(50);
+    check_remaining(&mut ctx, Some(0));
+
+    // Too many repetitions to count (computed without iterating over them)
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([4, 5])
+            .set_repetitions(AnimationRepeat::Times(usize::MAX))
+    });
+
+    ctx.run(50);
+    check_remaining(&mut ctx, Some(usize::MAX));
+}
+
+#[test]
+fn active_clip_id() {
+    let mut ctx = Context::new();
+
+    let mut clip1_id = ClipId::dummy();
+    let mut clip2_id = ClipId::dummy();
+
+    fn check_clip_id(ctx: &mut Context, expected: Option<ClipId>) {
+        ctx.get_sprite(|sprite| assert_eq!(sprite.active_clip_id(), expected));
+    }
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_direction(AnimationDirection::PingPong)
+            .add_indices([0, 1])
+            .get_current_clip_id(&mut clip1_id)
+            .start_clip()
+            .add_indices([2])
+            .get_current_clip_id(&mut clip2_id)
+    });
+
+    // Not played yet
+
+    check_clip_id(&mut ctx, None);
+
+    // Ping: 0 → 1 → 2
+
+    ctx.run(50);
+    check_clip_id(&mut ctx, Some(clip1_id));
+
+    ctx.run(100); // 150
+    check_clip_id(&mut ctx, Some(clip1_id));
+
+    ctx.run(100); // 250
+    check_clip_id(&mut ctx, Some(clip2_id));
+    ctx.get_sprite(|sprite| assert_eq!(sprite.active_clip_index(), Some(1)));
+
+    // Pong: 1 → 0
+
+    ctx.run(100); // 350
+    ctx.get_sprite(|sprite| assert_eq!(sprite.progress.repetition, 1));
+    check_clip_id(&mut ctx, Some(clip1_id));
+    ctx.get_sprite(|sprite| assert_eq!(sprite.active_clip_index(), Some(0)));
+}
+
+#[test]
+fn playback_rate() {
+    let mut ctx = Context::new();
+
+    fn check_rate(ctx: &mut Context, expected: f32) {
+        ctx.get_sprite(|sprite| assert!((sprite.playback_rate() - expected).abs() < 0.01));
+    }
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_clip_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1])
+            .start_clip()
+            .set_clip_duration(AnimationDuration::PerFrame(250))
+            .add_indices([2])
+    });
+
+    // Not played yet
+
+    check_rate(&mut ctx, 0.0);
+
+    // First clip: 10 fps
+
+    ctx.run(50);
+    check_rate(&mut ctx, 10.0);
+
+    // Second clip: 4 fps
+
+    ctx.run(200); // 250
+    check_rate(&mut ctx, 4.0);
+
+    // Twice faster
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = 2.0);
+    check_rate(&mut ctx, 8.0);
+
+    // Paused
+
+    ctx.get_sprite(|sprite| sprite.pause());
+    check_rate(&mut ctx, 0.0);
+}
+
+#[test]
+fn animation_query() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([3, 4])
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    ctx.run(150);
+
+    let sprite_entity = ctx.sprite_entity;
+
+    let mut query = ctx.app.world_mut().query::<AnimationQuery>();
+
+    let item = query.single(ctx.app.world()).unwrap();
+
+    assert_eq!(item.entity, sprite_entity);
+    assert_eq!(item.progress(), AnimationProgress::with_frame(1));
+    assert!(item.is_playing());
+    assert_eq!(item.active_clip_id(), Some(clip_id));
+    assert_eq!(item.atlas_index(), Some(4));
+}
+
+#[test]
+fn animation_views() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    // The same clip played twice
+
+    let clip = ClipBuilder::new(&Spritesheet::new(&Handle::default(), 8, 8))
+        .add_indices([3, 4])
+        .set_name("twice")
+        .build();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_animation_name("views")
+            .add_clip(clip.clone())
+            .start_clip()
+            .add_indices([5])
+            .get_current_clip_id(&mut clip_id)
+            .add_clip(clip.clone())
+    });
+
+    ctx.run(250);
+
+    let sprite_entity = ctx.sprite_entity;
+
+    let other_entity = ctx.app.world_mut().spawn_empty().id();
+
+    ctx.app
+        .world_mut()
+        .run_system_once(move |views: AnimationViews| {
+            let view = views.get(sprite_entity).unwrap();
+
+            assert_eq!(view.state.entity, sprite_entity);
+            assert_eq!(view.state.atlas_index(), Some(5));
+            assert_eq!(view.animation.name(), Some("views"));
+            assert_eq!(view.active_clip().map(|clip| clip.id()), Some(clip_id));
+            assert_eq!(view.state.active_clip_index(), Some(1));
+
+            assert!(views.get(other_entity).is_none());
+
+            assert_eq!(views.iter().count(), 1);
+        })
+        .unwrap();
+}
+
+#[test]
+fn set_animation_keeping_frame() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2])
+    });
+
+    let other_animation = ctx.create_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([5, 6])
+    });
+
+    ctx.run(50);
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // Same animation: keeps going
+
+    ctx.get_sprite(|sprite| sprite.set_animation_keeping_frame(animation.clone()));
+
+    ctx.run(20); // 170
+    ctx.check(1, []);
+
+    ctx.run(50); // 220
+    ctx.check(2, []);
+
+    // Another animation: starts from scratch
+
+    ctx.get_sprite(|sprite| sprite.set_animation_keeping_frame(other_animation.clone()));
+
+    ctx.run(10);
+    ctx.check(5, []);
+
+    ctx.run(100);
+    ctx.check(6, []);
+}
+
+#[test]
+fn is_same_animation() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.create_animation(|builder| builder.add_indices([0, 1, 2]));
+    let other_animation = ctx.create_animation(|builder| builder.add_indices([0, 1, 2]));
+
+    let sprite = SpritesheetAnimation::new(animation.clone());
+
+    // Different progress, same animation
+
+    let same_sprite = SpritesheetAnimation::new(animation.clone())
+        .with_progress(AnimationProgress::with_frame(2))
+        .with_playing(false);
+
+    assert!(sprite.is_same_animation(&same_sprite));
+
+    // Identical but different animations
+
+    let other_sprite = SpritesheetAnimation::new(other_animation);
+
+    assert!(!sprite.is_same_animation(&other_sprite));
+}
+
+#[test]
+fn play_segment() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([10, 11, 12, 13, 14, 15])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+    ctx.check(10, []);
+
+    // Jump to the segment
+
+    ctx.get_sprite(|sprite| {
+        sprite.play_segment(2, 4);
+        assert_eq!(sprite.segment(), Some((2, 4)));
+    });
+
+    ctx.run(10); // 60
+    ctx.check(12, []);
+
+    ctx.run(100); // 160
+    ctx.check(13, []);
+
+    ctx.run(100); // 260
+    ctx.check(14, []);
+
+    ctx.get_sprite(|sprite| {
+        assert!(sprite.is_on_last_frame());
+        assert_eq!(sprite.frames_remaining(), None);
+        assert_eq!(sprite.expected_completion_time(), None);
+    });
+
+    // Loops within the segment
+
+    for _ in 0..3 {
+        ctx.run(100);
+        ctx.check(12, []);
+
+        ctx.run(100);
+        ctx.check(13, []);
+
+        ctx.run(100);
+        ctx.check(14, []);
+    }
+
+    // Back to the whole animation
+
+    ctx.get_sprite(|sprite| {
+        sprite.play_full();
+        assert_eq!(sprite.segment(), None);
+    });
+
+    ctx.run(100);
+    ctx.check(15, []);
+
+    // Invalid segments are ignored
+
+    ctx.get_sprite(|sprite| {
+        sprite.play_segment(4, 2);
+        assert_eq!(sprite.segment(), None);
+    });
+
+    // Segments ending past the last frame are clamped to it instead of rolling over into the next repetition
+
+    ctx.get_sprite(|sprite| sprite.play_segment(4, 10));
+
+    ctx.run(10);
+    ctx.check(14, []);
+    ctx.get_sprite(|sprite| assert!(sprite.is_on_first_frame()));
+
+    for _ in 0..3 {
+        ctx.run(100);
+        ctx.check(15, []);
+        ctx.get_sprite(|sprite| assert!(sprite.is_on_last_frame()));
+
+        ctx.run(100);
+        ctx.check(14, []);
+        ctx.get_sprite(|sprite| assert_eq!(sprite.progress.repetition, 0));
+    }
+}
+
+#[test]
+fn expected_completion_time() {
+    let mut ctx = Context::new();
+
+    fn check_time(ctx: &mut Context, expected: Option<u64>) {
+        ctx.get_sprite(|sprite| {
+            assert_eq!(
+                sprite.expected_completion_time(),
+                expected.map(Duration::from_millis)
+            )
+        });
+    }
+
+    // Looping forever
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([0, 1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+    check_time(&mut ctx, None);
+
+    // (0 → 1) + (2 → 3) × 2 at different speeds, repeated twice
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_repetitions(AnimationRepeat::Times(2))
+            .add_indices([0, 1])
+            .set_clip_duration(AnimationDuration::PerFrame(100))
+            .start_clip()
+            .add_indices([2, 3])
+            .set_clip_duration(AnimationDuration::PerFrame(200))
+            .set_clip_repetitions(2)
+    });
+
+    // Not played yet
+
+    check_time(&mut ctx, None);
+
+    // 1 frame of 100ms + 4 frames of 200ms + 2 frames of 100ms + 4 frames of 200ms
+
+    ctx.run(50);
+    ctx.check(0, []);
+    check_time(&mut ctx, Some(1900));
+
+    ctx.run(100);
+    ctx.check(1, []);
+    check_time(&mut ctx, Some(1800));
+
+    // Twice as fast
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = 2.0);
+    check_time(&mut ctx, Some(900));
+
+    // Stopped
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = 0.0);
+    check_time(&mut ctx, None);
+
+    // Invalid or too slow to be represented
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = f32::NAN);
+    check_time(&mut ctx, None);
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = f32::MIN_POSITIVE);
+    check_time(&mut ctx, None);
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = 1.0);
+
+    // Over
+
+    ctx.run(3000);
+    check_time(&mut ctx, Some(0));
+}
+
+#[test]
+fn override_frame() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([0, 1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    // The overridden frame is displayed for one update and the animation does not advance meanwhile
+
+    ctx.get_sprite(|sprite| sprite.override_frame(40));
+
+    ctx.run(100); // 150 but still 50 for the animation
+    ctx.check(40, []);
+    ctx.get_sprite(|sprite| assert_eq!(sprite.progress.frame, 0));
+
+    // Back to normal
+
+    ctx.run(20); // 70
+    ctx.check(0, []);
+
+    ctx.run(40); // 110
+    ctx.check(1, []);
+
+    // Paused animations display the overridden frame too
+
+    ctx.get_sprite(|sprite| {
+        sprite.pause();
+        sprite.override_frame(41);
+    });
+
+    ctx.run(10);
+    ctx.check(41, []);
+
+    ctx.run(10);
+    ctx.check(1, []);
+}
+
+#[test]
+fn synchronize_with() {
+    let mut ctx = Context::new();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .add_indices([0, 1, 2, 3])
+    });
+
+    ctx.run(50);
+    ctx.run(200); // 250
+    ctx.check(2, []);
+
+    // Spawn another sprite that joins the first one halfway through frame 2
+
+    let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).cloned();
+
+    let mut other_animation = SpritesheetAnimation::new(animation);
+    other_animation.synchronize_with(ctx.sprite_entity);
+
+    let other_entity = ctx
+        .app
+        .world_mut()
+        .spawn((sprite.unwrap(), other_animation))
+        .id();
+
+    let other_atlas_index = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(other_entity)
+            .and_then(|sprite| sprite.texture_atlas.as_ref())
+            .map(|atlas| atlas.index)
+    };
+
+    ctx.run(10); // 260
+    ctx.check(2, []);
+    assert_eq!(other_atlas_index(&ctx), Some(2));
+
+    // Both sprites move to the next frame at the same time
+
+    ctx.run(50); // 310
+    ctx.check(3, []);
+    assert_eq!(other_atlas_index(&ctx), Some(3));
+
+    // Synchronizing with an entity without animation leaves the animation untouched
+
+    let empty_entity = ctx.app.world_mut().spawn_empty().id();
+
+    ctx.get_sprite(|sprite| sprite.synchronize_with(empty_entity));
+
+    ctx.run(50); // 360
+    ctx.check(3, []);
+}
@@ -0,0 +1,96 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn record_and_playback() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .add_indices([1, 2])
+            .set_duration(AnimationDuration::PerFrame(100))
+            .start_clip()
+            .add_indices([3])
+            .set_clip_duration(AnimationDuration::PerFrame(300))
+    });
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(AnimationRecorder::default());
+
+    ctx.run(50); // 50
+    ctx.run(100); // 150
+    ctx.run(100); // 250
+
+    // Jump back to the first frame
+
+    ctx.get_sprite(|sprite| {
+        sprite.progress.frame = 0;
+    });
+
+    ctx.run(20); // 270
+
+    let recorder = ctx
+        .app
+        .world()
+        .get::<AnimationRecorder>(ctx.sprite_entity)
+        .unwrap();
+
+    let frames: Vec<_> = recorder
+        .frames()
+        .iter()
+        .map(|frame| {
+            (
+                frame.tick,
+                frame.clip_index,
+                frame.frame_index,
+                frame.atlas_index,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        frames,
+        [(0, 0, 0, 1), (2, 0, 1, 2), (3, 1, 2, 3), (4, 0, 0, 1)]
+    );
+
+    // Frames that start during an update are timestamped from the leftover time
+
+    for (frame, expected_ms) in recorder.frames().iter().zip([0, 100, 200, 270]) {
+        assert!(frame.time.as_millis().abs_diff(expected_ms) <= 1);
+    }
+
+    // Convert the recording into an animation
+
+    let recorded_animation = recorder.playback();
+
+    assert_eq!(recorded_animation.duration(), recorder.elapsed());
+
+    let animation: Animation = recorded_animation.into();
+
+    assert_eq!(animation.num_clips(), 4);
+
+    for (clip, (expected_atlas_index, expected_ms)) in
+        animation
+            .clips()
+            .iter()
+            .zip([(1, 100), (2, 100), (3, 70), (1, 0)])
+    {
+        assert_eq!(clip.atlas_indices(), [expected_atlas_index]);
+
+        let Some(AnimationDuration::PerFrame(ms)) = *clip.duration() else {
+            panic!("unexpected duration");
+        };
+
+        assert!(ms.abs_diff(expected_ms) <= 1);
+    }
+
+    assert!(matches!(
+        animation.repetitions(),
+        Some(AnimationRepeat::Times(1))
+    ));
+}
@@ -0,0 +1,155 @@
+pub mod context;
+
+use bevy::{
+    asset::uuid_handle,
+    prelude::*,
+    reflect::{FromReflect, ReflectFromReflect},
+    scene::serde::SceneDeserializer,
+};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+use serde::de::DeserializeSeed;
+
+fn sample_animation() -> Animation {
+    let spritesheet = Spritesheet::new(&Handle::default(), 8, 8);
+
+    let marker = Marker::new();
+
+    spritesheet
+        .create_animation()
+        .add_indices([1, 2, 3])
+        .set_clip_duration(AnimationDuration::PerFrame(250))
+        .set_clip_repetitions(3)
+        .set_clip_easing(Easing::In(EasingVariety::Quadratic))
+        .add_clip_marker(marker, 1)
+        .start_clip()
+        .add_row(4)
+        .set_clip_direction(AnimationDirection::PingPong)
+        .set_duration(AnimationDuration::PerRepetition(2000))
+        .set_repetitions(AnimationRepeat::Times(2))
+        .set_direction(AnimationDirection::Backwards)
+        .set_easing(Easing::Out(EasingVariety::Cubic))
+        .build()
+}
+
+#[test]
+fn animation_round_trip_from_reflect() {
+    let animation = sample_animation();
+
+    let dynamic = animation.to_dynamic();
+
+    let rebuilt = Animation::from_reflect(dynamic.as_partial_reflect())
+        .expect("animation should be reconstructible from its reflected representation");
+
+    assert_eq!(format!("{rebuilt:?}"), format!("{animation:?}"));
+}
+
+#[test]
+fn animation_round_trip_through_type_registry() {
+    let ctx = Context::new();
+
+    let registry = ctx.app.world().resource::<AppTypeRegistry>().read();
+
+    let animation = sample_animation();
+
+    let dynamic = animation.to_dynamic();
+
+    let from_reflect = registry
+        .get_type_data::<ReflectFromReflect>(std::any::TypeId::of::<Animation>())
+        .expect("Animation should register ReflectFromReflect");
+
+    let rebuilt = from_reflect
+        .from_reflect(dynamic.as_partial_reflect())
+        .expect("animation should be reconstructible from its reflected representation");
+
+    let rebuilt = rebuilt
+        .downcast_ref::<Animation>()
+        .expect("reconstructed value should be an Animation");
+
+    assert_eq!(format!("{rebuilt:?}"), format!("{animation:?}"));
+
+    // Nested types are registered as well
+
+    for type_id in [
+        std::any::TypeId::of::<Clip>(),
+        std::any::TypeId::of::<ClipId>(),
+        std::any::TypeId::of::<Marker>(),
+        std::any::TypeId::of::<AnimationDuration>(),
+        std::any::TypeId::of::<AnimationRepeat>(),
+        std::any::TypeId::of::<AnimationDirection>(),
+        std::any::TypeId::of::<Easing>(),
+        std::any::TypeId::of::<EasingVariety>(),
+    ] {
+        assert!(
+            registry
+                .get_type_data::<ReflectFromReflect>(type_id)
+                .is_some()
+        );
+    }
+}
+
+#[test]
+fn spritesheet_animation_round_trip_through_scene() {
+    let mut ctx = Context::new();
+
+    // Scenes can only reference assets with stable handles
+
+    const ANIMATION: Handle<Animation> = uuid_handle!("4d5e7e3c-04d4-4c4e-9f0b-7c1a2e58f1a5");
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(
+            SpritesheetAnimation::new(ANIMATION)
+                .with_progress(AnimationProgress::with_frame_repetition(2, 1))
+                .with_playing(false)
+                .with_speed_factor(1.5),
+        )
+        .id();
+
+    // Serialize a scene that contains the component
+
+    let scene = DynamicSceneBuilder::from_world(ctx.app.world())
+        .allow_component::<SpritesheetAnimation>()
+        .extract_entity(entity)
+        .build();
+
+    let registry = ctx.app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let serialized_scene = scene
+        .serialize(&registry)
+        .expect("the scene should be serializable");
+
+    // Deserialize it
+
+    let mut deserializer = ron::de::Deserializer::from_str(&serialized_scene).unwrap();
+
+    let deserialized_scene = SceneDeserializer {
+        type_registry: &registry,
+    }
+    .deserialize(&mut deserializer)
+    .expect("the scene should be deserializable");
+
+    let mut world = World::new();
+    world.insert_resource(ctx.app.world().resource::<AppTypeRegistry>().clone());
+
+    deserialized_scene
+        .write_to_world(&mut world, &mut default())
+        .expect("the scene should be written to the world");
+
+    // The component is restored
+
+    let sprite = world
+        .query::<&SpritesheetAnimation>()
+        .single(&world)
+        .expect("the entity should be restored");
+
+    assert_eq!(sprite.animation, ANIMATION);
+    assert_eq!(
+        sprite.progress,
+        AnimationProgress::with_frame_repetition(2, 1)
+    );
+    assert!(!sprite.playing);
+    assert_eq!(sprite.speed_factor, 1.5);
+}
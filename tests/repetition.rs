@@ -1,5 +1,8 @@
 pub mod context;
 
+use std::time::Duration;
+
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -284,6 +287,127 @@ fn animation_many() {
     }
 }
 
+#[test]
+fn animation_loop_for_duration() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    // 450ms ends during the third repetition, which is played entirely
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::LoopFor(Duration::from_millis(450)))
+            .add_indices([0, 1])
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    for i in 0..2 {
+        ctx.run(100);
+        ctx.check(1, []);
+
+        ctx.run(100);
+        ctx.check(
+            0,
+            [
+                ctx.clip_rep_end(&animation, clip_id, 0),
+                ctx.clip_end(&animation, clip_id),
+                ctx.anim_rep_end(&animation, i),
+            ],
+        );
+    }
+
+    // Last cycle
+
+    ctx.run(100);
+    ctx.check(1, []);
+
+    ctx.run(100);
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+            ctx.anim_rep_end(&animation, 2),
+            ctx.anim_end(&animation),
+        ],
+    );
+
+    // Over
+
+    for _ in 0..100 {
+        ctx.run(100);
+        ctx.check(1, []);
+    }
+}
+
+#[test]
+fn animation_loop_for_duration_real_time() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::LoopFor(Duration::from_millis(450)))
+            .add_indices([0, 1])
+    });
+
+    // Twice as fast: 450ms of real time ends during the fifth repetition
+
+    ctx.get_sprite(|sprite| sprite.speed_factor = 2.0);
+
+    ctx.run(25);
+
+    let mut atlas_indices = Vec::new();
+
+    for _ in 0..12 {
+        ctx.run(50);
+
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+
+        atlas_indices.push(sprite.texture_atlas.as_ref().unwrap().index);
+    }
+
+    assert_eq!(atlas_indices, [1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 1]);
+}
+
+#[test]
+fn animation_loop_for_duration_virtual_clock() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::LoopFor(Duration::from_millis(450)))
+            .add_indices([0, 1])
+    });
+
+    // The virtual clock runs twice as fast: 450ms of real time still ends during the fifth repetition
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_relative_speed(2.0);
+
+    ctx.run(25);
+
+    let mut atlas_indices = Vec::new();
+
+    for _ in 0..12 {
+        ctx.run(50);
+
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+
+        atlas_indices.push(sprite.texture_atlas.as_ref().unwrap().index);
+    }
+
+    assert_eq!(atlas_indices, [1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 1]);
+}
+
 #[test]
 fn animation_forever() {
     let mut ctx = Context::new();
@@ -316,3 +440,238 @@ fn animation_forever() {
         );
     }
 }
+
+#[test]
+fn clip_and_animation_many() {
+    let mut ctx = Context::new();
+
+    let mut once_clip_id = ClipId::dummy();
+    let mut twice_clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(2))
+            // Clip 1: played once per animation repetition
+            .add_indices([0, 1])
+            .get_current_clip_id(&mut once_clip_id)
+            // Clip 2: played twice per animation repetition
+            .start_clip()
+            .add_indices([2, 3])
+            .set_clip_repetitions(2)
+            .get_current_clip_id(&mut twice_clip_id)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    // Each animation repetition plays the whole clip sequence, clip 2 playing its two repetitions every time
+
+    for i in 0..2 {
+        if i > 0 {
+            ctx.run(100);
+            ctx.check(
+                0,
+                [
+                    ctx.clip_rep_end(&animation, twice_clip_id, 1),
+                    ctx.clip_end(&animation, twice_clip_id),
+                    ctx.anim_rep_end(&animation, 0),
+                ],
+            );
+        }
+
+        ctx.run(100);
+        ctx.check(1, []);
+
+        ctx.run(100);
+        ctx.check(
+            2,
+            [
+                ctx.clip_rep_end(&animation, once_clip_id, 0),
+                ctx.clip_end(&animation, once_clip_id),
+            ],
+        );
+
+        ctx.run(100);
+        ctx.check(3, []);
+
+        ctx.run(100);
+        ctx.check(2, [ctx.clip_rep_end(&animation, twice_clip_id, 0)]);
+
+        ctx.run(100);
+        ctx.check(3, []);
+    }
+
+    // End
+
+    ctx.run(100);
+    ctx.check(
+        3,
+        [
+            ctx.clip_rep_end(&animation, twice_clip_id, 1),
+            ctx.clip_end(&animation, twice_clip_id),
+            ctx.anim_rep_end(&animation, 1),
+            ctx.anim_end(&animation),
+        ],
+    );
+
+    for _ in 0..100 {
+        ctx.run(100);
+        ctx.check(3, []);
+    }
+}
+
+#[test]
+fn clip_many_animation_forever() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Loop)
+            .add_indices([0, 1])
+            .set_clip_repetitions(3)
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    for i in 0..100 {
+        for repetition in 0..3 {
+            ctx.run(100);
+            ctx.check(1, []);
+
+            ctx.run(100);
+
+            if repetition < 2 {
+                ctx.check(0, [ctx.clip_rep_end(&animation, clip_id, repetition)]);
+            } else {
+                ctx.check(
+                    0,
+                    [
+                        ctx.clip_rep_end(&animation, clip_id, 2),
+                        ctx.clip_end(&animation, clip_id),
+                        ctx.anim_rep_end(&animation, i),
+                    ],
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn clips_zero_animation_many() {
+    let mut ctx = Context::new();
+
+    ctx.attach_animation(|builder| {
+        builder
+            .set_repetitions(AnimationRepeat::Times(10))
+            .add_indices([4, 5])
+            .set_clip_repetitions(0)
+            .start_clip()
+            .add_indices([6, 7])
+            .set_clip_repetitions(0)
+    });
+
+    // No frames to play, however many times the animation repeats
+
+    for _ in 0..100 {
+        ctx.run(100);
+        ctx.check(0, []);
+    }
+}
+
+#[test]
+fn on_complete_atlas_index() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(1))
+            .set_on_complete_atlas_index(7)
+            .add_indices([1, 2])
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    ctx.run(50);
+    ctx.check(1, []);
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+
+    // Jump to the resting frame when the animation ends
+
+    ctx.run(100); // 250
+    ctx.check(
+        7,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+            ctx.anim_rep_end(&animation, 0),
+            ctx.anim_end(&animation),
+        ],
+    );
+
+    ctx.run(1000);
+    ctx.check(7, []);
+}
+
+#[test]
+fn repetition_end_and_animation_end() {
+    let mut ctx = Context::new();
+
+    let mut clip_id = ClipId::dummy();
+
+    let animation = ctx.attach_animation(|builder| {
+        builder
+            .set_duration(AnimationDuration::PerFrame(100))
+            .set_repetitions(AnimationRepeat::Times(3))
+            .add_indices([0, 1])
+            .get_current_clip_id(&mut clip_id)
+    });
+
+    ctx.run(50);
+    ctx.check(0, []);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // Each repetition emits its own event...
+
+    for repetition in 0..2 {
+        ctx.run(100);
+        ctx.check(
+            0,
+            [
+                ctx.clip_rep_end(&animation, clip_id, 0),
+                ctx.clip_end(&animation, clip_id),
+                ctx.anim_rep_end(&animation, repetition),
+            ],
+        );
+
+        ctx.run(100);
+        ctx.check(1, []);
+    }
+
+    // ... and only the last one also ends the animation
+
+    ctx.run(100); // 650
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(&animation, clip_id, 0),
+            ctx.clip_end(&animation, clip_id),
+            ctx.anim_rep_end(&animation, 2),
+            ctx.anim_end(&animation),
+        ],
+    );
+
+    ctx.run(1000);
+    ctx.check(1, []);
+}
@@ -1,5 +1,6 @@
 pub mod context;
 
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::Context;
 
@@ -87,3 +88,30 @@ fn animation_assigned_while_paused() {
         ctx.check(0, []);
     }
 }
+
+#[test]
+fn default_animation() {
+    let mut ctx = Context::new();
+
+    // The default animation is playable but does not display anything
+
+    let default_animation = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Animation>>()
+        .add(Animation::default());
+
+    ctx.attach_animation(|builder| builder.add_indices([4, 5]));
+
+    ctx.run(50);
+    ctx.check(4, []);
+
+    ctx.get_sprite(|sprite| {
+        sprite.switch(default_animation.clone());
+    });
+
+    for _ in 0..10 {
+        ctx.run(100);
+        ctx.check(4, []);
+    }
+}